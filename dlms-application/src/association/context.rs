@@ -5,6 +5,7 @@
 
 use crate::pdu::{Conformance, InitiateRequest, InitiateResponse};
 use crate::association::state::AssociationState;
+use dlms_asn1::iso_acse::{AEQualifier, APInvocationIdentifier, APTitle};
 use dlms_core::DlmsResult;
 
 /// Service Access Point address
@@ -182,6 +183,24 @@ pub struct AssociationContext {
 
     /// Negotiated protocol parameters
     pub negotiated_params: Option<NegotiatedParameters>,
+
+    /// Calling AP title sent in the AARQ (optional, full ACSE addressing)
+    pub calling_ap_title: Option<APTitle>,
+
+    /// Calling AE qualifier sent in the AARQ (optional)
+    pub calling_ae_qualifier: Option<AEQualifier>,
+
+    /// Calling AP invocation identifier sent in the AARQ (optional)
+    pub calling_ap_invocation_id: Option<APInvocationIdentifier>,
+
+    /// Responding AP title received in the AARE (optional)
+    pub responding_ap_title: Option<APTitle>,
+
+    /// Authentication value sent in the AARQ (LLS password or HLS challenge)
+    pub calling_authentication_value: Vec<u8>,
+
+    /// Authentication value received in the AARE (HLS server challenge)
+    pub responding_authentication_value: Vec<u8>,
 }
 
 impl AssociationContext {
@@ -199,6 +218,12 @@ impl AssociationContext {
             local_title: None,
             remote_title: None,
             negotiated_params: None,
+            calling_ap_title: None,
+            calling_ae_qualifier: None,
+            calling_ap_invocation_id: None,
+            responding_ap_title: None,
+            calling_authentication_value: Vec::new(),
+            responding_authentication_value: Vec::new(),
         }
     }
 
@@ -223,6 +248,44 @@ impl AssociationContext {
         self
     }
 
+    /// Set the calling AP title for the AARQ
+    pub fn with_calling_ap_title(mut self, title: APTitle) -> Self {
+        self.calling_ap_title = Some(title);
+        self
+    }
+
+    /// Set the calling AE qualifier for the AARQ
+    pub fn with_calling_ae_qualifier(mut self, qualifier: AEQualifier) -> Self {
+        self.calling_ae_qualifier = Some(qualifier);
+        self
+    }
+
+    /// Set the calling AP invocation identifier for the AARQ
+    pub fn with_calling_ap_invocation_id(mut self, id: APInvocationIdentifier) -> Self {
+        self.calling_ap_invocation_id = Some(id);
+        self
+    }
+
+    /// Set the calling authentication value for the AARQ
+    ///
+    /// For LLS this is the password; for HLS this is the client challenge
+    /// (CtoS).
+    pub fn with_calling_authentication_value(mut self, value: Vec<u8>) -> Self {
+        self.calling_authentication_value = value;
+        self
+    }
+
+    /// Verify a Low Level Security (LLS) password
+    ///
+    /// Compares the authentication value received in the AARQ against the
+    /// expected password. An empty stored value never matches, so an AARQ
+    /// without an authentication value is rejected.
+    #[must_use]
+    pub fn verify_low_auth(&self, expected_password: &[u8]) -> bool {
+        !self.calling_authentication_value.is_empty()
+            && self.calling_authentication_value.as_slice() == expected_password
+    }
+
     /// Get the association state
     #[must_use]
     pub const fn state(&self) -> &AssociationState {
@@ -351,6 +414,26 @@ mod tests {
         assert!(ctx.remote_title.is_some());
     }
 
+    #[test]
+    fn test_verify_low_auth_matching_password() {
+        let ctx = AssociationContext::with_defaults()
+            .with_calling_authentication_value(b"12345678".to_vec());
+
+        assert!(ctx.verify_low_auth(b"12345678"));
+    }
+
+    #[test]
+    fn test_verify_low_auth_non_matching_password() {
+        let ctx = AssociationContext::with_defaults()
+            .with_calling_authentication_value(b"12345678".to_vec());
+
+        assert!(!ctx.verify_low_auth(b"wrongpass"));
+
+        // An AARQ without an authentication value must not match
+        let empty_ctx = AssociationContext::with_defaults();
+        assert!(!empty_ctx.verify_low_auth(b"12345678"));
+    }
+
     #[test]
     fn test_negotiated_parameters() {
         let params = NegotiatedParameters {
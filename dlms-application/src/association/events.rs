@@ -196,35 +196,181 @@ where
 /// A listener that sends events to a tokio mpsc channel.
 #[cfg(feature = "tokio")]
 pub mod channel_listener {
-    use tokio::sync::mpsc::{UnboundedSender, UnboundedReceiver};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    use dlms_core::{DlmsError, DlmsResult};
+    use tokio::sync::mpsc::error::TrySendError;
+    use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
+    use tokio::sync::Mutex;
+
+    use super::AssociationEvent;
+
+    /// Behavior of a bounded listener when its channel is full
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum OverflowPolicy {
+        /// Discard the oldest queued event to make room for the new one
+        DropOldest,
+        /// Block the sending thread until the receiver frees a slot
+        ///
+        /// This applies backpressure to the producer, but only from threads
+        /// outside a tokio runtime: `blocking_send` panics on a runtime
+        /// thread, so events emitted from async context fall back to being
+        /// dropped (and counted in
+        /// [`ChannelEventListener::dropped_events`]) when the channel is
+        /// full.
+        Block,
+    }
+
+    enum EventSender {
+        Unbounded(UnboundedSender<AssociationEvent>),
+        Bounded {
+            tx: Sender<AssociationEvent>,
+            rx: Arc<Mutex<Receiver<AssociationEvent>>>,
+            policy: OverflowPolicy,
+        },
+    }
 
     /// Channel-based event listener
     pub struct ChannelEventListener {
-        tx: UnboundedSender<super::AssociationEvent>,
+        sender: EventSender,
+        dropped: AtomicU64,
+    }
+
+    /// Receiving half of a bounded event channel
+    ///
+    /// Wraps the underlying receiver so the listener can discard the oldest
+    /// queued event when the channel overflows under the
+    /// [`OverflowPolicy::DropOldest`] policy.
+    pub struct BoundedEventReceiver {
+        rx: Arc<Mutex<Receiver<AssociationEvent>>>,
+    }
+
+    impl BoundedEventReceiver {
+        /// Receive the next event, waiting until one is available
+        ///
+        /// Returns `None` when the listener has been dropped and all queued
+        /// events have been consumed.
+        pub async fn recv(&self) -> Option<AssociationEvent> {
+            self.rx.lock().await.recv().await
+        }
     }
 
     impl ChannelEventListener {
         /// Create a new channel-based event listener
         ///
+        /// The channel is unbounded: events are never dropped, but an
+        /// unresponsive receiver lets the queue grow without limit.
+        ///
         /// # Returns
         /// Returns the listener and a receiver for events.
-        pub fn new() -> (Self, UnboundedReceiver<super::AssociationEvent>) {
+        pub fn new() -> (Self, UnboundedReceiver<AssociationEvent>) {
             let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-            (Self { tx }, rx)
+            (
+                Self {
+                    sender: EventSender::Unbounded(tx),
+                    dropped: AtomicU64::new(0),
+                },
+                rx,
+            )
+        }
+
+        /// Create a bounded channel-based event listener
+        ///
+        /// At most `capacity` events are queued. When the channel is full,
+        /// the `policy` decides whether the oldest queued event is discarded
+        /// or the producer is blocked until the receiver catches up.
+        ///
+        /// # Arguments
+        /// * `capacity` - Maximum number of queued events (must be > 0)
+        /// * `policy` - What to do when the channel is full
+        ///
+        /// # Returns
+        /// Returns the listener and a receiver for events.
+        pub fn new_bounded(
+            capacity: usize,
+            policy: OverflowPolicy,
+        ) -> (Self, BoundedEventReceiver) {
+            let (tx, rx) = tokio::sync::mpsc::channel(capacity);
+            let rx = Arc::new(Mutex::new(rx));
+            (
+                Self {
+                    sender: EventSender::Bounded {
+                        tx,
+                        rx: Arc::clone(&rx),
+                        policy,
+                    },
+                    dropped: AtomicU64::new(0),
+                },
+                BoundedEventReceiver { rx },
+            )
+        }
+
+        /// Number of events discarded because the channel was full
+        ///
+        /// Always zero for unbounded listeners. Under the
+        /// [`OverflowPolicy::Block`] policy, counts only events emitted
+        /// from a runtime thread while the channel was full, where
+        /// blocking is not an option.
+        #[must_use]
+        pub fn dropped_events(&self) -> u64 {
+            self.dropped.load(Ordering::Relaxed)
         }
 
         /// Send an event to the channel
-        pub fn send(&self, event: super::AssociationEvent) -> Result<(), super::super::super::dlms_core::DlmsError> {
-            self.tx
-                .send(event)
-                .map_err(|e| super::super::super::dlms_core::DlmsError::Other(e.to_string()))
+        ///
+        /// # Errors
+        /// Returns `DlmsError::Protocol` when the receiver has been dropped.
+        pub fn send(&self, event: AssociationEvent) -> DlmsResult<()> {
+            match &self.sender {
+                EventSender::Unbounded(tx) => tx
+                    .send(event)
+                    .map_err(|_| DlmsError::Protocol("Event channel closed".to_string())),
+                EventSender::Bounded { tx, rx, policy } => match tx.try_send(event) {
+                    Ok(()) => Ok(()),
+                    Err(TrySendError::Closed(_)) => {
+                        Err(DlmsError::Protocol("Event channel closed".to_string()))
+                    }
+                    Err(TrySendError::Full(event)) => match policy {
+                        OverflowPolicy::DropOldest => {
+                            if let Ok(mut queue) = rx.try_lock() {
+                                if queue.try_recv().is_ok() {
+                                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                                }
+                                drop(queue);
+                                if tx.try_send(event).is_ok() {
+                                    return Ok(());
+                                }
+                            }
+                            // The receiver is busy draining; losing the new
+                            // event is the closest we can get to drop-oldest
+                            // without blocking.
+                            self.dropped.fetch_add(1, Ordering::Relaxed);
+                            Ok(())
+                        }
+                        OverflowPolicy::Block => {
+                            if tokio::runtime::Handle::try_current().is_ok() {
+                                // blocking_send panics on a runtime thread;
+                                // dropping the event is the only
+                                // non-panicking option left here
+                                self.dropped.fetch_add(1, Ordering::Relaxed);
+                                Ok(())
+                            } else {
+                                tx.blocking_send(event).map_err(|_| {
+                                    DlmsError::Protocol("Event channel closed".to_string())
+                                })
+                            }
+                        }
+                    },
+                },
+            }
         }
     }
 
     impl super::AssociationEventListener for ChannelEventListener {
-        fn on_event(&self, event: super::AssociationEvent) {
+        fn on_event(&self, event: AssociationEvent) {
             // Ignore send errors - the receiver might be dropped
-            let _ = self.tx.send(event);
+            let _ = self.send(event);
         }
     }
 }
@@ -278,9 +424,88 @@ mod tests {
         let (listener, mut rx) = ChannelEventListener::new();
 
         let event = AssociationEvent::Released;
-        listener.on_event(event).unwrap();
+        listener.on_event(event);
 
         let received = rx.recv().await.unwrap();
         assert_eq!(received, AssociationEvent::Released);
     }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_bounded_listener_drops_oldest_when_full() {
+        use crate::association::events::channel_listener::{
+            ChannelEventListener, OverflowPolicy,
+        };
+
+        let (listener, rx) = ChannelEventListener::new_bounded(2, OverflowPolicy::DropOldest);
+
+        listener.on_event(AssociationEvent::Established { version: 1 });
+        listener.on_event(AssociationEvent::Established { version: 2 });
+        listener.on_event(AssociationEvent::Established { version: 3 });
+
+        assert_eq!(listener.dropped_events(), 1);
+        assert_eq!(
+            rx.recv().await,
+            Some(AssociationEvent::Established { version: 2 })
+        );
+        assert_eq!(
+            rx.recv().await,
+            Some(AssociationEvent::Established { version: 3 })
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_bounded_listener_blocks_when_full() {
+        use std::sync::Arc;
+
+        use crate::association::events::channel_listener::{
+            ChannelEventListener, OverflowPolicy,
+        };
+
+        let (listener, rx) = ChannelEventListener::new_bounded(1, OverflowPolicy::Block);
+        let listener = Arc::new(listener);
+
+        listener.on_event(AssociationEvent::Established { version: 1 });
+
+        // The channel is full, so this producer thread blocks until the
+        // first event is consumed below.
+        let producer = Arc::clone(&listener);
+        let handle = std::thread::spawn(move || {
+            producer.on_event(AssociationEvent::Established { version: 2 });
+        });
+
+        assert_eq!(
+            rx.recv().await,
+            Some(AssociationEvent::Established { version: 1 })
+        );
+        handle.join().unwrap();
+        assert_eq!(
+            rx.recv().await,
+            Some(AssociationEvent::Established { version: 2 })
+        );
+        assert_eq!(listener.dropped_events(), 0);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_bounded_block_policy_drops_on_runtime_thread() {
+        use crate::association::events::channel_listener::{
+            ChannelEventListener, OverflowPolicy,
+        };
+
+        let (listener, rx) = ChannelEventListener::new_bounded(1, OverflowPolicy::Block);
+
+        listener.on_event(AssociationEvent::Established { version: 1 });
+
+        // Emitting from a runtime thread must not panic in blocking_send;
+        // the overflowing event is dropped and counted instead
+        listener.on_event(AssociationEvent::Established { version: 2 });
+
+        assert_eq!(listener.dropped_events(), 1);
+        assert_eq!(
+            rx.recv().await,
+            Some(AssociationEvent::Established { version: 1 })
+        );
+    }
 }
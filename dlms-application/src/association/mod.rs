@@ -57,10 +57,17 @@ use std::fmt;
 
 // Re-export for convenience in this module
 use crate::pdu::{InitiateRequest, InitiateResponse};
-use dlms_asn1::iso_acse::{AARQApdu, AAREApdu, RLRQApdu, RLREApdu, AssociateResult};
+use dlms_asn1::iso_acse::{
+    AARQApdu, AAREApdu, RLRQApdu, RLREApdu, AssociateResult,
+    AEQualifier, APInvocationIdentifier, APTitle,
+};
 #[allow(unused_imports)] // Used in tests
 use dlms_asn1::iso_acse::AssociateSourceDiagnostic;
-use dlms_core::DlmsResult;
+use dlms_core::{DlmsError, DlmsResult};
+use dlms_security::{
+    GloCiphering, SecurityControl, XdlmsContext,
+    GLO_INITIATE_REQUEST_TAG, GLO_INITIATE_RESPONSE_TAG,
+};
 
 /// Events emitter for internal use
 #[derive(Clone)]
@@ -139,6 +146,13 @@ pub struct Association {
 
     /// Event emitters for listener notifications
     events: EventsEmitter,
+
+    /// xDLMS security context for ciphered application contexts
+    ///
+    /// When set and a ciphered context name is used, the InitiateRequest
+    /// is wrapped in a glo-initiate-request and the glo-initiate-response
+    /// is deciphered transparently.
+    xdlms_context: Option<Arc<XdlmsContext>>,
 }
 
 impl Association {
@@ -151,6 +165,7 @@ impl Association {
         Self {
             context,
             events: EventsEmitter::new(),
+            xdlms_context: None,
         }
     }
 
@@ -173,6 +188,27 @@ impl Association {
         &mut self.context
     }
 
+    /// Install the xDLMS security context for ciphered associations
+    ///
+    /// Required when the application context name is a ciphered one
+    /// (`1.0.17.0.0.128.0.2`): `build_aarq` then ciphers the
+    /// InitiateRequest and `process_aare` deciphers the
+    /// glo-initiate-response.
+    ///
+    /// # Arguments
+    /// * `context` - xDLMS context with keys, system titles, and counters
+    pub fn set_xdlms_context(&mut self, context: Arc<XdlmsContext>) {
+        self.xdlms_context = Some(context);
+    }
+
+    /// Check whether an application context name selects ciphered APDUs
+    ///
+    /// The ciphered logical name context is `1.0.17.0.0.128.0.2`; only the
+    /// trailing arc distinguishes it from the clear-text context.
+    fn is_ciphered_context(application_context_name: &[u32]) -> bool {
+        application_context_name.ends_with(&[128, 0, 2])
+    }
+
     /// Get the current association state
     #[must_use]
     pub fn state(&self) -> AssociationState {
@@ -266,10 +302,18 @@ impl Association {
     /// # Arguments
     /// * `initiate_request` - The InitiateRequest PDU to include
     /// * `application_context_name` - Application context OID (optional)
+    /// * `calling_ap_title` - Calling AP title (optional, falls back to the context)
+    /// * `calling_ae_qualifier` - Calling AE qualifier (optional, falls back to the context)
+    /// * `calling_ap_invocation_id` - Calling AP invocation identifier (optional, falls back to the context)
     ///
     /// # Returns
     /// Returns the encoded AARQ APDU bytes ready for transmission.
     ///
+    /// # Note
+    /// Servers that require full ACSE addressing check the calling fields.
+    /// Addressing values not passed explicitly are taken from the
+    /// [`AssociationContext`], so they can be configured once up front.
+    ///
     /// # Example
     /// ```rust
     /// use dlms_application::association::Association;
@@ -278,13 +322,16 @@ impl Association {
     /// let mut association = Association::with_defaults();
     /// let initiate_req = InitiateRequest::new();
     ///
-    /// let aarq_bytes = association.build_aarq(&initiate_req, None).unwrap();
+    /// let aarq_bytes = association.build_aarq(&initiate_req, None, None, None, None).unwrap();
     /// // Send aarq_bytes to server...
     /// ```
     pub fn build_aarq(
         &self,
         initiate_request: &InitiateRequest,
         application_context_name: Option<Vec<u32>>,
+        calling_ap_title: Option<APTitle>,
+        calling_ae_qualifier: Option<AEQualifier>,
+        calling_ap_invocation_id: Option<APInvocationIdentifier>,
     ) -> DlmsResult<Vec<u8>> {
         // Use DLMS/COSEM application context if not specified
         let app_ctx = application_context_name.unwrap_or_else(|| {
@@ -292,12 +339,42 @@ impl Association {
             vec![1, 0, 17, 0, 0, 8, 0, 101]
         });
 
+        let ciphered_context = Self::is_ciphered_context(&app_ctx);
+
         // Create AARQ
         let mut aarq = AARQApdu::new(app_ctx);
 
-        // Encode InitiateRequest and add to user_information
+        // Full ACSE addressing: explicit arguments win, context values are the fallback
+        aarq.calling_ap_title =
+            calling_ap_title.or_else(|| self.context.calling_ap_title.clone());
+        aarq.calling_ae_qualifier =
+            calling_ae_qualifier.or_else(|| self.context.calling_ae_qualifier.clone());
+        aarq.calling_ap_invocation_identifier =
+            calling_ap_invocation_id.or(self.context.calling_ap_invocation_id);
+
+        // Encode InitiateRequest and add to user_information; ciphered
+        // contexts carry it inside a glo-initiate-request instead
         let initiate_bytes = initiate_request.encode()?;
-        aarq.set_initiate_request(initiate_bytes);
+        if ciphered_context {
+            let xdlms = self.xdlms_context.clone().ok_or_else(|| {
+                DlmsError::Security(
+                    "Ciphered application context requires an xDLMS context; call set_xdlms_context() first".to_string(),
+                )
+            })?;
+            let frame_counter = xdlms.increment_send_counter();
+            let system_title = xdlms.client_system_title.clone();
+            let security_control = SecurityControl::new(0, true, true, false);
+            let ciphered = GloCiphering::new(xdlms).build_glo_initiate(
+                GLO_INITIATE_REQUEST_TAG,
+                &initiate_bytes,
+                security_control,
+                &system_title,
+                frame_counter,
+            )?;
+            aarq.set_initiate_request(ciphered);
+        } else {
+            aarq.set_initiate_request(initiate_bytes);
+        }
 
         // Encode AARQ to BER
         aarq.encode()
@@ -329,6 +406,10 @@ impl Association {
         // Decode AARE
         let aare = AAREApdu::decode(aare_bytes)?;
 
+        // Preserve the responding AP title for callers that need the
+        // server's addressing (e.g. for ciphered contexts)
+        self.context.responding_ap_title = aare.responding_ap_title.clone();
+
         // Check if association was accepted
         match aare.result {
             AssociateResult::Accepted => {
@@ -336,7 +417,25 @@ impl Association {
                 if let Some(initiate_bytes) = aare.get_initiate_response() {
                     eprintln!("initiate_bytes from AARE: {:02X?}", initiate_bytes);
                     eprintln!("initiate_bytes len: {}", initiate_bytes.len());
-                    match InitiateResponse::decode(initiate_bytes) {
+                    // Ciphered associations answer with a glo-initiate-response
+                    let initiate_bytes = if initiate_bytes.first()
+                        == Some(&GLO_INITIATE_RESPONSE_TAG)
+                    {
+                        let xdlms = self.xdlms_context.clone().ok_or_else(|| {
+                            DlmsError::Security(
+                                "Received glo-initiate-response without an xDLMS context; call set_xdlms_context() first".to_string(),
+                            )
+                        })?;
+                        let system_title = xdlms.server_system_title.clone();
+                        GloCiphering::new(xdlms).parse_glo_initiate(
+                            GLO_INITIATE_RESPONSE_TAG,
+                            initiate_bytes,
+                            &system_title,
+                        )?
+                    } else {
+                        initiate_bytes.to_vec()
+                    };
+                    match InitiateResponse::decode(&initiate_bytes) {
                         Ok(initiate_res) => {
                             // Create negotiated parameters
                             let negotiated_params = NegotiatedParameters::from_initiate(
@@ -379,14 +478,8 @@ impl Association {
                 // Association rejected
                 self.transition_to(AssociationState::Idle);
 
-                let reason = match aare.result_source_diagnostic.value() {
-                    0 => RejectReason::None,
-                    1 => RejectReason::NotAuthorized,
-                    2 => RejectReason::CalledApTitleNotRecognized,
-                    3 => RejectReason::CalledApInvocationIdNotRecognized,
-                    4 => RejectReason::NotAuthorizedToInvoke,
-                    n => RejectReason::Other(n as u8),
-                };
+                let reason =
+                    RejectReason::from_source_diagnostic(&aare.result_source_diagnostic);
 
                 Ok(OpenResult::Rejected {
                     reason,
@@ -682,7 +775,7 @@ mod tests {
         let association = Association::with_defaults();
         let initiate_req = InitiateRequest::new();
 
-        let aarq_bytes = association.build_aarq(&initiate_req, None);
+        let aarq_bytes = association.build_aarq(&initiate_req, None, None, None, None);
         assert!(aarq_bytes.is_ok());
 
         let bytes = aarq_bytes.unwrap();
@@ -692,6 +785,73 @@ mod tests {
         assert!(decoded.is_ok(), "Failed to decode AARQ: {:?}", decoded);
     }
 
+    #[test]
+    fn test_build_aarq_with_addressing_fields() {
+        let association = Association::with_defaults();
+        let initiate_req = InitiateRequest::new();
+
+        let ap_title = APTitle::form_2(vec![0x4D, 0x4D, 0x4D, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        let ae_qualifier = AEQualifier::form_1(1);
+        let invocation_id = APInvocationIdentifier::new(7);
+
+        let aarq_bytes = association
+            .build_aarq(
+                &initiate_req,
+                None,
+                Some(ap_title.clone()),
+                Some(ae_qualifier.clone()),
+                Some(invocation_id),
+            )
+            .unwrap();
+
+        // All addressing fields survive an encode/decode roundtrip
+        let decoded = AARQApdu::decode(&aarq_bytes).unwrap();
+        assert_eq!(decoded.calling_ap_title, Some(ap_title));
+        assert_eq!(decoded.calling_ae_qualifier, Some(ae_qualifier));
+        assert_eq!(decoded.calling_ap_invocation_identifier, Some(invocation_id));
+    }
+
+    #[test]
+    fn test_build_aarq_addressing_from_context() {
+        let ap_title = APTitle::form_2(vec![0x4D, 0x4D, 0x4D, 0x00, 0x00, 0x00, 0x00, 0x02]);
+        let ctx = AssociationContext::with_defaults()
+            .with_calling_ap_title(ap_title.clone());
+        let association = Association::new(ctx);
+
+        let aarq_bytes = association
+            .build_aarq(&InitiateRequest::new(), None, None, None, None)
+            .unwrap();
+
+        let decoded = AARQApdu::decode(&aarq_bytes).unwrap();
+        assert_eq!(decoded.calling_ap_title, Some(ap_title));
+    }
+
+    #[test]
+    fn test_process_aare_preserves_responding_ap_title() {
+        let mut association = Association::with_defaults();
+        association.on_connected(); // Set state to Idle
+
+        let responding_title =
+            APTitle::form_2(vec![0x53, 0x52, 0x56, 0x00, 0x00, 0x00, 0x00, 0x01]);
+
+        let initiate_res =
+            InitiateResponse::new(6, crate::pdu::Conformance::new(), 2048, 0x0007).unwrap();
+        let mut aare = AAREApdu::new(
+            vec![1, 0, 17, 0, 0, 8, 0, 101], // DLMS application context
+            AssociateResult::Accepted,
+            AssociateSourceDiagnostic::null(),
+        );
+        aare.responding_ap_title = Some(responding_title.clone());
+        aare.set_initiate_response(initiate_res.encode().unwrap());
+
+        let result = association.process_aare(&aare.encode().unwrap()).unwrap();
+        assert!(matches!(result, OpenResult::Success { .. }));
+        assert_eq!(
+            association.context().responding_ap_title,
+            Some(responding_title)
+        );
+    }
+
     #[test]
     fn test_build_rlrq() {
         let association = Association::with_defaults();
@@ -740,6 +900,85 @@ mod tests {
         assert!(association.is_active());
     }
 
+    /// Build an xDLMS context with fixed keys and system titles
+    fn test_xdlms_context() -> XdlmsContext {
+        let client_st =
+            dlms_security::SystemTitle::new([0x4D, 0x4D, 0x4D, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        let server_st =
+            dlms_security::SystemTitle::new([0x53, 0x52, 0x56, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        let mut context = XdlmsContext::new(client_st, server_st);
+        context.set_master_key(vec![0xA5; 16]).unwrap();
+        context
+    }
+
+    #[test]
+    fn test_ciphered_association_roundtrip() {
+        let ciphered_ctx_name = dlms_asn1::iso_acse::DLMS_APPLICATION_CONTEXT_NAME_CIPHERED;
+
+        // Client ciphers the InitiateRequest into a glo-initiate-request
+        let mut association = Association::with_defaults();
+        association.set_xdlms_context(Arc::new(test_xdlms_context()));
+        association.on_connected();
+
+        let initiate_req = InitiateRequest::new();
+        let aarq_bytes = association
+            .build_aarq(&initiate_req, Some(ciphered_ctx_name.to_vec()), None, None, None)
+            .unwrap();
+
+        let aarq = AARQApdu::decode(&aarq_bytes).unwrap();
+        let ciphered = aarq.get_initiate_request().unwrap();
+        assert_eq!(ciphered.first(), Some(&GLO_INITIATE_REQUEST_TAG));
+
+        // Server side deciphers with its own context (same keys, counter at 0)
+        let server_context = Arc::new(test_xdlms_context());
+        let glo = GloCiphering::new(server_context.clone());
+        let client_st = server_context.client_system_title.clone();
+        let plaintext = glo
+            .parse_glo_initiate(GLO_INITIATE_REQUEST_TAG, ciphered, &client_st)
+            .unwrap();
+        assert_eq!(plaintext, initiate_req.encode().unwrap());
+
+        // Server answers with a ciphered glo-initiate-response
+        let initiate_res =
+            InitiateResponse::new(6, crate::pdu::Conformance::new(), 2048, 0x0007).unwrap();
+        let server_st = server_context.server_system_title.clone();
+        let frame_counter = server_context.increment_send_counter();
+        let ciphered_response = glo
+            .build_glo_initiate(
+                GLO_INITIATE_RESPONSE_TAG,
+                &initiate_res.encode().unwrap(),
+                SecurityControl::new(0, true, true, false),
+                &server_st,
+                frame_counter,
+            )
+            .unwrap();
+
+        let mut aare = AAREApdu::new(
+            ciphered_ctx_name.to_vec(),
+            AssociateResult::Accepted,
+            AssociateSourceDiagnostic::null(),
+        );
+        aare.set_initiate_response(ciphered_response);
+
+        // Client deciphers the response transparently in process_aare
+        let result = association.process_aare(&aare.encode().unwrap()).unwrap();
+        assert!(matches!(result, OpenResult::Success { .. }), "got {:?}", result);
+        assert_eq!(association.state(), AssociationState::Associated);
+    }
+
+    #[test]
+    fn test_ciphered_context_requires_xdlms_context() {
+        let association = Association::with_defaults();
+        let result = association.build_aarq(
+            &InitiateRequest::new(),
+            Some(dlms_asn1::iso_acse::DLMS_APPLICATION_CONTEXT_NAME_CIPHERED.to_vec()),
+            None,
+            None,
+            None,
+        );
+        assert!(matches!(result, Err(DlmsError::Security(_))));
+    }
+
     #[test]
     fn test_process_aare_rejected() {
         let mut association = Association::with_defaults();
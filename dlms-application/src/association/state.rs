@@ -4,6 +4,7 @@
 //! between a DLMS client and server, following the COSEM-OPEN and COSEM-RELEASE
 //! service primitives defined in the DLMS Green Book.
 
+use dlms_asn1::iso_acse::AssociateSourceDiagnostic;
 use std::fmt::{self, Display};
 
 /// Association state for DLMS/COSEM application layer connections
@@ -198,22 +199,62 @@ pub enum OpenResult {
 }
 
 /// Reason for association rejection
+///
+/// Typed view of the AARE associate-source-diagnostic. The
+/// acse-service-user space carries application-level reasons, including
+/// the DLMS authentication diagnostics (values 11-14 per Green Book);
+/// the acse-service-provider space carries ACSE protocol-level reasons.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RejectReason {
-    /// No reason given
+    /// No diagnostic given (null, value 0 in either space)
     None,
-    /// Sender is not authorized to establish an association
-    NotAuthorized,
-    /// Called AP title not recognized
-    CalledApTitleNotRecognized,
-    /// Called AP invocation identifier not recognized
-    CalledApInvocationIdNotRecognized,
-    /// Caller not authorized to invoke this operation
-    NotAuthorizedToInvoke,
-    /// Other reason
+    /// acse-service-user (1): no reason given
+    NoReasonGiven,
+    /// acse-service-user (2): application context name not supported
+    ApplicationContextNameNotSupported,
+    /// acse-service-user (11): authentication mechanism name not recognized
+    AuthenticationMechanismNameNotRecognized,
+    /// acse-service-user (12): authentication mechanism name required
+    AuthenticationMechanismNameRequired,
+    /// acse-service-user (13): authentication failure
+    AuthenticationFailure,
+    /// acse-service-user (14): authentication required
+    AuthenticationRequired,
+    /// acse-service-provider (1): no reason given
+    ProviderNoReasonGiven,
+    /// acse-service-provider (2): no common ACSE version
+    NoCommonAcseVersion,
+    /// Diagnostic value with no named mapping
     Other(u8),
 }
 
+impl RejectReason {
+    /// Map an AARE associate-source-diagnostic to a typed reason
+    ///
+    /// The CHOICE arm selects the diagnostic space, so the same numeric
+    /// value maps differently for service-user and service-provider.
+    pub fn from_source_diagnostic(diagnostic: &AssociateSourceDiagnostic) -> Self {
+        match diagnostic {
+            AssociateSourceDiagnostic::AcseServiceUser(value) => match *value {
+                0 => Self::None,
+                1 => Self::NoReasonGiven,
+                2 => Self::ApplicationContextNameNotSupported,
+                11 => Self::AuthenticationMechanismNameNotRecognized,
+                12 => Self::AuthenticationMechanismNameRequired,
+                13 => Self::AuthenticationFailure,
+                14 => Self::AuthenticationRequired,
+                n => Self::Other(n as u8),
+            },
+            AssociateSourceDiagnostic::AcseServiceProvider(value) => match *value {
+                0 => Self::None,
+                1 => Self::ProviderNoReasonGiven,
+                2 => Self::NoCommonAcseVersion,
+                n => Self::Other(n as u8),
+            },
+        }
+    }
+}
+
 /// Reason for association abort
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AbortReason {
@@ -298,4 +339,49 @@ mod tests {
         assert_eq!(AssociationState::Idle.to_string(), "Idle");
         assert_eq!(AssociationState::Associated.to_string(), "Associated");
     }
+
+    #[test]
+    fn test_reject_reason_service_user_mapping() {
+        let cases = [
+            (0, RejectReason::None),
+            (1, RejectReason::NoReasonGiven),
+            (2, RejectReason::ApplicationContextNameNotSupported),
+            (11, RejectReason::AuthenticationMechanismNameNotRecognized),
+            (12, RejectReason::AuthenticationMechanismNameRequired),
+            (13, RejectReason::AuthenticationFailure),
+            (14, RejectReason::AuthenticationRequired),
+            (99, RejectReason::Other(99)),
+        ];
+        for (value, expected) in cases {
+            let diagnostic = AssociateSourceDiagnostic::service_user(value);
+            assert_eq!(RejectReason::from_source_diagnostic(&diagnostic), expected);
+        }
+    }
+
+    #[test]
+    fn test_reject_reason_service_provider_mapping() {
+        let cases = [
+            (0, RejectReason::None),
+            (1, RejectReason::ProviderNoReasonGiven),
+            (2, RejectReason::NoCommonAcseVersion),
+            (3, RejectReason::Other(3)),
+        ];
+        for (value, expected) in cases {
+            let diagnostic = AssociateSourceDiagnostic::service_provider(value);
+            assert_eq!(RejectReason::from_source_diagnostic(&diagnostic), expected);
+        }
+    }
+
+    #[test]
+    fn test_reject_reason_same_value_maps_per_space() {
+        // Value 2 names a different condition in each diagnostic space
+        assert_eq!(
+            RejectReason::from_source_diagnostic(&AssociateSourceDiagnostic::service_user(2)),
+            RejectReason::ApplicationContextNameNotSupported
+        );
+        assert_eq!(
+            RejectReason::from_source_diagnostic(&AssociateSourceDiagnostic::service_provider(2)),
+            RejectReason::NoCommonAcseVersion
+        );
+    }
 }
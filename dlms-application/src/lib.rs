@@ -164,7 +164,9 @@ pub mod service {
     pub mod set;
     pub mod action;
     pub mod event;
+    pub mod block_transfer;
 
+    pub use block_transfer::BlockTransfer;
     pub use get::GetService;
     pub use set::SetService;
     pub use action::ActionService;
@@ -481,6 +481,16 @@ impl Conformance {
         self.get_bit(16).unwrap_or(false)
     }
 
+    /// Set ACCESS service capability (bit 17)
+    pub fn set_access(&mut self, value: bool) -> DlmsResult<()> {
+        self.set_bit(17, value)
+    }
+
+    /// Get ACCESS service capability (bit 17)
+    pub fn access(&self) -> bool {
+        self.get_bit(17).unwrap_or(false)
+    }
+
     /// Set parameterized access capability (bit 18)
     pub fn set_parameterized_access(&mut self, value: bool) -> DlmsResult<()> {
         self.set_bit(18, value)
@@ -540,6 +550,97 @@ impl Conformance {
     pub fn action(&self) -> bool {
         self.get_bit(23).unwrap_or(false)
     }
+
+    /// Intersection of two conformance blocks
+    ///
+    /// # Returns
+    /// A Conformance with only the bits set in both `self` and `other`.
+    /// This is the core of conformance negotiation: the negotiated set is the
+    /// intersection of the client's proposal and the server's capabilities.
+    pub fn intersect(&self, other: &Conformance) -> Conformance {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Union of two conformance blocks
+    ///
+    /// # Returns
+    /// A Conformance with the bits set in either `self` or `other`
+    pub fn union(&self, other: &Conformance) -> Conformance {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Difference of two conformance blocks
+    ///
+    /// # Returns
+    /// A Conformance with the bits set in `self` but not in `other`,
+    /// i.e. the capabilities `other` is missing relative to `self`
+    pub fn difference(&self, other: &Conformance) -> Conformance {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Check whether all bits of a required conformance are supported
+    ///
+    /// # Arguments
+    /// * `required` - Capabilities that must all be present in `self`
+    ///
+    /// # Returns
+    /// `true` if every bit set in `required` is also set in `self`
+    pub fn supports_all(&self, required: &Conformance) -> bool {
+        required.difference(self) == Conformance::new()
+    }
+
+    /// Combine two conformance blocks bit by bit
+    fn combine(&self, other: &Conformance, op: impl Fn(bool, bool) -> bool) -> Conformance {
+        let mut result = Conformance::new();
+        for bit in 0..24 {
+            let a = self.get_bit(bit).unwrap_or(false);
+            let b = other.get_bit(bit).unwrap_or(false);
+            let _ = result.set_bit(bit, op(a, b));
+        }
+        result
+    }
+
+    /// Feature names per the documented bit layout, indexed by bit number
+    ///
+    /// Reserved bits are named "reserved-<bit>" so unexpected bits remain visible.
+    const FEATURE_NAMES: [&'static str; 24] = [
+        "general-protection",            // 0
+        "general-block-transfer",        // 1
+        "reserved-2",                    // 2
+        "block-read",                    // 3
+        "block-write",                   // 4
+        "unconfirmed-write",             // 5
+        "reserved-6",                    // 6
+        "reserved-7",                    // 7
+        "attribute0-with-set",           // 8
+        "priority-management",           // 9
+        "attribute0-with-get",           // 10
+        "block-transfer-with-get",       // 11
+        "block-transfer-with-set",       // 12
+        "block-transfer-with-action",    // 13
+        "multiple-references",           // 14
+        "information-report",            // 15
+        "data-notification",             // 16
+        "access",                        // 17
+        "parameterized-access",          // 18
+        "get",                           // 19
+        "set",                           // 20
+        "selective-access",              // 21
+        "event-notification",            // 22
+        "action",                        // 23
+    ];
+
+    /// Names of all enabled features
+    ///
+    /// # Returns
+    /// Feature names (e.g. "get", "set", "selective-access") for each set
+    /// bit, in bit order. Useful for logging negotiated conformance.
+    pub fn enabled_features(&self) -> Vec<&'static str> {
+        (0..24)
+            .filter(|&bit| self.get_bit(bit).unwrap_or(false))
+            .map(|bit| Self::FEATURE_NAMES[bit])
+            .collect()
+    }
 }
 
 impl Default for Conformance {
@@ -548,6 +649,17 @@ impl Default for Conformance {
     }
 }
 
+impl std::fmt::Display for Conformance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let features = self.enabled_features();
+        if features.is_empty() {
+            write!(f, "Conformance {{}}")
+        } else {
+            write!(f, "Conformance {{ {} }}", features.join(", "))
+        }
+    }
+}
+
 /// Initiate Request PDU
 ///
 /// This PDU is sent by the client to initiate a DLMS/COSEM association.
@@ -1466,6 +1578,30 @@ impl SelectiveAccessDescriptor {
         }
     }
 
+    /// Create a selector-2 entry descriptor for Profile Generic buffer reads
+    ///
+    /// All indexes are 1-based per the Blue Book `entry_descriptor`:
+    /// `from_entry`/`to_entry` select entries and
+    /// `from_selected_value`/`to_selected_value` select columns within each
+    /// entry (column 1 is the first capture object). A `to_*` value of 0
+    /// means "through the last entry/column".
+    pub fn by_entry(
+        from_entry: u32,
+        to_entry: u32,
+        from_selected_value: u16,
+        to_selected_value: u16,
+    ) -> Self {
+        Self::new(
+            2,
+            DataObject::Structure(vec![
+                DataObject::Unsigned32(from_entry),
+                DataObject::Unsigned32(to_entry),
+                DataObject::Unsigned16(from_selected_value),
+                DataObject::Unsigned16(to_selected_value),
+            ]),
+        )
+    }
+
     /// Encode to A-XDR format
     ///
     /// Encoding format (A-XDR, reverse order):
@@ -1485,16 +1621,18 @@ impl SelectiveAccessDescriptor {
     }
 
     /// Decode from A-XDR format
+    ///
+    /// Fields are read in the order `encode` writes them:
+    /// access_parameters first, then access_selector.
     pub fn decode(data: &[u8]) -> DlmsResult<Self> {
         let mut decoder = AxdrDecoder::new(data);
 
-        // Decode in reverse order
-        // 1. access_selector (Unsigned8)
-        let access_selector = decoder.decode_u8()?;
-
-        // 2. access_parameters (DataObject)
+        // 1. access_parameters (DataObject)
         let access_parameters = decoder.decode_data_object()?;
 
+        // 2. access_selector (Unsigned8)
+        let access_selector = decoder.decode_u8()?;
+
         Ok(Self {
             access_selector,
             access_parameters,
@@ -1660,6 +1798,38 @@ pub mod action_result {
     pub const NOT_SET: u8 = 255;
 }
 
+/// ExceptionResponse state-error codes
+///
+/// Based on the DLMS/COSEM Green Book standard.
+/// These constants provide type-safe values for the optional
+/// `state_error` field of ExceptionResponse.
+pub mod state_error {
+    /// Service not allowed in the current association state
+    pub const SERVICE_NOT_ALLOWED: u8 = 1;
+    /// Service unknown to the server
+    pub const SERVICE_UNKNOWN: u8 = 2;
+}
+
+/// ExceptionResponse service-error codes
+///
+/// Based on the DLMS/COSEM Green Book standard.
+/// These constants provide type-safe values for the `service_error`
+/// field of ExceptionResponse.
+pub mod service_error {
+    /// Operation not possible
+    pub const OPERATION_NOT_POSSIBLE: u8 = 1;
+    /// Service not supported
+    pub const SERVICE_NOT_SUPPORTED: u8 = 2;
+    /// Other reason
+    pub const OTHER_REASON: u8 = 3;
+    /// PDU too long
+    pub const PDU_TOO_LONG: u8 = 4;
+    /// Deciphering error
+    pub const DECIPHERING_ERROR: u8 = 5;
+    /// Invalid (malformed) inquiry
+    pub const INVALID_INQUIRY: u8 = 6;
+}
+
 impl GetDataResult {
     /// Create a new GetDataResult with data
     pub fn new_data(data: DataObject) -> Self {
@@ -2432,6 +2602,45 @@ impl GetResponse {
         Self::Normal(GetResponseNormal::new(invoke_id_and_priority, result))
     }
 
+    /// Convert the response into per-entry results
+    ///
+    /// Each `GetDataResult` becomes `Ok(data)` for a data entry or
+    /// `Err(DlmsError::DataAccess { code, description })` for an
+    /// access-result entry, so a `WithList` response with mixed outcomes
+    /// can be iterated ergonomically. A `Normal` response yields a
+    /// single-element vector; a `WithDataBlock` response cannot be split
+    /// into per-entry results and yields a single error entry.
+    pub fn into_results(self) -> Vec<DlmsResult<DataObject>> {
+        match self {
+            GetResponse::Normal(normal) => vec![Self::data_result_into(normal.result)],
+            GetResponse::WithList { result_list, .. } => result_list
+                .into_iter()
+                .map(Self::data_result_into)
+                .collect(),
+            GetResponse::WithDataBlock { .. } => vec![Err(DlmsError::InvalidData(
+                "WithDataBlock response cannot be converted into results; reassemble blocks first"
+                    .to_string(),
+            ))],
+        }
+    }
+
+    /// Convert a single `GetDataResult` into a `DlmsResult<DataObject>`
+    fn data_result_into(result: GetDataResult) -> DlmsResult<DataObject> {
+        match result {
+            GetDataResult::Data(data) => Ok(data),
+            GetDataResult::DataBlock(_) => Err(DlmsError::InvalidData(
+                "DataBlock entry cannot be converted into a result; reassemble blocks first"
+                    .to_string(),
+            )),
+            GetDataResult::DataAccessResult(code) => Err(DlmsError::DataAccess {
+                code,
+                description: GetDataResult::DataAccessResult(code)
+                    .error_description()
+                    .to_string(),
+            }),
+        }
+    }
+
     /// Encode to A-XDR format
     ///
     /// Encoding format (A-XDR CHOICE):
@@ -2631,6 +2840,89 @@ mod tests {
         assert_eq!(decoded.get_bit(23), Some(true)); // ACTION
     }
 
+    #[test]
+    fn test_conformance_intersect_keeps_mutual_bits() {
+        let mut client = Conformance::new();
+        client.set_bit(3, true).unwrap(); // Block read
+        client.set_bit(19, true).unwrap(); // GET
+        client.set_bit(20, true).unwrap(); // SET
+
+        let mut server = Conformance::new();
+        server.set_bit(19, true).unwrap(); // GET
+        server.set_bit(23, true).unwrap(); // ACTION
+
+        let negotiated = client.intersect(&server);
+        assert_eq!(negotiated.get_bit(19), Some(true)); // mutual
+        assert_eq!(negotiated.get_bit(3), Some(false)); // client only
+        assert_eq!(negotiated.get_bit(20), Some(false)); // client only
+        assert_eq!(negotiated.get_bit(23), Some(false)); // server only
+    }
+
+    #[test]
+    fn test_conformance_union_and_difference() {
+        let mut a = Conformance::new();
+        a.set_bit(19, true).unwrap(); // GET
+        a.set_bit(20, true).unwrap(); // SET
+
+        let mut b = Conformance::new();
+        b.set_bit(20, true).unwrap(); // SET
+        b.set_bit(23, true).unwrap(); // ACTION
+
+        let union = a.union(&b);
+        assert_eq!(union.get_bit(19), Some(true));
+        assert_eq!(union.get_bit(20), Some(true));
+        assert_eq!(union.get_bit(23), Some(true));
+
+        let difference = a.difference(&b);
+        assert_eq!(difference.get_bit(19), Some(true)); // only in a
+        assert_eq!(difference.get_bit(20), Some(false)); // shared
+        assert_eq!(difference.get_bit(23), Some(false)); // only in b
+    }
+
+    #[test]
+    fn test_conformance_supports_all_reports_missing_capabilities() {
+        let mut server = Conformance::new();
+        server.set_bit(19, true).unwrap(); // GET
+        server.set_bit(20, true).unwrap(); // SET
+
+        let mut required = Conformance::new();
+        required.set_bit(19, true).unwrap(); // GET
+        assert!(server.supports_all(&required));
+
+        required.set_bit(23, true).unwrap(); // ACTION not supported by server
+        assert!(!server.supports_all(&required));
+
+        // The empty requirement is always satisfied
+        assert!(server.supports_all(&Conformance::new()));
+    }
+
+    #[test]
+    fn test_conformance_enabled_features() {
+        let mut conformance = Conformance::new();
+        conformance.set_bit(19, true).unwrap(); // GET
+        conformance.set_bit(20, true).unwrap(); // SET
+        conformance.set_bit(21, true).unwrap(); // Selective access
+
+        assert_eq!(
+            conformance.enabled_features(),
+            vec!["get", "set", "selective-access"]
+        );
+        assert!(Conformance::new().enabled_features().is_empty());
+    }
+
+    #[test]
+    fn test_conformance_display() {
+        let mut conformance = Conformance::new();
+        conformance.set_bit(3, true).unwrap(); // Block read
+        conformance.set_bit(19, true).unwrap(); // GET
+
+        assert_eq!(
+            conformance.to_string(),
+            "Conformance { block-read, get }"
+        );
+        assert_eq!(Conformance::new().to_string(), "Conformance {}");
+    }
+
     #[test]
     fn test_conformance_encode_with_mode_ber() {
         let mut conformance = Conformance::new();
@@ -3123,6 +3415,106 @@ mod tests {
             _ => panic!("Expected WithDataBlock variant"),
         }
     }
+
+    #[test]
+    fn test_get_response_into_results_mixed_list() {
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let response = GetResponse::WithList {
+            invoke_id_and_priority: invoke,
+            result_list: vec![
+                GetDataResult::Data(DataObject::new_unsigned32(1234)),
+                GetDataResult::DataAccessResult(data_access_result::OBJECT_UNDEFINED),
+            ],
+        };
+
+        let results = response.into_results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &DataObject::new_unsigned32(1234)
+        );
+        match &results[1] {
+            Err(DlmsError::DataAccess { code, description }) => {
+                assert_eq!(*code, data_access_result::OBJECT_UNDEFINED);
+                assert_eq!(description, "Object undefined");
+            }
+            other => panic!("Expected DataAccess error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_get_response_into_results_normal() {
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let response = GetResponse::new_normal(
+            invoke,
+            GetDataResult::Data(DataObject::new_bool(true)),
+        );
+
+        let results = response.into_results();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap(), &DataObject::new_bool(true));
+    }
+
+    #[test]
+    fn test_access_request_long_form_list_roundtrip() {
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let obis = ObisCode::new(1, 0, 1, 8, 0, 255);
+        let ln_ref = LogicalNameReference::new(3, obis, 2).unwrap();
+
+        // 200 entries force the long form (0x81) array length encoding
+        let specs: Vec<AccessRequestSpecification> = (0..200)
+            .map(|_| AccessRequestSpecification::Get {
+                cosem_attribute_descriptor: CosemAttributeDescriptor::LogicalName(
+                    ln_ref.clone(),
+                ),
+                access_selection: None,
+            })
+            .collect();
+
+        let request = AccessRequest::new(invoke, specs).unwrap();
+        let encoded = request.encode().unwrap();
+        let decoded = AccessRequest::decode(&encoded).unwrap();
+
+        assert_eq!(decoded.access_request_list.len(), 200);
+        assert_eq!(request, decoded);
+    }
+
+    #[test]
+    fn test_access_response_long_form_list_roundtrip() {
+        let invoke = InvokeIdAndPriority::new(2, false).unwrap();
+
+        let results: Vec<AccessResponseSpecification> = (0..200)
+            .map(|_| AccessResponseSpecification::Set(SetDataResult::Success))
+            .collect();
+
+        let response = AccessResponse::new(invoke, results).unwrap();
+        let encoded = response.encode().unwrap();
+        let decoded = AccessResponse::decode(&encoded).unwrap();
+
+        assert_eq!(decoded.access_response_list.len(), 200);
+        assert_eq!(response, decoded);
+    }
+
+    #[test]
+    fn test_selective_access_by_entry() {
+        let descriptor = SelectiveAccessDescriptor::by_entry(1, 5, 2, 3);
+
+        assert_eq!(descriptor.access_selector, 2);
+        assert_eq!(
+            descriptor.access_parameters,
+            DataObject::Structure(vec![
+                DataObject::Unsigned32(1),
+                DataObject::Unsigned32(5),
+                DataObject::Unsigned16(2),
+                DataObject::Unsigned16(3),
+            ])
+        );
+
+        // Entry descriptors survive the A-XDR roundtrip like any other selector
+        let encoded = descriptor.encode().unwrap();
+        let decoded = SelectiveAccessDescriptor::decode(&encoded).unwrap();
+        assert_eq!(descriptor, decoded);
+    }
 }
 
 // ============================================================================
@@ -3423,19 +3815,19 @@ impl CosemMethodDescriptor {
     pub fn decode(data: &[u8]) -> DlmsResult<Self> {
         let mut decoder = AxdrDecoder::new(data);
 
-        // Decode in reverse order
-        // 1. class_id (Unsigned16)
-        let class_id = decoder.decode_u16()?;
-
-        // 2. instance_id (OctetString)
-        let instance_bytes = decoder.decode_octet_string()?;
-
-        // 3. method_id (Integer8)
+        // Decode in the same order as encode
+        // 1. method_id (Integer8)
         // Note: decode_i8 returns i8, but method_id is u8. We cast the signed value to unsigned.
         // This is safe because method IDs are always positive values (0-255 range).
         let method_id_i8: i8 = decoder.decode_i8()?;
         let method_id: u8 = method_id_i8 as u8;
 
+        // 2. instance_id (OctetString)
+        let instance_bytes = decoder.decode_octet_string()?;
+
+        // 3. class_id (Unsigned16)
+        let class_id = decoder.decode_u16()?;
+
         // Determine addressing method by instance_id length
         match instance_bytes.len() {
             6 => {
@@ -3553,26 +3945,34 @@ impl SetRequestNormal {
     pub fn decode(data: &[u8]) -> DlmsResult<Self> {
         let mut decoder = AxdrDecoder::new(data);
 
-        // Decode in reverse order
-        // 1. invoke_id_and_priority (InvokeIdAndPriority)
-        let invoke_bytes = decoder.decode_octet_string()?;
-        let invoke_id_and_priority = InvokeIdAndPriority::decode(&invoke_bytes)?;
-
-        // 2. cosem_attribute_descriptor (CosemAttributeDescriptor)
-        let attr_bytes = decoder.decode_octet_string()?;
-        let cosem_attribute_descriptor = CosemAttributeDescriptor::decode(&attr_bytes)?;
+        // Decode in the same order as encode (value, access_selection,
+        // cosem_attribute_descriptor, invoke_id_and_priority). Nested
+        // structures are concatenated without length prefixes, so consumed
+        // bytes are tracked by re-encoding (same approach as
+        // `GetRequestNormal::decode`).
+        // 1. value (DataObject)
+        let value = decoder.decode_data_object()?;
 
-        // 3. access_selection (optional SelectiveAccessDescriptor)
+        // 2. access_selection (optional SelectiveAccessDescriptor)
         let has_access = decoder.decode_bool()?;
+        let mut pos = decoder.position();
+
         let access_selection = if has_access {
-            let access_bytes = decoder.decode_octet_string()?;
-            Some(SelectiveAccessDescriptor::decode(&access_bytes)?)
+            let access = SelectiveAccessDescriptor::decode(&data[pos..])?;
+            let access_encoded = access.encode()?;
+            pos += access_encoded.len();
+            Some(access)
         } else {
             None
         };
 
-        // 4. value (DataObject)
-        let value = decoder.decode_data_object()?;
+        // 3. cosem_attribute_descriptor (CosemAttributeDescriptor)
+        let cosem_attribute_descriptor = CosemAttributeDescriptor::decode(&data[pos..])?;
+        let attr_encoded = cosem_attribute_descriptor.encode()?;
+        pos += attr_encoded.len();
+
+        // 4. invoke_id_and_priority (InvokeIdAndPriority)
+        let invoke_id_and_priority = InvokeIdAndPriority::decode(&data[pos..])?;
 
         Ok(Self {
             invoke_id_and_priority,
@@ -3769,23 +4169,28 @@ impl SetRequestWithList {
 
         let mut encoder = AxdrEncoder::new();
 
-        // Encode in reverse order (A-XDR)
+        // Encode in the order decode reads the fields
 
-        // 1. value_list - array of DataObject, each encoded directly
+        // 1. invoke_id_and_priority
+        let invoke_bytes = self.invoke_id_and_priority.encode()?;
+        encoder.encode_octet_string(&invoke_bytes)?;
+
+        // 2. attribute_descriptor_list - array of CosemAttributeDescriptor
         // Encode array length
-        let len_enc = if self.value_list.len() < 128 {
-            LengthEncoding::Short(self.value_list.len() as u8)
+        let len_enc = if self.attribute_descriptor_list.len() < 128 {
+            LengthEncoding::Short(self.attribute_descriptor_list.len() as u8)
         } else {
-            LengthEncoding::Long(self.value_list.len())
+            LengthEncoding::Long(self.attribute_descriptor_list.len())
         };
         encoder.encode_bytes(&len_enc.encode())?;
 
         // Encode each element (in forward order, as per A-XDR array encoding)
-        for value in &self.value_list {
-            encoder.encode_data_object(value)?;
+        for attr_desc in &self.attribute_descriptor_list {
+            let attr_bytes = attr_desc.encode()?;
+            encoder.encode_octet_string(&attr_bytes)?;
         }
 
-        // 2. access_selection_list - array of optional SelectiveAccessDescriptor
+        // 3. access_selection_list - array of optional SelectiveAccessDescriptor
         // Encode array length
         let len_enc = if self.access_selection_list.len() < 128 {
             LengthEncoding::Short(self.access_selection_list.len() as u8)
@@ -3804,25 +4209,20 @@ impl SetRequestWithList {
             }
         }
 
-        // 3. attribute_descriptor_list - array of CosemAttributeDescriptor
+        // 4. value_list - array of DataObject, each encoded directly
         // Encode array length
-        let len_enc = if self.attribute_descriptor_list.len() < 128 {
-            LengthEncoding::Short(self.attribute_descriptor_list.len() as u8)
+        let len_enc = if self.value_list.len() < 128 {
+            LengthEncoding::Short(self.value_list.len() as u8)
         } else {
-            LengthEncoding::Long(self.attribute_descriptor_list.len())
+            LengthEncoding::Long(self.value_list.len())
         };
         encoder.encode_bytes(&len_enc.encode())?;
 
         // Encode each element (in forward order, as per A-XDR array encoding)
-        for attr_desc in &self.attribute_descriptor_list {
-            let attr_bytes = attr_desc.encode()?;
-            encoder.encode_octet_string(&attr_bytes)?;
+        for value in &self.value_list {
+            encoder.encode_data_object(value)?;
         }
 
-        // 4. invoke_id_and_priority
-        let invoke_bytes = self.invoke_id_and_priority.encode()?;
-        encoder.encode_octet_string(&invoke_bytes)?;
-
         Ok(encoder.into_bytes())
     }
 
@@ -4258,11 +4658,11 @@ impl SetRequest {
 
         match self {
             SetRequest::Normal(normal) => {
-                // Encode value first (A-XDR reverse order)
-                let normal_bytes = normal.encode()?;
-                encoder.encode_bytes(&normal_bytes)?;
-                // Encode choice tag (1 = Normal)
+                // Encode choice tag first (1 = Normal), same layout as GetRequest
                 encoder.encode_u8(1)?;
+                // Encode value after tag (as octet string with length prefix)
+                let normal_bytes = normal.encode()?;
+                encoder.encode_octet_string(&normal_bytes)?;
             }
             SetRequest::WithFirstDataBlock {
                 invoke_id_and_priority,
@@ -4272,27 +4672,26 @@ impl SetRequest {
                 last_block,
                 block_data,
             } => {
-                // Encode in reverse order (A-XDR)
-                // 1. block_data (octet string)
-                encoder.encode_octet_string(block_data)?;
-                // 2. last_block (boolean)
-                encoder.encode_bool(*last_block)?;
-                // 3. block_number (unsigned32)
-                encoder.encode_u32(*block_number)?;
-                // 4. access_selection (optional)
+                // Encode choice tag first (2 = WithFirstDataBlock)
+                encoder.encode_u8(2)?;
+                // 1. invoke_id_and_priority
+                let invoke_bytes = invoke_id_and_priority.encode()?;
+                encoder.encode_octet_string(&invoke_bytes)?;
+                // 2. cosem_attribute_descriptor (encoded)
+                let desc_bytes = cosem_attribute_descriptor.encode()?;
+                encoder.encode_octet_string(&desc_bytes)?;
+                // 3. access_selection (optional)
                 encoder.encode_bool(access_selection.is_some())?;
                 if let Some(access) = access_selection {
                     let access_bytes = access.encode()?;
                     encoder.encode_octet_string(&access_bytes)?;
                 }
-                // 5. cosem_attribute_descriptor (encoded)
-                let desc_bytes = cosem_attribute_descriptor.encode()?;
-                encoder.encode_octet_string(&desc_bytes)?;
-                // 6. invoke_id_and_priority
-                let invoke_bytes = invoke_id_and_priority.encode()?;
-                encoder.encode_octet_string(&invoke_bytes)?;
-                // Encode choice tag (2 = WithFirstDataBlock)
-                encoder.encode_u8(2)?;
+                // 4. block_number (unsigned32)
+                encoder.encode_u32(*block_number)?;
+                // 5. last_block (boolean)
+                encoder.encode_bool(*last_block)?;
+                // 6. block_data (octet string)
+                encoder.encode_octet_string(block_data)?;
             }
             SetRequest::WithDataBlock {
                 invoke_id_and_priority,
@@ -4300,25 +4699,24 @@ impl SetRequest {
                 last_block,
                 block_data,
             } => {
-                // Encode in reverse order (A-XDR)
-                // 1. block_data (octet string)
-                encoder.encode_octet_string(block_data)?;
-                // 2. last_block (boolean)
-                encoder.encode_bool(*last_block)?;
-                // 3. block_number (unsigned32)
-                encoder.encode_u32(*block_number)?;
-                // 4. invoke_id_and_priority
+                // Encode choice tag first (3 = WithDataBlock)
+                encoder.encode_u8(3)?;
+                // 1. invoke_id_and_priority
                 let invoke_bytes = invoke_id_and_priority.encode()?;
                 encoder.encode_octet_string(&invoke_bytes)?;
-                // Encode choice tag (3 = WithDataBlock)
-                encoder.encode_u8(3)?;
+                // 2. block_number (unsigned32)
+                encoder.encode_u32(*block_number)?;
+                // 3. last_block (boolean)
+                encoder.encode_bool(*last_block)?;
+                // 4. block_data (octet string)
+                encoder.encode_octet_string(block_data)?;
             }
             SetRequest::WithList(with_list) => {
-                // Encode value first (A-XDR reverse order)
-                let list_bytes = with_list.encode()?;
-                encoder.encode_bytes(&list_bytes)?;
-                // Encode choice tag (4 = WithList)
+                // Encode choice tag first (4 = WithList)
                 encoder.encode_u8(4)?;
+                // Encode value after tag (as octet string with length prefix)
+                let list_bytes = with_list.encode()?;
+                encoder.encode_octet_string(&list_bytes)?;
             }
         }
 
@@ -4810,25 +5208,31 @@ impl ActionRequestNormal {
     }
 
     /// Decode from A-XDR format
+    ///
+    /// Fields are read in the order `encode` writes them:
+    /// method_invocation_parameters, cosem_method_descriptor, then
+    /// invoke_id_and_priority. Nested structures are concatenated without
+    /// length prefixes, so consumed bytes are tracked by re-encoding
+    /// (same approach as `GetRequestNormal::decode`).
     pub fn decode(data: &[u8]) -> DlmsResult<Self> {
         let mut decoder = AxdrDecoder::new(data);
 
-        // Decode in reverse order
-        // 1. invoke_id_and_priority (InvokeIdAndPriority)
-        let invoke_bytes = decoder.decode_octet_string()?;
-        let invoke_id_and_priority = InvokeIdAndPriority::decode(&invoke_bytes)?;
-
-        // 2. cosem_method_descriptor (CosemMethodDescriptor)
-        let method_bytes = decoder.decode_octet_string()?;
-        let cosem_method_descriptor = CosemMethodDescriptor::decode(&method_bytes)?;
-
-        // 3. method_invocation_parameters (optional DataObject)
+        // 1. method_invocation_parameters (optional DataObject)
         let has_params = decoder.decode_bool()?;
         let method_invocation_parameters = if has_params {
             Some(decoder.decode_data_object()?)
         } else {
             None
         };
+        let mut pos = decoder.position();
+
+        // 2. cosem_method_descriptor (CosemMethodDescriptor)
+        let cosem_method_descriptor = CosemMethodDescriptor::decode(&data[pos..])?;
+        let method_encoded = cosem_method_descriptor.encode()?;
+        pos += method_encoded.len();
+
+        // 3. invoke_id_and_priority (InvokeIdAndPriority)
+        let invoke_id_and_priority = InvokeIdAndPriority::decode(&data[pos..])?;
 
         Ok(Self {
             invoke_id_and_priority,
@@ -4970,11 +5374,11 @@ impl ActionRequest {
 
         match self {
             ActionRequest::Normal(normal) => {
-                // Encode value first (A-XDR reverse order)
-                let normal_bytes = normal.encode()?;
-                encoder.encode_bytes(&normal_bytes)?;
-                // Encode choice tag (1 = Normal)
+                // Encode choice tag first (1 = Normal), same layout as GetRequest
                 encoder.encode_u8(1)?;
+                // Encode value after tag (as octet string with length prefix)
+                let normal_bytes = normal.encode()?;
+                encoder.encode_octet_string(&normal_bytes)?;
             }
         }
 
@@ -5582,6 +5986,59 @@ impl AccessRequestSpecification {
     }
 }
 
+/// Encode an A-XDR array length (short or long form)
+///
+/// Lengths below 128 use the short form (single byte); larger lengths use
+/// the long form (length-of-length byte with bit 7 set, followed by the
+/// length in big-endian bytes).
+fn encode_array_length(encoder: &mut AxdrEncoder, len: usize) -> DlmsResult<()> {
+    if len < 128 {
+        // Short form
+        encoder.encode_u8(len as u8)?;
+    } else if len <= 0xFF {
+        // Long form, 1 length byte
+        encoder.encode_u8(0x81)?;
+        encoder.encode_u8(len as u8)?;
+    } else if len <= 0xFFFF {
+        // Long form, 2 length bytes (big-endian)
+        encoder.encode_u8(0x82)?;
+        encoder.encode_u8((len >> 8) as u8)?;
+        encoder.encode_u8(len as u8)?;
+    } else {
+        return Err(DlmsError::InvalidData(format!(
+            "Array length {} exceeds maximum encodable length",
+            len
+        )));
+    }
+    Ok(())
+}
+
+/// Decode an A-XDR array length (short or long form)
+///
+/// `context` names the list being decoded and is included in error messages.
+fn decode_array_length(decoder: &mut AxdrDecoder, context: &str) -> DlmsResult<usize> {
+    let first_byte: u8 = decoder.decode_u8()?;
+    if (first_byte & 0x80) == 0 {
+        // Short form: length < 128
+        return Ok(first_byte as usize);
+    }
+
+    // Long form: length-of-length byte + length bytes
+    let length_of_length = (first_byte & 0x7F) as usize;
+    if length_of_length == 0 || length_of_length > 4 {
+        return Err(DlmsError::InvalidData(format!(
+            "{}: Invalid length-of-length: {}",
+            context, length_of_length
+        )));
+    }
+    let len_bytes = decoder.decode_fixed_bytes(length_of_length)?;
+    let mut len = 0usize;
+    for &byte in len_bytes.iter() {
+        len = (len << 8) | (byte as usize);
+    }
+    Ok(len)
+}
+
 /// Access Request PDU
 ///
 /// Used for accessing multiple attributes/methods in a single request.
@@ -5654,15 +6111,8 @@ impl AccessRequest {
         encoder.encode_octet_string(&invoke_bytes)?;
 
         // 2. access_request_list (array of AccessRequestSpecification)
-        // Encode array length: first byte indicates format
-        let list_len = self.access_request_list.len();
-        if list_len >= 128 {
-            return Err(DlmsError::InvalidData(format!(
-                "AccessRequest: access_request_list length ({}) exceeds maximum (127)",
-                list_len
-            )));
-        }
-        encoder.encode_u8(list_len as u8)?;
+        // Encode array length (short or long form)
+        encode_array_length(&mut encoder, self.access_request_list.len())?;
         // Encode each element (in forward order, as per A-XDR array encoding)
         for access_spec in self.access_request_list.iter() {
             let spec_bytes = access_spec.encode()?;
@@ -5682,16 +6132,8 @@ impl AccessRequest {
         let invoke_id_and_priority = InvokeIdAndPriority::decode(&invoke_bytes)?;
 
         // 2. access_request_list (array of AccessRequestSpecification)
-        // Decode array length: first byte indicates format
-        let first_byte: u8 = decoder.decode_u8()?;
-        let list_len: usize = if (first_byte & 0x80) == 0 {
-            // Short form: length < 128
-            first_byte as usize
-        } else {
-            return Err(DlmsError::InvalidData(
-                "AccessRequest: Long form array length not supported".to_string(),
-            ));
-        };
+        // Decode array length (short or long form)
+        let list_len = decode_array_length(&mut decoder, "AccessRequest")?;
 
         let mut access_request_list = Vec::with_capacity(list_len);
         for _ in 0..list_len {
@@ -5874,15 +6316,8 @@ impl AccessResponse {
         encoder.encode_octet_string(&invoke_bytes)?;
 
         // 2. access_response_list (array of AccessResponseSpecification)
-        // Encode array length: first byte indicates format
-        let list_len = self.access_response_list.len();
-        if list_len >= 128 {
-            return Err(DlmsError::InvalidData(format!(
-                "AccessResponse: access_response_list length ({}) exceeds maximum (127)",
-                list_len
-            )));
-        }
-        encoder.encode_u8(list_len as u8)?;
+        // Encode array length (short or long form)
+        encode_array_length(&mut encoder, self.access_response_list.len())?;
         // Encode each element (in forward order, as per A-XDR array encoding)
         for response_spec in self.access_response_list.iter() {
             let spec_bytes = response_spec.encode()?;
@@ -5902,16 +6337,8 @@ impl AccessResponse {
         let invoke_id_and_priority = InvokeIdAndPriority::decode(&invoke_bytes)?;
 
         // 2. access_response_list (array of AccessResponseSpecification)
-        // Decode array length: first byte indicates format
-        let first_byte: u8 = decoder.decode_u8()?;
-        let list_len: usize = if (first_byte & 0x80) == 0 {
-            // Short form: length < 128
-            first_byte as usize
-        } else {
-            return Err(DlmsError::InvalidData(
-                "AccessResponse: Long form array length not supported".to_string(),
-            ));
-        };
+        // Decode array length (short or long form)
+        let list_len = decode_array_length(&mut decoder, "AccessResponse")?;
 
         let mut access_response_list = Vec::with_capacity(list_len);
         for _ in 0..list_len {
@@ -0,0 +1,193 @@
+//! Block transfer state machine for DLMS/COSEM
+//!
+//! Both the GET and SET services move large attribute values in numbered
+//! blocks and share the same sequencing rules: blocks are numbered from 1,
+//! each block must arrive (or be acknowledged) in order, and the transfer
+//! ends when the `last_block` flag is set. This module centralizes that
+//! bookkeeping so the per-service code only has to match on its own
+//! response variant.
+//!
+//! # Design Philosophy
+//!
+//! The state machine is deliberately transport-agnostic: it tracks block
+//! numbers, the last-block flag, and accumulated bytes, but never touches
+//! PDUs. A GET client feeds it received block payloads; a SET client feeds
+//! it empty acknowledgments. Either way the caller asks
+//! [`BlockTransfer::expected_block_number`] for the next number to request
+//! or send.
+
+use dlms_core::{DlmsError, DlmsResult};
+
+/// Client-side block transfer state machine
+///
+/// Tracks the expected next block number, the last-block flag, and the
+/// accumulated payload across a GET or SET block transfer.
+///
+/// # Usage
+///
+/// - GET: call [`BlockTransfer::receive_block`] with each
+///   `GetResponse::WithDataBlock`; when it returns `true`, take the
+///   reassembled value with [`BlockTransfer::into_data`].
+/// - SET: call [`BlockTransfer::acknowledge_block`] with each
+///   `SetResponse::WithDataBlock`; the machine validates the
+///   acknowledgment sequence without accumulating data.
+#[derive(Debug, Clone)]
+pub struct BlockTransfer {
+    /// Next block number expected from the peer (blocks are numbered from 1)
+    expected_block: u32,
+    /// Whether the last block has been seen
+    complete: bool,
+    /// Accumulated block payloads
+    data: Vec<u8>,
+}
+
+impl BlockTransfer {
+    /// Create a new block transfer expecting block number 1
+    pub fn new() -> Self {
+        Self {
+            expected_block: 1,
+            complete: false,
+            data: Vec::new(),
+        }
+    }
+
+    /// Get the block number expected next
+    ///
+    /// This is the number to put in the next `GetRequest::Next` or
+    /// `SetRequest::WithDataBlock`.
+    pub fn expected_block_number(&self) -> u32 {
+        self.expected_block
+    }
+
+    /// Check whether the last block has been received or acknowledged
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Feed a received block into the transfer
+    ///
+    /// Returns `true` when this was the last block and the transfer is
+    /// complete.
+    ///
+    /// # Errors
+    /// Returns `DlmsError::InvalidData` if the transfer is already
+    /// complete or the block number does not match the expected sequence
+    pub fn receive_block(
+        &mut self,
+        block_number: u32,
+        last_block: bool,
+        block_data: &[u8],
+    ) -> DlmsResult<bool> {
+        if self.complete {
+            return Err(DlmsError::InvalidData(
+                "Block transfer already completed".to_string(),
+            ));
+        }
+        if block_number != self.expected_block {
+            return Err(DlmsError::InvalidData(format!(
+                "Invalid block number: received {}, expected {}",
+                block_number, self.expected_block
+            )));
+        }
+
+        self.data.extend_from_slice(block_data);
+        self.expected_block += 1;
+        self.complete = last_block;
+        Ok(self.complete)
+    }
+
+    /// Feed a block acknowledgment into the transfer
+    ///
+    /// Used on the SET path, where the server acknowledges each block
+    /// without returning data. Sequencing rules are identical to
+    /// [`BlockTransfer::receive_block`].
+    ///
+    /// # Errors
+    /// Returns `DlmsError::InvalidData` if the transfer is already
+    /// complete or the block number does not match the expected sequence
+    pub fn acknowledge_block(&mut self, block_number: u32, last_block: bool) -> DlmsResult<bool> {
+        self.receive_block(block_number, last_block, &[])
+    }
+
+    /// Get the payload accumulated so far
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consume the transfer and return the reassembled payload
+    ///
+    /// # Errors
+    /// Returns `DlmsError::InvalidData` if the last block has not been
+    /// received yet
+    pub fn into_data(self) -> DlmsResult<Vec<u8>> {
+        if !self.complete {
+            return Err(DlmsError::InvalidData(format!(
+                "Block transfer incomplete: still expecting block {}",
+                self.expected_block
+            )));
+        }
+        Ok(self.data)
+    }
+}
+
+impl Default for BlockTransfer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_transfer_normal_sequence() {
+        let mut transfer = BlockTransfer::new();
+        assert_eq!(transfer.expected_block_number(), 1);
+        assert!(!transfer.is_complete());
+
+        assert!(!transfer.receive_block(1, false, &[1, 2]).unwrap());
+        assert_eq!(transfer.expected_block_number(), 2);
+        assert!(!transfer.receive_block(2, false, &[3, 4]).unwrap());
+        assert!(transfer.receive_block(3, true, &[5]).unwrap());
+
+        assert!(transfer.is_complete());
+        assert_eq!(transfer.into_data().unwrap(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_block_transfer_out_of_order_block() {
+        let mut transfer = BlockTransfer::new();
+        transfer.receive_block(1, false, &[1]).unwrap();
+
+        // Skipping ahead (or replaying an old block) must be rejected
+        // without corrupting the accumulated data
+        assert!(transfer.receive_block(3, false, &[2]).is_err());
+        assert!(transfer.receive_block(1, false, &[2]).is_err());
+        assert_eq!(transfer.data(), &[1]);
+        assert_eq!(transfer.expected_block_number(), 2);
+    }
+
+    #[test]
+    fn test_block_transfer_rejects_blocks_after_completion() {
+        let mut transfer = BlockTransfer::new();
+        transfer.receive_block(1, true, &[1]).unwrap();
+        assert!(transfer.receive_block(2, false, &[2]).is_err());
+    }
+
+    #[test]
+    fn test_block_transfer_into_data_requires_completion() {
+        let mut transfer = BlockTransfer::new();
+        transfer.receive_block(1, false, &[1]).unwrap();
+        assert!(transfer.into_data().is_err());
+    }
+
+    #[test]
+    fn test_block_transfer_acknowledge_sequence() {
+        let mut transfer = BlockTransfer::new();
+        assert!(!transfer.acknowledge_block(1, false).unwrap());
+        assert!(transfer.acknowledge_block(2, true).unwrap());
+        assert!(transfer.is_complete());
+        assert!(transfer.data().is_empty());
+    }
+}
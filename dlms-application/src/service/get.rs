@@ -29,9 +29,10 @@
 //! ```
 
 use crate::pdu::{
-    GetRequest, GetResponse, GetDataResult,
+    data_access_result, GetRequest, GetResponse, GetDataResult,
     CosemAttributeDescriptor, SelectiveAccessDescriptor, InvokeIdAndPriority,
 };
+use crate::service::block_transfer::BlockTransfer;
 use dlms_core::{DlmsError, DlmsResult, DataObject};
 
 /// GET Service for DLMS/COSEM
@@ -255,6 +256,93 @@ impl GetService {
             )),
         }
     }
+
+    /// Issue a GET request with retries on temporary failures
+    ///
+    /// Re-issues the request through `transport` while the server answers
+    /// with `TEMPORARY_FAILURE` (code 2), sleeping `delay` between
+    /// attempts, for at most `retries` retries beyond the initial attempt.
+    /// Any other error, from the transport or the server, is surfaced
+    /// immediately. When the retry budget is exhausted the last
+    /// temporary-failure response is processed and returned as an error.
+    ///
+    /// # Arguments
+    /// * `request` - The GET request to (re-)issue
+    /// * `transport` - Closure exchanging a request for a response over
+    ///   the underlying connection
+    /// * `retries` - Maximum number of retries after the initial attempt
+    /// * `delay` - Pause between attempts
+    pub fn get_with_retry<F>(
+        &self,
+        request: &GetRequest,
+        mut transport: F,
+        retries: u32,
+        delay: std::time::Duration,
+    ) -> DlmsResult<DataObject>
+    where
+        F: FnMut(&GetRequest) -> DlmsResult<GetResponse>,
+    {
+        let mut retries_left = retries;
+        loop {
+            let response = transport(request)?;
+            let temporary = matches!(
+                &response,
+                GetResponse::Normal(normal)
+                    if normal.result.error_code() == Some(data_access_result::TEMPORARY_FAILURE)
+            );
+            if temporary && retries_left > 0 {
+                retries_left -= 1;
+                if !delay.is_zero() {
+                    std::thread::sleep(delay);
+                }
+                continue;
+            }
+            return Self::process_response(&response);
+        }
+    }
+
+    /// Process a GET response with data block through a block transfer
+    ///
+    /// Feeds the block into the supplied [`BlockTransfer`], which validates
+    /// the sequence and accumulates the payload across calls.
+    ///
+    /// # Arguments
+    /// * `response` - The GET response PDU (must be WithDataBlock variant)
+    /// * `transfer` - Block transfer state machine shared across the blocks
+    ///   of one GET operation
+    ///
+    /// # Returns
+    /// `Some(data)` with the reassembled attribute value when the last
+    /// block has been received, `None` while more blocks are expected
+    /// (request the next one with `GetRequest::Next` and
+    /// `transfer.expected_block_number()`)
+    ///
+    /// # Errors
+    /// Returns error if the response is not a WithDataBlock response or if
+    /// the block number does not match the expected sequence
+    pub fn process_response_with_blocks(
+        response: &GetResponse,
+        transfer: &mut BlockTransfer,
+    ) -> DlmsResult<Option<Vec<u8>>> {
+        match response {
+            GetResponse::WithDataBlock {
+                block_number,
+                last_block,
+                block_data,
+                ..
+            } => {
+                let complete = transfer.receive_block(*block_number, *last_block, block_data)?;
+                if complete {
+                    Ok(Some(transfer.data().to_vec()))
+                } else {
+                    Ok(None)
+                }
+            }
+            _ => Err(DlmsError::InvalidData(
+                "Expected WithDataBlock GET response".to_string(),
+            )),
+        }
+    }
 }
 
 impl Default for GetService {
@@ -291,4 +379,119 @@ mod tests {
         assert!(id1 >= 1 && id1 <= 127);
         assert!(id2 >= 1 && id2 <= 127);
     }
+
+    fn normal_response(result: GetDataResult) -> GetResponse {
+        GetResponse::new_normal(InvokeIdAndPriority::new(1, false).unwrap(), result)
+    }
+
+    #[test]
+    fn test_get_service_get_with_retry_recovers_from_temporary_failure() {
+        let service = GetService::new();
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let obis = ObisCode::new(1, 1, 1, 8, 0, 255);
+        let attr_desc = CosemAttributeDescriptor::new_logical_name(1, obis, 2).unwrap();
+        let request = GetService::create_normal_request(invoke, attr_desc, None).unwrap();
+
+        let mut calls = 0;
+        let data = service
+            .get_with_retry(
+                &request,
+                |_| {
+                    calls += 1;
+                    if calls <= 2 {
+                        Ok(normal_response(GetDataResult::new_error(
+                            data_access_result::TEMPORARY_FAILURE,
+                        )))
+                    } else {
+                        Ok(normal_response(GetDataResult::new_data(
+                            DataObject::Unsigned32(42),
+                        )))
+                    }
+                },
+                3,
+                std::time::Duration::ZERO,
+            )
+            .unwrap();
+
+        assert_eq!(calls, 3);
+        assert_eq!(data, DataObject::Unsigned32(42));
+    }
+
+    #[test]
+    fn test_get_service_get_with_retry_surfaces_permanent_error() {
+        let service = GetService::new();
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let obis = ObisCode::new(1, 1, 1, 8, 0, 255);
+        let attr_desc = CosemAttributeDescriptor::new_logical_name(1, obis, 2).unwrap();
+        let request = GetService::create_normal_request(invoke, attr_desc, None).unwrap();
+
+        let mut calls = 0;
+        let result = service.get_with_retry(
+            &request,
+            |_| {
+                calls += 1;
+                Ok(normal_response(GetDataResult::new_error(
+                    data_access_result::READ_WRITE_DENIED,
+                )))
+            },
+            3,
+            std::time::Duration::ZERO,
+        );
+
+        // A permanent error is not retried
+        assert_eq!(calls, 1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_service_get_with_retry_exhausts_budget() {
+        let service = GetService::new();
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let obis = ObisCode::new(1, 1, 1, 8, 0, 255);
+        let attr_desc = CosemAttributeDescriptor::new_logical_name(1, obis, 2).unwrap();
+        let request = GetService::create_normal_request(invoke, attr_desc, None).unwrap();
+
+        let mut calls = 0;
+        let result = service.get_with_retry(
+            &request,
+            |_| {
+                calls += 1;
+                Ok(normal_response(GetDataResult::new_error(
+                    data_access_result::TEMPORARY_FAILURE,
+                )))
+            },
+            2,
+            std::time::Duration::ZERO,
+        );
+
+        // Initial attempt plus two retries
+        assert_eq!(calls, 3);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_service_process_response_with_blocks() {
+        let mut transfer = BlockTransfer::new();
+        let first = GetResponse::WithDataBlock {
+            invoke_id_and_priority: InvokeIdAndPriority::new(1, false).unwrap(),
+            block_number: 1,
+            last_block: false,
+            block_data: vec![1, 2],
+        };
+        let last = GetResponse::WithDataBlock {
+            invoke_id_and_priority: InvokeIdAndPriority::new(1, false).unwrap(),
+            block_number: 2,
+            last_block: true,
+            block_data: vec![3],
+        };
+
+        assert!(GetService::process_response_with_blocks(&first, &mut transfer)
+            .unwrap()
+            .is_none());
+        assert_eq!(transfer.expected_block_number(), 2);
+        let data = GetService::process_response_with_blocks(&last, &mut transfer)
+            .unwrap()
+            .unwrap();
+        assert_eq!(data, vec![1, 2, 3]);
+    }
 }
@@ -22,6 +22,7 @@ use crate::pdu::{
     SetRequest, SetResponse, SetDataResult,
     CosemAttributeDescriptor, SelectiveAccessDescriptor, InvokeIdAndPriority,
 };
+use crate::service::block_transfer::BlockTransfer;
 use dlms_core::{DlmsError, DlmsResult, DataObject};
 
 /// SET Service for DLMS/COSEM
@@ -189,6 +190,41 @@ impl SetService {
     pub fn needs_more_blocks(response: &SetResponse) -> bool {
         matches!(response, SetResponse::WithDataBlock { last_block: false, .. })
     }
+
+    /// Process a SET block acknowledgment through a block transfer
+    ///
+    /// Feeds the acknowledgment into the supplied [`BlockTransfer`], which
+    /// validates that the server acknowledges blocks in the order they were
+    /// sent.
+    ///
+    /// # Arguments
+    /// * `response` - The SET response PDU (must be WithDataBlock variant)
+    /// * `transfer` - Block transfer state machine shared across the blocks
+    ///   of one SET operation
+    ///
+    /// # Returns
+    /// `true` when the last block has been acknowledged, `false` while more
+    /// blocks need to be sent (number the next one with
+    /// `transfer.expected_block_number()`)
+    ///
+    /// # Errors
+    /// Returns error if the response is not a WithDataBlock response or if
+    /// the acknowledged block number does not match the expected sequence
+    pub fn process_response_with_blocks(
+        response: &SetResponse,
+        transfer: &mut BlockTransfer,
+    ) -> DlmsResult<bool> {
+        match response {
+            SetResponse::WithDataBlock {
+                block_number,
+                last_block,
+                ..
+            } => transfer.acknowledge_block(*block_number, *last_block),
+            _ => Err(DlmsError::InvalidData(
+                "Expected WithDataBlock SET response".to_string(),
+            )),
+        }
+    }
 }
 
 impl Default for SetService {
@@ -129,8 +129,9 @@ impl<'a> AxdrDecoder<'a> {
 
     /// Decode a tag
     pub fn decode_tag(&mut self) -> DlmsResult<AxdrTag> {
+        let offset = self.position;
         let byte = self.read_byte()?;
-        AxdrTag::from_u8(byte)
+        AxdrTag::from_u8(byte).map_err(|e| Self::decode_error_at(offset, e))
     }
 
     /// Decode a boolean
@@ -211,26 +212,14 @@ impl<'a> AxdrDecoder<'a> {
 
     /// Decode an octet string
     pub fn decode_octet_string(&mut self) -> DlmsResult<Vec<u8>> {
-        let (len_enc, consumed) = LengthEncoding::decode(&self.buffer[self.position..])?;
-        self.position += consumed;
-        
-        let len = match len_enc {
-            LengthEncoding::Short(l) => l as usize,
-            LengthEncoding::Long(l) => l,
-        };
+        let len = self.decode_length()?;
         
         self.decode_fixed_bytes(len)
     }
 
     /// Decode a bit string
     pub fn decode_bit_string(&mut self) -> DlmsResult<BitString> {
-        let (len_enc, consumed) = LengthEncoding::decode(&self.buffer[self.position..])?;
-        self.position += consumed;
-        
-        let num_bits = match len_enc {
-            LengthEncoding::Short(l) => l as usize,
-            LengthEncoding::Long(l) => l,
-        };
+        let num_bits = self.decode_length()?;
         
         let num_bytes = (num_bits + 7) / 8;
         let bytes = self.decode_fixed_bytes(num_bytes)?;
@@ -240,14 +229,8 @@ impl<'a> AxdrDecoder<'a> {
 
     /// Decode an array
     pub fn decode_array(&mut self) -> DlmsResult<Vec<DataObject>> {
-        let (len_enc, consumed) = LengthEncoding::decode(&self.buffer[self.position..])?;
-        self.position += consumed;
-        
-        let len = match len_enc {
-            LengthEncoding::Short(l) => l as usize,
-            LengthEncoding::Long(l) => l,
-        };
-        
+        let len = self.decode_length()?;
+
         let mut array = Vec::with_capacity(len);
         for _ in 0..len {
             array.push(self.decode_data_object()?);
@@ -255,15 +238,42 @@ impl<'a> AxdrDecoder<'a> {
         Ok(array)
     }
 
+    /// Decode an array, streaming each element to a visitor
+    ///
+    /// Unlike [`decode_array`](Self::decode_array), the elements are not
+    /// collected: each decoded `DataObject` is handed to `visitor` and
+    /// dropped afterwards, so arbitrarily large arrays (e.g. Profile
+    /// Generic buffers) decode in constant memory. The decoder must be
+    /// positioned on the array tag.
+    ///
+    /// # Arguments
+    /// * `visitor` - Called once per element; an error aborts the decode
+    ///
+    /// # Returns
+    /// The number of elements decoded
+    pub fn decode_array_streaming<F>(&mut self, mut visitor: F) -> DlmsResult<usize>
+    where
+        F: FnMut(DataObject) -> DlmsResult<()>,
+    {
+        let offset = self.position;
+        let tag = self.decode_tag()?;
+        if tag != AxdrTag::Array {
+            return Err(DlmsError::DecodeError {
+                offset,
+                context: format!("Expected Array tag, got {:?}", tag),
+            });
+        }
+
+        let len = self.decode_length()?;
+        for _ in 0..len {
+            visitor(self.decode_data_object()?)?;
+        }
+        Ok(len)
+    }
+
     /// Decode a structure
     pub fn decode_structure(&mut self) -> DlmsResult<Vec<DataObject>> {
-        let (len_enc, consumed) = LengthEncoding::decode(&self.buffer[self.position..])?;
-        self.position += consumed;
-        
-        let len = match len_enc {
-            LengthEncoding::Short(l) => l as usize,
-            LengthEncoding::Long(l) => l,
-        };
+        let len = self.decode_length()?;
         
         let mut structure = Vec::with_capacity(len);
         for _ in 0..len {
@@ -272,14 +282,42 @@ impl<'a> AxdrDecoder<'a> {
         Ok(structure)
     }
 
+    /// Decode a length at the current position
+    ///
+    /// Failures are reported as [`DlmsError::DecodeError`] carrying the
+    /// byte offset of the malformed length.
+    fn decode_length(&mut self) -> DlmsResult<usize> {
+        let offset = self.position;
+        let (len_enc, consumed) = LengthEncoding::decode(&self.buffer[self.position..])
+            .map_err(|e| Self::decode_error_at(offset, e))?;
+        self.position += consumed;
+
+        Ok(match len_enc {
+            LengthEncoding::Short(l) => l as usize,
+            LengthEncoding::Long(l) => l,
+        })
+    }
+
+    /// Wrap an error with the byte offset where decoding failed
+    fn decode_error_at(offset: usize, error: DlmsError) -> DlmsError {
+        let context = match error {
+            DlmsError::InvalidData(msg) => msg,
+            other => other.to_string(),
+        };
+        DlmsError::DecodeError { offset, context }
+    }
+
     /// Decode fixed-length bytes
     pub fn decode_fixed_bytes(&mut self, len: usize) -> DlmsResult<Vec<u8>> {
         if self.position + len > self.buffer.len() {
-            return Err(DlmsError::InvalidData(format!(
-                "Not enough bytes: need {}, have {}",
-                len,
-                self.buffer.len() - self.position
-            )));
+            return Err(DlmsError::DecodeError {
+                offset: self.position,
+                context: format!(
+                    "Not enough bytes: need {}, have {}",
+                    len,
+                    self.buffer.len() - self.position
+                ),
+            });
         }
         
         let result = self.buffer[self.position..self.position + len].to_vec();
@@ -290,7 +328,10 @@ impl<'a> AxdrDecoder<'a> {
     /// Read a single byte
     fn read_byte(&mut self) -> DlmsResult<u8> {
         if self.position >= self.buffer.len() {
-            return Err(DlmsError::InvalidData("Not enough bytes".to_string()));
+            return Err(DlmsError::DecodeError {
+                offset: self.position,
+                context: "Not enough bytes".to_string(),
+            });
         }
         let byte = self.buffer[self.position];
         self.position += 1;
@@ -379,4 +420,113 @@ mod tests {
         let obj = decoder.decode_data_object().unwrap();
         assert!((obj.as_float64().unwrap() - 1.0).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_decode_array_streaming_sums_without_collecting() {
+        // Array of 10_000 Unsigned8 elements, hand-framed with a long-form
+        // length so nothing is materialized up front
+        let count = 10_000usize;
+        let mut bytes = vec![0x01];
+        bytes.extend_from_slice(&LengthEncoding::Long(count).encode());
+        for i in 0..count {
+            bytes.push(0x11);
+            bytes.push((i % 251) as u8);
+        }
+
+        let mut decoder = AxdrDecoder::new(&bytes);
+        let mut sum = 0u64;
+        let decoded = decoder
+            .decode_array_streaming(|obj| {
+                match obj {
+                    DataObject::Unsigned8(value) => sum += value as u64,
+                    other => panic!("Expected Unsigned8, got {:?}", other),
+                }
+                Ok(())
+            })
+            .unwrap();
+
+        let expected: u64 = (0..count as u64).map(|i| i % 251).sum();
+        assert_eq!(decoded, count);
+        assert_eq!(sum, expected);
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    fn test_decode_array_streaming_rejects_non_array() {
+        // A structure tag is not an array
+        let bytes = [0x02, 0x01, 0x11, 0x2A];
+        let mut decoder = AxdrDecoder::new(&bytes);
+
+        let result = decoder.decode_array_streaming(|_| Ok(()));
+        assert!(matches!(result, Err(DlmsError::DecodeError { offset: 0, .. })));
+    }
+
+    #[test]
+    fn test_decode_truncated_structure_reports_offset() {
+        // Structure of 2 elements: Unsigned8(42) followed by an Unsigned32
+        // whose value is cut off after two bytes
+        let bytes = [0x02, 0x02, 0x11, 0x2A, 0x06, 0x00, 0x00];
+        let mut decoder = AxdrDecoder::new(&bytes);
+
+        match decoder.decode_data_object() {
+            Err(DlmsError::DecodeError { offset, context }) => {
+                // The Unsigned32 value starts at byte 5, where the data ends
+                assert_eq!(offset, 5);
+                assert!(context.contains("Not enough bytes"), "context: {}", context);
+            }
+            other => panic!("Expected DecodeError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_array_of_structures_roundtrip() {
+        // Known-good bytes for an array of 3 {unsigned8, octet-string,
+        // boolean} structures, matching the encoder's canonical output
+        #[rustfmt::skip]
+        let bytes = [
+            0x01, 0x03, // array, 3 elements
+            0x02, 0x03, 0x11, 0x01, 0x09, 0x02, 0xDE, 0xAD, 0x03, 0xFF,
+            0x02, 0x03, 0x11, 0x02, 0x09, 0x02, 0xBE, 0xEF, 0x03, 0x00,
+            0x02, 0x03, 0x11, 0x03, 0x09, 0x03, 0x01, 0x02, 0x03, 0x03, 0xFF,
+        ];
+
+        let mut decoder = AxdrDecoder::new(&bytes);
+        let decoded = decoder.decode_data_object().unwrap();
+
+        let expected = DataObject::Array(vec![
+            DataObject::Structure(vec![
+                DataObject::Unsigned8(1),
+                DataObject::OctetString(vec![0xDE, 0xAD]),
+                DataObject::Boolean(true),
+            ]),
+            DataObject::Structure(vec![
+                DataObject::Unsigned8(2),
+                DataObject::OctetString(vec![0xBE, 0xEF]),
+                DataObject::Boolean(false),
+            ]),
+            DataObject::Structure(vec![
+                DataObject::Unsigned8(3),
+                DataObject::OctetString(vec![0x01, 0x02, 0x03]),
+                DataObject::Boolean(true),
+            ]),
+        ]);
+        assert_eq!(decoded, expected);
+
+        // Re-encoding reproduces the original bytes
+        let mut encoder = crate::axdr::AxdrEncoder::new();
+        encoder.encode_data_object(&decoded).unwrap();
+        assert_eq!(encoder.as_bytes(), &bytes);
+    }
+
+    #[test]
+    fn test_decode_unknown_tag_reports_offset() {
+        // Second structure element carries an unknown tag
+        let bytes = [0x02, 0x02, 0x11, 0x2A, 0x7F];
+        let mut decoder = AxdrDecoder::new(&bytes);
+
+        match decoder.decode_data_object() {
+            Err(DlmsError::DecodeError { offset, .. }) => assert_eq!(offset, 4),
+            other => panic!("Expected DecodeError, got {:?}", other),
+        }
+    }
 }
@@ -381,4 +381,98 @@ mod tests {
         // IEEE 754: 1.0 = 0x3FF0000000000000, tag for Float64 is 0x18
         assert_eq!(encoder.as_bytes(), &[0x18, 0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
     }
+
+    #[test]
+    fn test_encode_array_of_structures_known_bytes() {
+        // Array of 3 structures, each {unsigned8, octet-string, boolean}.
+        // Per Green Book A-XDR: array = tag 0x01 + element count, each
+        // structure = tag 0x02 + field count, fields follow tagged.
+        let array = DataObject::Array(vec![
+            DataObject::Structure(vec![
+                DataObject::Unsigned8(1),
+                DataObject::OctetString(vec![0xDE, 0xAD]),
+                DataObject::Boolean(true),
+            ]),
+            DataObject::Structure(vec![
+                DataObject::Unsigned8(2),
+                DataObject::OctetString(vec![0xBE, 0xEF]),
+                DataObject::Boolean(false),
+            ]),
+            DataObject::Structure(vec![
+                DataObject::Unsigned8(3),
+                DataObject::OctetString(vec![0x01, 0x02, 0x03]),
+                DataObject::Boolean(true),
+            ]),
+        ]);
+
+        let mut encoder = AxdrEncoder::new();
+        encoder.encode_data_object(&array).unwrap();
+
+        #[rustfmt::skip]
+        let expected = [
+            0x01, 0x03, // array, 3 elements
+            0x02, 0x03, 0x11, 0x01, 0x09, 0x02, 0xDE, 0xAD, 0x03, 0xFF,
+            0x02, 0x03, 0x11, 0x02, 0x09, 0x02, 0xBE, 0xEF, 0x03, 0x00,
+            0x02, 0x03, 0x11, 0x03, 0x09, 0x03, 0x01, 0x02, 0x03, 0x03, 0xFF,
+        ];
+        assert_eq!(encoder.as_bytes(), &expected);
+    }
+
+    fn assert_encoded_len_matches(obj: &DataObject) {
+        let mut encoder = AxdrEncoder::new();
+        encoder.encode_data_object(obj).unwrap();
+        assert_eq!(
+            obj.encoded_len(),
+            encoder.as_bytes().len(),
+            "encoded_len mismatch for {:?}",
+            obj
+        );
+    }
+
+    #[test]
+    fn test_encoded_len_matches_encoding_for_primitives() {
+        assert_encoded_len_matches(&DataObject::Null);
+        assert_encoded_len_matches(&DataObject::Boolean(true));
+        assert_encoded_len_matches(&DataObject::Integer8(-5));
+        assert_encoded_len_matches(&DataObject::Integer16(-300));
+        assert_encoded_len_matches(&DataObject::Integer32(1 << 20));
+        assert_encoded_len_matches(&DataObject::Integer64(i64::MIN));
+        assert_encoded_len_matches(&DataObject::Unsigned8(5));
+        assert_encoded_len_matches(&DataObject::Unsigned16(1000));
+        assert_encoded_len_matches(&DataObject::Unsigned32(u32::MAX));
+        assert_encoded_len_matches(&DataObject::Unsigned64(u64::MAX));
+        assert_encoded_len_matches(&DataObject::Float32(1.5));
+        assert_encoded_len_matches(&DataObject::Float64(-2.25));
+        assert_encoded_len_matches(&DataObject::Enumerate(7));
+        assert_encoded_len_matches(&DataObject::Bcd(0x42));
+        assert_encoded_len_matches(&DataObject::OctetString(vec![0xAB; 10]));
+        // 200-byte string exercises the long-form length prefix
+        assert_encoded_len_matches(&DataObject::OctetString(vec![0xCD; 200]));
+        assert_encoded_len_matches(&DataObject::VisibleString(b"hello".to_vec()));
+    }
+
+    #[test]
+    fn test_encoded_len_matches_encoding_for_structure() {
+        let structure = DataObject::Structure(vec![
+            DataObject::Unsigned16(100),
+            DataObject::OctetString(vec![0x01, 0x02, 0x03]),
+            DataObject::Boolean(false),
+        ]);
+        assert_encoded_len_matches(&structure);
+    }
+
+    #[test]
+    fn test_encoded_len_matches_encoding_for_nested_array() {
+        let array = DataObject::Array(vec![
+            DataObject::Structure(vec![
+                DataObject::Unsigned32(1),
+                DataObject::OctetString(vec![0xDE, 0xAD]),
+            ]),
+            DataObject::Structure(vec![
+                DataObject::Unsigned32(2),
+                DataObject::OctetString(vec![0xFF; 150]),
+            ]),
+        ]);
+        assert_encoded_len_matches(&array);
+    }
 }
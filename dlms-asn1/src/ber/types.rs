@@ -211,15 +211,16 @@ impl BerTag {
             while has_more && pos < data.len() {
                 let byte = data[pos];
                 has_more = (byte & 0x80) != 0;
-                tag_number = (tag_number << 7) | ((byte & 0x7F) as u32);
-                pos += 1;
 
-                // Prevent infinite loop (max 5 bytes for u32)
-                if pos > 5 {
+                // A u32 tag number needs at most 5 continuation bytes;
+                // guard the shift so crafted input cannot overflow
+                if tag_number > (u32::MAX >> 7) {
                     return Err(DlmsError::InvalidData(
                         "Tag number too large or invalid encoding".to_string(),
                     ));
                 }
+                tag_number = (tag_number << 7) | ((byte & 0x7F) as u32);
+                pos += 1;
             }
 
             if has_more {
@@ -415,6 +416,43 @@ mod tests {
         assert_eq!(tag.number(), 2);
     }
 
+    #[test]
+    fn test_ber_tag_extended_form_roundtrip() {
+        // 30 stays short form; 31 and above use the extended form
+        for number in [30u32, 31, 127, 128, 16383] {
+            let tag = BerTag::context_specific(true, number);
+            let encoded = tag.encode();
+            if number <= 30 {
+                assert_eq!(encoded.len(), 1);
+            } else {
+                assert_eq!(encoded[0] & 0x1F, 0x1F); // All tag bits set
+            }
+
+            let (decoded, consumed) = BerTag::decode(&encoded).unwrap();
+            assert_eq!(consumed, encoded.len());
+            assert_eq!(decoded, tag, "roundtrip failed for tag number {}", number);
+        }
+    }
+
+    #[test]
+    fn test_ber_tag_extended_form_encoding_bytes() {
+        // 128 = 0b1_0000000: high septet with continuation bit, then low septet
+        let tag = BerTag::context_specific(false, 128);
+        assert_eq!(tag.encode(), vec![0x9F, 0x81, 0x00]);
+
+        // 16383 = 0x3FFF: two full septets
+        let tag = BerTag::context_specific(false, 16383);
+        assert_eq!(tag.encode(), vec![0x9F, 0xFF, 0x7F]);
+    }
+
+    #[test]
+    fn test_ber_tag_decode_incomplete_extended() {
+        // Continuation bit set on the last available byte
+        assert!(BerTag::decode(&[0x9F, 0x81]).is_err());
+        // Continuation bytes overflowing a u32 tag number
+        assert!(BerTag::decode(&[0x9F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F]).is_err());
+    }
+
     #[test]
     fn test_ber_length_short() {
         let length = BerLength::new(100);
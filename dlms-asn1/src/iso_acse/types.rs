@@ -1636,8 +1636,8 @@ impl AuthenticationValue {
         }
 
         let length_byte = data[1];
-        let (length_bytes, pos) = if length_byte < 128 {
-            (2usize, 2)
+        let (value_length, pos) = if length_byte < 128 {
+            (length_byte as usize, 2)
         } else {
             let num_length_bytes = (length_byte & 0x7F) as usize;
             if data.len() < 2 + num_length_bytes {
@@ -1650,29 +1650,16 @@ impl AuthenticationValue {
             for i in 0..num_length_bytes {
                 length = (length << 8) | (data[2 + i] as usize);
             }
-            (2 + num_length_bytes, 2 + num_length_bytes)
+            (length, 2 + num_length_bytes)
         };
 
-        // Extract value data
-        let _value_length = if length_bytes == 2 {
-            length_byte as usize
-        } else {
-            // Need to parse long form
-            let num_length_bytes = (length_byte & 0x7F) as usize;
-            let mut length = 0usize;
-            for i in 0..num_length_bytes {
-                length = (length << 8) | (data[2 + i] as usize);
-            }
-            length
-        };
-
-        if data.len() < pos + length_bytes {
+        if data.len() < pos + value_length {
             return Err(DlmsError::InvalidData(
                 "Incomplete AuthenticationValue (truncated)".to_string(),
             ));
         }
 
-        let value_data = &data[pos..pos + length_bytes];
+        let value_data = &data[pos..pos + value_length];
 
         // Decode based on tag number
         let mut decoder = BerDecoder::new(value_data);
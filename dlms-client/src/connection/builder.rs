@@ -25,7 +25,9 @@ use super::{LnConnection, LnConnectionConfig, SnConnection, SnConnectionConfig};
 use dlms_core::DlmsResult;
 use dlms_session::hdlc::HdlcAddress;
 use dlms_security::SecuritySuite;
+use dlms_security::auth_flow::AuthenticationMechanism;
 use dlms_application::pdu::Conformance;
+use std::time::Duration;
 
 /// Connection builder for creating DLMS/COSEM connections
 ///
@@ -60,12 +62,16 @@ pub struct ConnectionBuilder {
     logical_device_id: Option<u16>,
     /// Security suite (optional)
     security_suite: Option<SecuritySuite>,
+    /// Dedicated session key (optional)
+    dedicated_key: Option<Vec<u8>>,
     /// Conformance bits
     conformance: Conformance,
     /// Maximum PDU size
     max_pdu_size: u16,
     /// DLMS version
     dlms_version: u8,
+    /// Request/response timeout
+    request_timeout: Duration,
 }
 
 /// Transport type configuration
@@ -104,9 +110,11 @@ impl ConnectionBuilder {
             client_id: Some(0x10),
             logical_device_id: Some(0x01),
             security_suite: None,
+            dedicated_key: None,
             conformance: Conformance::default(),
             max_pdu_size: 1024,
             dlms_version: 6,
+            request_timeout: Duration::from_secs(30),
         }
     }
 
@@ -199,6 +207,23 @@ impl ConnectionBuilder {
         self
     }
 
+    /// Configure a dedicated session key
+    ///
+    /// # Arguments
+    /// * `key` - Dedicated session key (16 bytes)
+    ///
+    /// # Returns
+    /// Self for method chaining
+    ///
+    /// # Note
+    /// The dedicated key is carried in the InitiateRequest and used as the
+    /// session encryption key for ciphered xDLMS APDUs instead of the
+    /// derived unicast key.
+    pub fn dedicated_key(mut self, key: Vec<u8>) -> Self {
+        self.dedicated_key = Some(key);
+        self
+    }
+
     /// Configure conformance bits
     ///
     /// # Arguments
@@ -247,6 +272,23 @@ impl ConnectionBuilder {
         self
     }
 
+    /// Configure the request/response timeout
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for a server response
+    ///
+    /// # Returns
+    /// Self for method chaining
+    ///
+    /// # Note
+    /// Default is 30 seconds. GET/SET/ACTION operations fail with
+    /// `DlmsError::Timeout` when the server does not answer in time;
+    /// the association remains open so the operation can be retried.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
     /// Build a Logical Name (LN) connection
     ///
     /// # Returns
@@ -295,9 +337,13 @@ impl ConnectionBuilder {
             client_id: self.client_id,
             logical_device_id: self.logical_device_id,
             security_suite: self.security_suite,
+            dedicated_key: self.dedicated_key,
+            authentication_mechanism: AuthenticationMechanism::None,
+            authentication_secret: None,
             conformance: self.conformance,
             max_pdu_size: self.max_pdu_size,
             dlms_version: self.dlms_version,
+            request_timeout: self.request_timeout,
         };
 
         // Create connection
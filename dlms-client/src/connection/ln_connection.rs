@@ -32,17 +32,29 @@ use super::connection::{Connection, ConnectionState};
 use dlms_application::service::{GetService, SetService, ActionService};
 use dlms_application::pdu::{
     InitiateRequest, InitiateResponse, GetRequest, GetResponse, SetRequest, SetResponse,
-    ActionRequest, ActionResponse, CosemAttributeDescriptor, CosemMethodDescriptor,
-    InvokeIdAndPriority, Conformance,
+    SetDataResult, ActionRequest, ActionResponse, CosemAttributeDescriptor,
+    CosemMethodDescriptor, EventNotification, InvokeIdAndPriority, Conformance,
+    SelectiveAccessDescriptor, AccessRequest, AccessResponse, AccessRequestSpecification,
+    AccessResponseSpecification,
 };
 use dlms_application::addressing::LogicalNameReference;
+use dlms_core::datatypes::CosemDateTime;
 use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
 use dlms_session::hdlc::{HdlcConnection, HdlcAddress};
 use dlms_session::wrapper::WrapperSession;
 use dlms_transport::{TcpTransport, SerialTransport, TcpSettings, SerialSettings};
 use dlms_security::SecuritySuite;
+use dlms_security::auth_flow::{AuthenticationFlow, AuthenticationMechanism};
+use dlms_asn1::iso_acse::{
+    AARQApdu, AAREApdu, ACSERequirements, AssociateResult, AuthenticationValue, MechanismName,
+};
 use std::time::Duration;
 use std::net::SocketAddr;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
+
+/// APDU choice tag of the unconfirmed EventNotificationRequest (IEC 62056-5-3)
+const EVENT_NOTIFICATION_TAG: u8 = 194;
 
 /// Session layer type
 ///
@@ -86,12 +98,36 @@ pub struct LnConnectionConfig {
     pub logical_device_id: Option<u16>,
     /// Security suite configuration (optional, for future use)
     pub security_suite: Option<SecuritySuite>,
+    /// Dedicated session key (optional, 16 bytes)
+    ///
+    /// When set, it is carried in the InitiateRequest and used as the
+    /// session encryption key for ciphered xDLMS APDUs instead of the
+    /// derived unicast key (install it via `XdlmsContext::set_dedicated_key`).
+    pub dedicated_key: Option<Vec<u8>>,
+    /// Authentication mechanism for the association
+    ///
+    /// `None` (lowest level security) skips the ACSE handshake entirely and
+    /// sends a bare InitiateRequest. Any other mechanism wraps the
+    /// InitiateRequest in an AARQ carrying the matching mechanism OID and
+    /// ACSE authentication requirements (see `with_authentication`).
+    pub authentication_mechanism: AuthenticationMechanism,
+    /// Authentication secret
+    ///
+    /// For LLS this is the password carried in the AARQ. For HLS-GMAC it is
+    /// the key material used when replying to the server challenge.
+    pub authentication_secret: Option<Vec<u8>>,
     /// Conformance bits (client capabilities)
     pub conformance: Conformance,
     /// Maximum PDU size
     pub max_pdu_size: u16,
     /// DLMS version
     pub dlms_version: u8,
+    /// Timeout for a complete request/response exchange
+    ///
+    /// Applies to GET/SET/ACTION operations. When the server does not answer
+    /// within this duration the operation fails with `DlmsError::Timeout`,
+    /// leaving the association open so the request can be retried.
+    pub request_timeout: Duration,
 }
 
 impl Default for LnConnectionConfig {
@@ -103,13 +139,46 @@ impl Default for LnConnectionConfig {
             client_id: Some(0x10),
             logical_device_id: Some(0x01),
             security_suite: None,
+            dedicated_key: None,
+            authentication_mechanism: AuthenticationMechanism::None,
+            authentication_secret: None,
             conformance: Conformance::default(),
             max_pdu_size: 1024,
             dlms_version: 6,
+            request_timeout: Duration::from_secs(30),
         }
     }
 }
 
+impl LnConnectionConfig {
+    /// Configure authentication for the association
+    ///
+    /// Sets the authentication mechanism together with its secret: the LLS
+    /// password for `LowLevel`, or the GMAC key material for the HLS
+    /// mechanisms. During `open()` the connection then sends an AARQ with
+    /// the ACSE authentication bit set, the matching mechanism OID, and the
+    /// appropriate calling authentication value (the password for LLS, a
+    /// freshly generated CtoS challenge for HLS-GMAC).
+    ///
+    /// # Arguments
+    /// * `mechanism` - Authentication mechanism to negotiate
+    /// * `secret` - LLS password or HLS key material
+    pub fn with_authentication(
+        mut self,
+        mechanism: AuthenticationMechanism,
+        secret: Vec<u8>,
+    ) -> Self {
+        self.authentication_mechanism = mechanism;
+        self.authentication_secret = Some(secret);
+        self
+    }
+}
+
+/// Callback invoked on every connection state transition
+///
+/// Receives the previous and the new `ConnectionState`.
+pub type StateChangeCallback = Box<dyn Fn(ConnectionState, ConnectionState) + Send + Sync>;
+
 /// Logical Name (LN) connection implementation
 ///
 /// Provides a high-level interface for DLMS/COSEM operations using
@@ -117,6 +186,8 @@ impl Default for LnConnectionConfig {
 pub struct LnConnection {
     /// Connection state
     state: ConnectionState,
+    /// Observer notified on every state transition
+    state_change_callback: Option<StateChangeCallback>,
     /// Session layer (HDLC or Wrapper)
     session: Option<SessionLayer>,
     /// GET service
@@ -131,6 +202,10 @@ pub struct LnConnection {
     negotiated_conformance: Option<Conformance>,
     /// Server max PDU size (from InitiateResponse)
     server_max_pdu_size: Option<u16>,
+    /// HLS authentication flow (prepared when an HLS mechanism is configured)
+    auth_flow: Option<AuthenticationFlow>,
+    /// Server challenge (StoC) received in the AARE for HLS mechanisms
+    server_challenge: Option<Vec<u8>>,
 }
 
 impl LnConnection {
@@ -138,6 +213,7 @@ impl LnConnection {
     pub fn new(config: LnConnectionConfig) -> Self {
         Self {
             state: ConnectionState::Closed,
+            state_change_callback: None,
             session: None,
             get_service: GetService::new(),
             set_service: SetService::new(),
@@ -145,7 +221,79 @@ impl LnConnection {
             config,
             negotiated_conformance: None,
             server_max_pdu_size: None,
+            auth_flow: None,
+            server_challenge: None,
+        }
+    }
+
+    /// Register a callback fired on every connection state transition
+    ///
+    /// The callback receives the previous and the new state, e.g.
+    /// `Closed -> SessionOpen -> Ready` during `open()`. It is invoked
+    /// synchronously from the connection's own methods, so it should be
+    /// cheap (push to a channel, bump a metric); registering a new
+    /// callback replaces the previous one.
+    pub fn on_state_change(&mut self, callback: StateChangeCallback) {
+        self.state_change_callback = Some(callback);
+    }
+
+    /// Move to `new_state`, notifying the registered observer on change
+    fn set_state(&mut self, new_state: ConnectionState) {
+        let old_state = self.state;
+        self.state = new_state;
+        if old_state != new_state
+            && let Some(callback) = &self.state_change_callback
+        {
+            callback(old_state, new_state);
+        }
+    }
+
+    /// Build the AARQ for an authenticated association
+    ///
+    /// Sets the ACSE authentication requirement, the mechanism OID matching
+    /// the configured `AuthenticationMechanism`, and the calling
+    /// authentication value: the LLS password, or a freshly generated CtoS
+    /// challenge for HLS-GMAC (the prepared `AuthenticationFlow` is kept on
+    /// the connection so the server challenge can be answered later).
+    ///
+    /// The InitiateRequest is carried in the AARQ user-information field,
+    /// replacing the bare InitiateRequest used for unauthenticated
+    /// associations.
+    fn build_aarq(&mut self, initiate_request: &InitiateRequest) -> DlmsResult<Vec<u8>> {
+        let secret = self.config.authentication_secret.as_ref().ok_or_else(|| {
+            DlmsError::Security(format!(
+                "Authentication mechanism {:?} requires a secret (use LnConnectionConfig::with_authentication)",
+                self.config.authentication_mechanism
+            ))
+        })?;
+
+        // Default LN application context (no ciphering)
+        let mut aarq = AARQApdu::new(vec![1, 0, 17, 0, 0, 8, 0, 101]);
+        aarq.sender_acse_requirements = Some(ACSERequirements::default_auth());
+
+        match self.config.authentication_mechanism {
+            AuthenticationMechanism::None => {
+                return Err(DlmsError::Security(
+                    "build_aarq called without an authentication mechanism".to_string(),
+                ));
+            }
+            AuthenticationMechanism::LowLevel => {
+                aarq.mechanism_name = Some(MechanismName::low_level());
+                aarq.calling_authentication_value =
+                    Some(AuthenticationValue::OctetString(secret.clone()));
+            }
+            AuthenticationMechanism::Hls5Gmac | AuthenticationMechanism::Gmac => {
+                let mut flow = AuthenticationFlow::new(self.config.authentication_mechanism);
+                let challenge = flow.generate_challenge(16)?;
+                self.auth_flow = Some(flow);
+                aarq.mechanism_name = Some(MechanismName::high_gmac());
+                aarq.calling_authentication_value =
+                    Some(AuthenticationValue::OctetString(challenge));
+            }
         }
+
+        aarq.set_initiate_request(initiate_request.encode()?);
+        aarq.encode()
     }
 
     /// Send data through the session layer
@@ -194,6 +342,444 @@ impl LnConnection {
             ))),
         }
     }
+
+    /// Drain a GetResponse-WithDataBlock sequence started by a Normal GET
+    ///
+    /// Requests the remaining blocks with `GetRequest::Next` (same invoke
+    /// id, so the server can find its transfer state), concatenates the
+    /// block data and decodes the reassembled bytes as the attribute
+    /// value. Out-of-sequence block numbers abort the transfer.
+    async fn complete_get_block_transfer(
+        &mut self,
+        invoke_id_and_priority: InvokeIdAndPriority,
+        mut block_number: u32,
+        mut last_block: bool,
+        block_data: Vec<u8>,
+    ) -> DlmsResult<DataObject> {
+        let mut buffer = block_data;
+
+        while !last_block {
+            let next = GetRequest::Next {
+                invoke_id_and_priority,
+                block_number: block_number + 1,
+            };
+            let request_bytes = next.encode()?;
+            let response_bytes = self.send_request_timed(&request_bytes).await?;
+
+            match GetResponse::decode(&response_bytes)? {
+                GetResponse::WithDataBlock {
+                    block_number: received_number,
+                    last_block: received_last,
+                    block_data,
+                    ..
+                } => {
+                    if received_number != block_number + 1 {
+                        return Err(DlmsError::Protocol(format!(
+                            "Out-of-sequence data block: expected {}, got {}",
+                            block_number + 1,
+                            received_number
+                        )));
+                    }
+                    buffer.extend_from_slice(&block_data);
+                    block_number = received_number;
+                    last_block = received_last;
+                }
+                other => {
+                    return Err(DlmsError::Protocol(format!(
+                        "Expected WithDataBlock continuation, got {:?}",
+                        other
+                    )));
+                }
+            }
+        }
+
+        let mut decoder = dlms_asn1::axdr::AxdrDecoder::new(&buffer);
+        decoder.decode_data_object()
+    }
+
+    /// Allocate the next GET invoke id (shared with the request builder)
+    pub(crate) fn next_get_invoke_id(&mut self) -> u8 {
+        self.get_service.next_invoke_id()
+    }
+
+    /// Reject outgoing APDUs larger than the negotiated server PDU size
+    ///
+    /// The limit is only known after association (`server_max_receive_pdu_size`
+    /// from the InitiateResponse); a value of 0 means the server did not state
+    /// a limit. Oversized GET/SET payloads should go through block transfer
+    /// (`BlockTransferWriter`) instead of a single APDU.
+    fn check_outgoing_apdu_size(&self, apdu_size: usize) -> DlmsResult<()> {
+        if let Some(max_size) = self.server_max_pdu_size
+            && max_size > 0
+            && apdu_size > usize::from(max_size)
+        {
+            return Err(DlmsError::LengthMismatch(format!(
+                "Encoded APDU is {} bytes but the server accepts at most {} bytes; use block transfer for large GET/SET payloads",
+                apdu_size, max_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reject an operation the negotiated conformance does not cover
+    ///
+    /// Without this guard an unsupported request only fails once the server
+    /// answers with an obscure error or exception. The check is skipped
+    /// before association completes (no conformance negotiated yet), so
+    /// connection setup is unaffected.
+    fn check_conformance(
+        &self,
+        supported: impl Fn(&Conformance) -> bool,
+        feature: &str,
+    ) -> DlmsResult<()> {
+        if let Some(conformance) = &self.negotiated_conformance
+            && !supported(conformance)
+        {
+            return Err(DlmsError::InvalidData(format!(
+                "{} not supported by server",
+                feature
+            )));
+        }
+        Ok(())
+    }
+
+    /// Send a request and await the response, bounded by the configured timeout
+    ///
+    /// Wraps the whole send-and-await exchange in `tokio::time::timeout` so a
+    /// non-responding meter cannot hang the call. On timeout the association
+    /// stays in the Ready state, so the operation can simply be retried.
+    async fn send_request_timed(&mut self, request: &[u8]) -> DlmsResult<Vec<u8>> {
+        let request_timeout = self.config.request_timeout;
+        tokio::time::timeout(
+            request_timeout,
+            self.send_request(request, Some(request_timeout)),
+        )
+        .await
+        .map_err(|_| DlmsError::Timeout)?
+    }
+
+    /// Send a SetRequest::Normal and return the decoded response
+    async fn set_request_normal(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        attribute_id: u8,
+        value: DataObject,
+    ) -> DlmsResult<SetResponse> {
+        if !self.is_open() {
+            return Err(DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Connection is not open",
+            )));
+        }
+        self.check_conformance(Conformance::set, "SET service")?;
+
+        // Create attribute descriptor with LN addressing
+        let ln_ref = LogicalNameReference::new(class_id, obis_code, attribute_id)?;
+        let attribute_descriptor = CosemAttributeDescriptor::LogicalName(ln_ref);
+
+        // Create SET request using SetService
+        let invoke_id = self.set_service.next_invoke_id();
+        let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
+            .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "dlms.set",
+            invoke_id,
+            obis = %obis_code,
+            class_id,
+            attribute_id,
+            result = tracing::field::Empty,
+        );
+
+        let request = SetRequest::new_normal(
+            invoke_id_and_priority,
+            attribute_descriptor,
+            None, // No selective access
+            value,
+        );
+
+        // Encode request
+        let request_bytes = request.encode()?;
+
+        // Send request and receive response inside the span
+        let send = self.send_request_timed(&request_bytes);
+        #[cfg(feature = "tracing")]
+        let send = send.instrument(span.clone());
+        let response_bytes = send.await?;
+
+        // Decode response
+        let result = SetResponse::decode(&response_bytes);
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => span.record("result", "success"),
+            Err(e) => span.record("result", tracing::field::debug(e)),
+        };
+
+        result
+    }
+
+    // ============================================================
+    // Typed SET helpers
+    // ============================================================
+    //
+    // Symmetric to the typed read helpers in the client API: the value is
+    // converted to the matching DataObject and sent as a SetRequest::Normal.
+    // The raw SetDataResult is returned so callers can inspect the access
+    // result (e.g. READ_WRITE_DENIED) without string-matching an error.
+
+    /// SET an Unsigned32 attribute value
+    pub async fn set_u32(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        attribute_id: u8,
+        value: u32,
+    ) -> DlmsResult<SetDataResult> {
+        let response = self
+            .set_request_normal(obis_code, class_id, attribute_id, DataObject::Unsigned32(value))
+            .await?;
+        SetService::process_response_result(&response)
+    }
+
+    /// SET a Boolean attribute value
+    pub async fn set_bool(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        attribute_id: u8,
+        value: bool,
+    ) -> DlmsResult<SetDataResult> {
+        let response = self
+            .set_request_normal(obis_code, class_id, attribute_id, DataObject::Boolean(value))
+            .await?;
+        SetService::process_response_result(&response)
+    }
+
+    /// SET a VisibleString attribute value
+    pub async fn set_string(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        attribute_id: u8,
+        value: &str,
+    ) -> DlmsResult<SetDataResult> {
+        let response = self
+            .set_request_normal(
+                obis_code,
+                class_id,
+                attribute_id,
+                DataObject::VisibleString(value.as_bytes().to_vec()),
+            )
+            .await?;
+        SetService::process_response_result(&response)
+    }
+
+    /// SET a DateTime attribute value
+    pub async fn set_datetime(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        attribute_id: u8,
+        value: CosemDateTime,
+    ) -> DlmsResult<SetDataResult> {
+        let response = self
+            .set_request_normal(obis_code, class_id, attribute_id, DataObject::DateTime(value))
+            .await?;
+        SetService::process_response_result(&response)
+    }
+
+    // ============================================================
+    // Typed ACTION helpers
+    // ============================================================
+    //
+    // Thin wrappers around the ACTION service: parameters go in as an
+    // optional DataObject and the returned data (if any) comes back out.
+    // An ActionResult::DataAccessResult is surfaced as an error.
+
+    /// Invoke a COSEM method, returning the data from the response (if any)
+    pub async fn action(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        method_id: u8,
+        parameters: Option<DataObject>,
+    ) -> DlmsResult<Option<DataObject>> {
+        self.invoke_method(obis_code, class_id, method_id, parameters)
+            .await
+    }
+
+    /// Invoke a COSEM method that takes no parameters
+    pub async fn action_no_params(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        method_id: u8,
+    ) -> DlmsResult<Option<DataObject>> {
+        self.action(obis_code, class_id, method_id, None).await
+    }
+
+    /// Invoke a COSEM method and expect an Unsigned32 result
+    pub async fn action_u32_result(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        method_id: u8,
+        parameters: Option<DataObject>,
+    ) -> DlmsResult<u32> {
+        match self.action(obis_code, class_id, method_id, parameters).await? {
+            Some(DataObject::Unsigned32(value)) => Ok(value),
+            Some(other) => Err(DlmsError::InvalidData(format!(
+                "ACTION returned unexpected data type: {:?} (expected Unsigned32)",
+                other
+            ))),
+            None => Err(DlmsError::InvalidData(
+                "ACTION returned no data (expected Unsigned32)".to_string(),
+            )),
+        }
+    }
+
+    /// GET an attribute through a selective access descriptor
+    ///
+    /// Like [`Connection::get_attribute`] but restricts the read to the
+    /// range or entries selected by `access_selection` (e.g. a Profile
+    /// Generic buffer slice). Rejected up front when the negotiated
+    /// conformance does not include selective access.
+    pub async fn get_attribute_with_selective_access(
+        &mut self,
+        obis_code: ObisCode,
+        class_id: u16,
+        attribute_id: u8,
+        access_selection: SelectiveAccessDescriptor,
+    ) -> DlmsResult<DataObject> {
+        if !self.is_open() {
+            return Err(DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Connection is not open",
+            )));
+        }
+        self.check_conformance(Conformance::selective_access, "selective access")?;
+
+        let ln_ref = LogicalNameReference::new(class_id, obis_code, attribute_id)?;
+        let attribute_descriptor = CosemAttributeDescriptor::LogicalName(ln_ref);
+
+        let invoke_id = self.get_service.next_invoke_id();
+        let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
+            .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
+
+        let request = GetRequest::new_normal(
+            invoke_id_and_priority,
+            attribute_descriptor,
+            Some(access_selection),
+        );
+
+        let request_bytes = request.encode()?;
+        let response_bytes = self.send_request_timed(&request_bytes).await?;
+        let response = GetResponse::decode(&response_bytes)?;
+        GetService::process_response(&response)
+    }
+
+    /// Execute a batch of GET/SET/ACTION operations in one exchange
+    ///
+    /// Builds an AccessRequest from `specs` and parses the AccessResponse.
+    /// The Access service correlates entries positionally: the result at
+    /// index `i` belongs to the specification at index `i`, so mixed
+    /// batches stay unambiguous.
+    ///
+    /// # Errors
+    /// Returns error if the connection is not open, the server did not
+    /// negotiate the ACCESS service, `specs` is empty, or the response
+    /// carries a different number of results than requested
+    pub async fn access(
+        &mut self,
+        specs: Vec<AccessRequestSpecification>,
+    ) -> DlmsResult<Vec<AccessResponseSpecification>> {
+        if !self.is_open() {
+            return Err(DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Connection is not open",
+            )));
+        }
+        self.check_conformance(Conformance::access, "ACCESS service")?;
+
+        let invoke_id = self.get_service.next_invoke_id();
+        let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
+            .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
+
+        let expected = specs.len();
+        let request = AccessRequest::new(invoke_id_and_priority, specs)?;
+        let request_bytes = request.encode()?;
+        let response_bytes = self.send_request_timed(&request_bytes).await?;
+        let response = AccessResponse::decode(&response_bytes)?;
+
+        if response.access_response_list.len() != expected {
+            return Err(DlmsError::Protocol(format!(
+                "AccessResponse carries {} results for {} requests",
+                response.access_response_list.len(),
+                expected
+            )));
+        }
+        Ok(response.access_response_list)
+    }
+
+    /// Listen for unsolicited event notifications between requests
+    ///
+    /// Reads incoming frames and dispatches every event-notification APDU
+    /// to `handler` until the configured request timeout elapses with no
+    /// further traffic. Because this takes `&mut self`, no request can be
+    /// in flight while listening, so an incoming frame with any other APDU
+    /// tag cannot be a response to a pending request — it is reported as a
+    /// protocol error instead of being swallowed.
+    ///
+    /// # Returns
+    /// The number of notifications dispatched before the line went quiet
+    ///
+    /// # Errors
+    /// Returns error if the connection is not open, a frame carries a
+    /// non-notification APDU, decoding fails, or the handler fails
+    pub async fn listen_notifications<F>(&mut self, mut handler: F) -> DlmsResult<usize>
+    where
+        F: FnMut(EventNotification) -> DlmsResult<()>,
+    {
+        if !self.is_open() {
+            return Err(DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Connection is not open",
+            )));
+        }
+
+        let timeout = self.config.request_timeout;
+        let mut dispatched = 0usize;
+        loop {
+            let apdu = match self.receive_session_data(Some(timeout)).await {
+                Ok(apdu) => apdu,
+                // The line going quiet ends the listening window
+                Err(DlmsError::Timeout) => return Ok(dispatched),
+                Err(e) => return Err(e),
+            };
+
+            match apdu.first() {
+                Some(&EVENT_NOTIFICATION_TAG) => {
+                    let notification = EventNotification::decode(&apdu[1..])?;
+                    handler(notification)?;
+                    dispatched += 1;
+                }
+                Some(&tag) => {
+                    return Err(DlmsError::Protocol(format!(
+                        "Unexpected APDU tag {} while listening for event notifications",
+                        tag
+                    )));
+                }
+                None => {
+                    return Err(DlmsError::Protocol(
+                        "Empty APDU while listening for event notifications".to_string(),
+                    ));
+                }
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -262,7 +848,7 @@ impl Connection for LnConnection {
         };
 
         self.session = Some(session);
-        self.state = ConnectionState::SessionOpen;
+        self.set_state(ConnectionState::SessionOpen);
 
         // Step 3: Send InitiateRequest
         let initiate_request = InitiateRequest {
@@ -271,14 +857,45 @@ impl Connection for LnConnection {
             client_max_receive_pdu_size: self.config.max_pdu_size,
             proposed_quality_of_service: None,
             response_allowed: true,
-            dedicated_key: None,
+            dedicated_key: self.config.dedicated_key.clone(),
         };
 
-        let request_bytes = initiate_request.encode()?;
-        self.send_session_data(&request_bytes).await?;
+        let response_bytes = if self.config.authentication_mechanism == AuthenticationMechanism::None {
+            // Unauthenticated: send a bare InitiateRequest
+            let request_bytes = initiate_request.encode()?;
+            self.send_session_data(&request_bytes).await?;
+
+            // Step 4: Receive InitiateResponse
+            self.receive_session_data(Some(Duration::from_secs(30))).await?
+        } else {
+            // Authenticated: run the ACSE handshake, carrying the
+            // InitiateRequest in the AARQ user-information field
+            let aarq_bytes = self.build_aarq(&initiate_request)?;
+            self.send_session_data(&aarq_bytes).await?;
 
-        // Step 4: Receive InitiateResponse
-        let response_bytes = self.receive_session_data(Some(Duration::from_secs(30))).await?;
+            // Step 4: Receive AARE and extract the InitiateResponse
+            let aare_bytes = self.receive_session_data(Some(Duration::from_secs(30))).await?;
+            let aare = AAREApdu::decode(&aare_bytes)?;
+            if aare.result != AssociateResult::Accepted {
+                self.set_state(ConnectionState::Closed);
+                self.session = None;
+                return Err(DlmsError::Security(format!(
+                    "Association rejected: {:?} ({:?})",
+                    aare.result, aare.result_source_diagnostic
+                )));
+            }
+            // Keep the server challenge (StoC) so HLS mechanisms can answer it
+            if let Some(AuthenticationValue::OctetString(challenge)) =
+                &aare.responding_authentication_value
+            {
+                self.server_challenge = Some(challenge.clone());
+            }
+            aare.get_initiate_response()
+                .ok_or_else(|| {
+                    DlmsError::Protocol("AARE is missing the InitiateResponse".to_string())
+                })?
+                .to_vec()
+        };
         let initiate_response = InitiateResponse::decode(&response_bytes)?;
 
         // Step 5: Update negotiated parameters
@@ -286,7 +903,13 @@ impl Connection for LnConnection {
         self.server_max_pdu_size = Some(initiate_response.server_max_receive_pdu_size);
 
         // Step 6: Update state to Ready
-        self.state = ConnectionState::Ready;
+        self.set_state(ConnectionState::Ready);
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            server_max_pdu_size = self.server_max_pdu_size,
+            "dlms.connection_open"
+        );
 
         Ok(())
     }
@@ -312,7 +935,11 @@ impl Connection for LnConnection {
         }
 
         self.session = None;
-        self.state = ConnectionState::Closed;
+        self.set_state(ConnectionState::Closed);
+
+        #[cfg(feature = "tracing")]
+        tracing::info!("dlms.connection_released");
+
         Ok(())
     }
 
@@ -332,6 +959,7 @@ impl Connection for LnConnection {
                 "Connection is not open",
             )));
         }
+        self.check_conformance(Conformance::get, "GET service")?;
 
         // Create attribute descriptor with LN addressing
         let ln_ref = LogicalNameReference::new(class_id, obis_code, attribute_id)?;
@@ -342,6 +970,16 @@ impl Connection for LnConnection {
         let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
             .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
 
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "dlms.get",
+            invoke_id,
+            obis = %obis_code,
+            class_id,
+            attribute_id,
+            result = tracing::field::Empty,
+        );
+
         let request = GetRequest::new_normal(
             invoke_id_and_priority,
             attribute_descriptor,
@@ -351,14 +989,45 @@ impl Connection for LnConnection {
         // Encode request
         let request_bytes = request.encode()?;
 
-        // Send request and receive response
-        let response_bytes = self.send_request(&request_bytes, Some(Duration::from_secs(30))).await?;
+        // Send request and receive response inside the span
+        let send = self.send_request_timed(&request_bytes);
+        #[cfg(feature = "tracing")]
+        let send = send.instrument(span.clone());
+        let response_bytes = send.await?;
 
         // Decode response
         let response = GetResponse::decode(&response_bytes)?;
 
-        // Process response using GetService
-        GetService::process_response(&response)
+        // Some servers answer a Normal GET with WithDataBlock when the
+        // encoded attribute exceeds the negotiated PDU size; drive the
+        // Next loop instead of failing
+        let result = match response {
+            GetResponse::WithDataBlock {
+                invoke_id_and_priority,
+                block_number,
+                last_block,
+                block_data,
+            } => {
+                let transfer = self.complete_get_block_transfer(
+                    invoke_id_and_priority,
+                    block_number,
+                    last_block,
+                    block_data,
+                );
+                #[cfg(feature = "tracing")]
+                let transfer = transfer.instrument(span.clone());
+                transfer.await
+            }
+            other => GetService::process_response(&other),
+        };
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => span.record("result", "success"),
+            Err(e) => span.record("result", tracing::field::debug(e)),
+        };
+
+        result
     }
 
     async fn set_attribute(
@@ -368,37 +1037,9 @@ impl Connection for LnConnection {
         attribute_id: u8,
         value: DataObject,
     ) -> DlmsResult<()> {
-        if !self.is_open() {
-            return Err(DlmsError::Connection(std::io::Error::new(
-                std::io::ErrorKind::NotConnected,
-                "Connection is not open",
-            )));
-        }
-
-        // Create attribute descriptor with LN addressing
-        let ln_ref = LogicalNameReference::new(class_id, obis_code, attribute_id)?;
-        let attribute_descriptor = CosemAttributeDescriptor::LogicalName(ln_ref);
-
-        // Create SET request using SetService
-        let invoke_id = self.set_service.next_invoke_id();
-        let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
-            .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
-
-        let request = SetRequest::new_normal(
-            invoke_id_and_priority,
-            attribute_descriptor,
-            None, // No selective access
-            value,
-        );
-
-        // Encode request
-        let request_bytes = request.encode()?;
-
-        // Send request and receive response
-        let response_bytes = self.send_request(&request_bytes, Some(Duration::from_secs(30))).await?;
-
-        // Decode response
-        let response = SetResponse::decode(&response_bytes)?;
+        let response = self
+            .set_request_normal(obis_code, class_id, attribute_id, value)
+            .await?;
 
         // Process response using SetService
         SetService::process_response(&response)?;
@@ -418,6 +1059,7 @@ impl Connection for LnConnection {
                 "Connection is not open",
             )));
         }
+        self.check_conformance(Conformance::action, "ACTION service")?;
 
         // Create method descriptor with LN addressing
         let ln_ref = LogicalNameReference::new(class_id, obis_code, method_id)?;
@@ -428,6 +1070,16 @@ impl Connection for LnConnection {
         let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
             .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
 
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "dlms.action",
+            invoke_id,
+            obis = %obis_code,
+            class_id,
+            method_id,
+            result = tracing::field::Empty,
+        );
+
         let request = ActionRequest::new_normal(
             invoke_id_and_priority,
             method_descriptor,
@@ -437,14 +1089,23 @@ impl Connection for LnConnection {
         // Encode request
         let request_bytes = request.encode()?;
 
-        // Send request and receive response
-        let response_bytes = self.send_request(&request_bytes, Some(Duration::from_secs(30))).await?;
+        // Send request and receive response inside the span
+        let send = self.send_request_timed(&request_bytes);
+        #[cfg(feature = "tracing")]
+        let send = send.instrument(span.clone());
+        let response_bytes = send.await?;
 
         // Decode response
-        let response = ActionResponse::decode(&response_bytes)?;
+        let result = ActionResponse::decode(&response_bytes)
+            .and_then(|response| ActionService::process_response(&response));
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => span.record("result", "success"),
+            Err(e) => span.record("result", tracing::field::debug(e)),
+        };
 
-        // Process response using ActionService
-        ActionService::process_response(&response)
+        result
     }
 
     async fn send_request(
@@ -459,6 +1120,9 @@ impl Connection for LnConnection {
             )));
         }
 
+        // Enforce the negotiated PDU size before anything hits the wire
+        self.check_outgoing_apdu_size(request.len())?;
+
         // Send request through session layer
         self.send_session_data(request).await?;
 
@@ -466,3 +1130,817 @@ impl Connection for LnConnection {
         self.receive_session_data(timeout).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dlms_application::pdu::{data_access_result, ActionResult, GetDataResult};
+    use dlms_core::datatypes::ClockStatus;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Build a Ready LN connection whose peer accepts but never replies
+    async fn silent_peer_connection(request_timeout: Duration) -> LnConnection {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            // Hold the accepted socket open without ever answering
+            let (_socket, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let transport = TcpTransport::new(TcpSettings::new(addr));
+        let mut wrapper = WrapperSession::new(transport, 0x10, 0x01);
+        wrapper.open().await.unwrap();
+
+        let mut conn = LnConnection::new(LnConnectionConfig {
+            request_timeout,
+            ..LnConnectionConfig::default()
+        });
+        conn.session = Some(SessionLayer::WrapperTcp(wrapper));
+        conn.state = ConnectionState::Ready;
+        conn
+    }
+
+    /// Build a Ready LN connection whose peer pushes one unsolicited APDU
+    /// (tag byte plus body) and then holds the socket open silently
+    async fn push_peer_connection(request_timeout: Duration, apdu: Vec<u8>) -> LnConnection {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut frame = vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x10];
+            frame.extend_from_slice(&(apdu.len() as u16).to_be_bytes());
+            frame.extend_from_slice(&apdu);
+            socket.write_all(&frame).await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let transport = TcpTransport::new(TcpSettings::new(addr));
+        let mut wrapper = WrapperSession::new(transport, 0x10, 0x01);
+        wrapper.open().await.unwrap();
+
+        let mut conn = LnConnection::new(LnConnectionConfig {
+            request_timeout,
+            ..LnConnectionConfig::default()
+        });
+        conn.session = Some(SessionLayer::WrapperTcp(wrapper));
+        conn.state = ConnectionState::Ready;
+        conn
+    }
+
+    /// Build a Ready LN connection whose peer answers every SET with `result`
+    async fn set_reply_peer_connection(result: SetDataResult) -> LnConnection {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            loop {
+                // Read one wrapper-framed SET request
+                let mut header = [0u8; 8];
+                if socket.read_exact(&mut header).await.is_err() {
+                    break;
+                }
+                let length = usize::from(u16::from_be_bytes([header[6], header[7]]));
+                let mut payload = vec![0u8; length];
+                socket.read_exact(&mut payload).await.unwrap();
+                // Reply with a wrapper-framed SetResponse::Normal. The response
+                // bytes are built by hand in the layout SetResponse::decode
+                // expects: choice tag, then the length-prefixed normal body
+                // holding the length-prefixed invoke-id and result encodings.
+                let invoke_bytes = InvokeIdAndPriority::new(1, false).unwrap().encode().unwrap();
+                let result_bytes = result.encode().unwrap();
+                let mut normal_bytes = vec![invoke_bytes.len() as u8];
+                normal_bytes.extend_from_slice(&invoke_bytes);
+                normal_bytes.push(result_bytes.len() as u8);
+                normal_bytes.extend_from_slice(&result_bytes);
+                let mut response_bytes = vec![0x01, normal_bytes.len() as u8];
+                response_bytes.extend_from_slice(&normal_bytes);
+                let mut frame = vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x10];
+                frame.extend_from_slice(&(response_bytes.len() as u16).to_be_bytes());
+                frame.extend_from_slice(&response_bytes);
+                socket.write_all(&frame).await.unwrap();
+            }
+        });
+
+        let transport = TcpTransport::new(TcpSettings::new(addr));
+        let mut wrapper = WrapperSession::new(transport, 0x10, 0x01);
+        wrapper.open().await.unwrap();
+
+        let mut conn = LnConnection::new(LnConnectionConfig::default());
+        conn.session = Some(SessionLayer::WrapperTcp(wrapper));
+        conn.state = ConnectionState::Ready;
+        conn
+    }
+
+    /// Build a Ready LN connection whose peer answers every GET with `result`
+    async fn get_reply_peer_connection(result: GetDataResult) -> LnConnection {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            loop {
+                // Read one wrapper-framed GET request
+                let mut header = [0u8; 8];
+                if socket.read_exact(&mut header).await.is_err() {
+                    break;
+                }
+                let length = usize::from(u16::from_be_bytes([header[6], header[7]]));
+                let mut payload = vec![0u8; length];
+                socket.read_exact(&mut payload).await.unwrap();
+                // Reply with a wrapper-framed GetResponse::Normal, built by
+                // hand in the layout GetResponse::decode expects: choice tag,
+                // then the result encoding followed by the invoke-id encoding
+                let mut response_bytes = vec![0x01];
+                response_bytes.extend_from_slice(&result.encode().unwrap());
+                response_bytes.extend_from_slice(
+                    &InvokeIdAndPriority::new(1, false).unwrap().encode().unwrap(),
+                );
+                let mut frame = vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x10];
+                frame.extend_from_slice(&(response_bytes.len() as u16).to_be_bytes());
+                frame.extend_from_slice(&response_bytes);
+                socket.write_all(&frame).await.unwrap();
+            }
+        });
+
+        let transport = TcpTransport::new(TcpSettings::new(addr));
+        let mut wrapper = WrapperSession::new(transport, 0x10, 0x01);
+        wrapper.open().await.unwrap();
+
+        let mut conn = LnConnection::new(LnConnectionConfig::default());
+        conn.session = Some(SessionLayer::WrapperTcp(wrapper));
+        conn.state = ConnectionState::Ready;
+        conn
+    }
+
+    /// Build a Ready LN connection whose peer sends one pre-encoded
+    /// GetResponse per request, in order, then holds the socket open
+    async fn scripted_get_peer_connection(replies: Vec<Vec<u8>>) -> LnConnection {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            for response_bytes in replies {
+                let mut header = [0u8; 8];
+                if socket.read_exact(&mut header).await.is_err() {
+                    break;
+                }
+                let length = usize::from(u16::from_be_bytes([header[6], header[7]]));
+                let mut payload = vec![0u8; length];
+                socket.read_exact(&mut payload).await.unwrap();
+                let mut frame = vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x10];
+                frame.extend_from_slice(&(response_bytes.len() as u16).to_be_bytes());
+                frame.extend_from_slice(&response_bytes);
+                socket.write_all(&frame).await.unwrap();
+            }
+            // Keep the socket open so the client side does not error early
+            let mut sink = [0u8; 64];
+            while socket.read(&mut sink).await.unwrap_or(0) > 0 {}
+        });
+
+        let transport = TcpTransport::new(TcpSettings::new(addr));
+        let mut wrapper = WrapperSession::new(transport, 0x10, 0x01);
+        wrapper.open().await.unwrap();
+
+        let mut conn = LnConnection::new(LnConnectionConfig::default());
+        conn.session = Some(SessionLayer::WrapperTcp(wrapper));
+        conn.state = ConnectionState::Ready;
+        conn
+    }
+
+    /// Build a Ready LN connection whose peer answers every ACTION with `result`
+    async fn action_reply_peer_connection(result: ActionResult) -> LnConnection {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            loop {
+                // Read one wrapper-framed ACTION request
+                let mut header = [0u8; 8];
+                if socket.read_exact(&mut header).await.is_err() {
+                    break;
+                }
+                let length = usize::from(u16::from_be_bytes([header[6], header[7]]));
+                let mut payload = vec![0u8; length];
+                socket.read_exact(&mut payload).await.unwrap();
+                // Reply with a wrapper-framed ActionResponse::Normal, built by
+                // hand in the layout ActionResponse::decode expects (see the
+                // SET peer above for the framing details)
+                let invoke_bytes = InvokeIdAndPriority::new(1, false).unwrap().encode().unwrap();
+                let result_bytes = result.encode().unwrap();
+                let mut normal_bytes = vec![invoke_bytes.len() as u8];
+                normal_bytes.extend_from_slice(&invoke_bytes);
+                normal_bytes.push(result_bytes.len() as u8);
+                normal_bytes.extend_from_slice(&result_bytes);
+                let mut response_bytes = vec![0x01, normal_bytes.len() as u8];
+                response_bytes.extend_from_slice(&normal_bytes);
+                let mut frame = vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x10];
+                frame.extend_from_slice(&(response_bytes.len() as u16).to_be_bytes());
+                frame.extend_from_slice(&response_bytes);
+                socket.write_all(&frame).await.unwrap();
+            }
+        });
+
+        let transport = TcpTransport::new(TcpSettings::new(addr));
+        let mut wrapper = WrapperSession::new(transport, 0x10, 0x01);
+        wrapper.open().await.unwrap();
+
+        let mut conn = LnConnection::new(LnConnectionConfig::default());
+        conn.session = Some(SessionLayer::WrapperTcp(wrapper));
+        conn.state = ConnectionState::Ready;
+        conn
+    }
+
+    #[tokio::test]
+    async fn test_action_returns_data() {
+        let mut conn = action_reply_peer_connection(ActionResult::SuccessWithData(
+            DataObject::Unsigned32(1234),
+        ))
+        .await;
+        let obis = ObisCode::new(0, 0, 10, 0, 0, 255);
+
+        let data = conn.action(obis, 9, 1, None).await.unwrap();
+        assert_eq!(data, Some(DataObject::Unsigned32(1234)));
+
+        let value = conn.action_u32_result(obis, 9, 1, None).await.unwrap();
+        assert_eq!(value, 1234);
+    }
+
+    #[tokio::test]
+    async fn test_action_no_params_success_without_data() {
+        let mut conn = action_reply_peer_connection(ActionResult::Success).await;
+        let obis = ObisCode::new(0, 0, 10, 0, 0, 255);
+
+        let data = conn.action_no_params(obis, 9, 1).await.unwrap();
+        assert_eq!(data, None);
+
+        // A no-data success is an error for callers expecting a value
+        let result = conn.action_u32_result(obis, 9, 1, None).await;
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+
+    #[tokio::test]
+    async fn test_action_maps_error_result() {
+        let mut conn = action_reply_peer_connection(ActionResult::DataAccessResult(
+            data_access_result::READ_WRITE_DENIED,
+        ))
+        .await;
+
+        let result = conn
+            .action_no_params(ObisCode::new(0, 0, 10, 0, 0, 255), 9, 1)
+            .await;
+        assert!(result.is_err());
+
+        // The error result does not tear down the association
+        assert!(conn.is_open());
+    }
+
+    /// Build the AARQ a connection with the given config would send
+    fn build_aarq_for(config: LnConnectionConfig) -> (LnConnection, AARQApdu) {
+        let mut conn = LnConnection::new(config);
+        let initiate_request = InitiateRequest {
+            proposed_dlms_version_number: 6,
+            proposed_conformance: Conformance::default(),
+            client_max_receive_pdu_size: 1024,
+            proposed_quality_of_service: None,
+            response_allowed: true,
+            dedicated_key: None,
+        };
+        let aarq_bytes = conn.build_aarq(&initiate_request).unwrap();
+        let aarq = AARQApdu::decode(&aarq_bytes).unwrap();
+        (conn, aarq)
+    }
+
+    #[test]
+    fn test_build_aarq_low_level_carries_password() {
+        let config = LnConnectionConfig::default()
+            .with_authentication(AuthenticationMechanism::LowLevel, b"secret01".to_vec());
+        let (conn, aarq) = build_aarq_for(config);
+
+        assert_eq!(
+            aarq.sender_acse_requirements,
+            Some(ACSERequirements::default_auth())
+        );
+        assert_eq!(
+            aarq.mechanism_name.as_ref().unwrap().oid(),
+            dlms_asn1::iso_acse::mechanism_oid::LOW
+        );
+        assert_eq!(
+            aarq.calling_authentication_value,
+            Some(AuthenticationValue::OctetString(b"secret01".to_vec()))
+        );
+        assert!(aarq.get_initiate_request().is_some());
+
+        // LLS needs no challenge/response flow
+        assert!(conn.auth_flow.is_none());
+    }
+
+    #[test]
+    fn test_build_aarq_hls_gmac_carries_challenge() {
+        let config = LnConnectionConfig::default()
+            .with_authentication(AuthenticationMechanism::Hls5Gmac, vec![0u8; 16]);
+        let (conn, aarq) = build_aarq_for(config);
+
+        assert_eq!(
+            aarq.sender_acse_requirements,
+            Some(ACSERequirements::default_auth())
+        );
+        assert_eq!(
+            aarq.mechanism_name.as_ref().unwrap().oid(),
+            dlms_asn1::iso_acse::mechanism_oid::HIGH_GMAC
+        );
+
+        // The CtoS challenge is generated, not the configured key material
+        let challenge = match aarq.calling_authentication_value {
+            Some(AuthenticationValue::OctetString(bytes)) => bytes,
+            other => panic!("Expected an octet-string challenge, got {:?}", other),
+        };
+        assert_eq!(challenge.len(), 16);
+        assert_ne!(challenge, vec![0u8; 16]);
+
+        // The prepared flow holds the same challenge for the response phase
+        let flow = conn.auth_flow.as_ref().expect("HLS flow should be prepared");
+        assert_eq!(flow.challenge(), Some(challenge.as_slice()));
+    }
+
+    #[test]
+    fn test_build_aarq_requires_secret() {
+        let config = LnConnectionConfig {
+            authentication_mechanism: AuthenticationMechanism::LowLevel,
+            ..LnConnectionConfig::default()
+        };
+        let mut conn = LnConnection::new(config);
+
+        let initiate_request = InitiateRequest {
+            proposed_dlms_version_number: 6,
+            proposed_conformance: Conformance::default(),
+            client_max_receive_pdu_size: 1024,
+            proposed_quality_of_service: None,
+            response_allowed: true,
+            dedicated_key: None,
+        };
+        let result = conn.build_aarq(&initiate_request);
+        assert!(matches!(result, Err(DlmsError::Security(_))));
+    }
+
+    #[tokio::test]
+    async fn test_typed_setters_report_success() {
+        let mut conn = set_reply_peer_connection(SetDataResult::Success).await;
+        let obis = ObisCode::new(0, 0, 96, 1, 0, 255);
+
+        let result = conn.set_u32(obis, 1, 2, 42).await.unwrap();
+        assert_eq!(result, SetDataResult::Success);
+
+        let result = conn.set_bool(obis, 1, 2, true).await.unwrap();
+        assert_eq!(result, SetDataResult::Success);
+
+        let result = conn.set_string(obis, 1, 2, "meter-01").await.unwrap();
+        assert_eq!(result, SetDataResult::Success);
+
+        let datetime =
+            CosemDateTime::new(2026, 8, 29, 12, 0, 0, 60, &[ClockStatus::DaylightSavingActive])
+                .unwrap();
+        let result = conn.set_datetime(obis, 8, 2, datetime).await.unwrap();
+        assert_eq!(result, SetDataResult::Success);
+    }
+
+    #[tokio::test]
+    async fn test_typed_setter_reports_read_write_denied() {
+        let mut conn = set_reply_peer_connection(SetDataResult::DataAccessResult(
+            data_access_result::READ_WRITE_DENIED,
+        ))
+        .await;
+
+        let result = conn
+            .set_u32(ObisCode::new(1, 0, 1, 8, 0, 255), 3, 2, 0)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            SetDataResult::DataAccessResult(data_access_result::READ_WRITE_DENIED)
+        );
+
+        // The access result is data, not an error: the association stays open
+        assert!(conn.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_get_attribute_times_out_without_response() {
+        let mut conn = silent_peer_connection(Duration::from_millis(100)).await;
+
+        let result = conn
+            .get_attribute(ObisCode::new(1, 0, 1, 8, 0, 255), 3, 2)
+            .await;
+        assert!(matches!(result, Err(DlmsError::Timeout)));
+
+        // The association stays open so the request can be retried
+        assert!(conn.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_set_attribute_times_out_without_response() {
+        let mut conn = silent_peer_connection(Duration::from_millis(100)).await;
+
+        let result = conn
+            .set_attribute(
+                ObisCode::new(1, 0, 1, 8, 0, 255),
+                3,
+                2,
+                DataObject::Unsigned32(0),
+            )
+            .await;
+        assert!(matches!(result, Err(DlmsError::Timeout)));
+        assert!(conn.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_set_attribute_rejected_above_negotiated_pdu_size() {
+        let mut conn = silent_peer_connection(Duration::from_millis(100)).await;
+        conn.server_max_pdu_size = Some(64);
+
+        // An octet string this large cannot fit into a 64-byte APDU
+        let result = conn
+            .set_attribute(
+                ObisCode::new(0, 0, 96, 1, 0, 255),
+                1,
+                2,
+                DataObject::OctetString(vec![0xAB; 200]),
+            )
+            .await;
+        assert!(matches!(result, Err(DlmsError::LengthMismatch(_))));
+
+        // Rejected locally, so the association stays open
+        assert!(conn.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_invoke_method_times_out_without_response() {
+        let mut conn = silent_peer_connection(Duration::from_millis(100)).await;
+
+        let result = conn
+            .invoke_method(ObisCode::new(0, 0, 10, 0, 0, 255), 9, 1, None)
+            .await;
+        assert!(matches!(result, Err(DlmsError::Timeout)));
+        assert!(conn.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_selective_access_get_rejected_without_conformance_bit() {
+        let mut conn = silent_peer_connection(Duration::from_millis(200)).await;
+        // Negotiated conformance with every bit clear
+        conn.negotiated_conformance = Some(Conformance::new());
+
+        let access = SelectiveAccessDescriptor::new(1, DataObject::Unsigned16(1));
+        let result = conn
+            .get_attribute_with_selective_access(ObisCode::new(1, 0, 99, 1, 0, 255), 7, 2, access)
+            .await;
+
+        match result {
+            Err(DlmsError::InvalidData(message)) => {
+                assert_eq!(message, "selective access not supported by server");
+            }
+            other => panic!("Expected InvalidData error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_selective_access_get_allowed_with_conformance_bit() {
+        let mut conn =
+            get_reply_peer_connection(GetDataResult::new_data(DataObject::Unsigned32(7))).await;
+        let mut conformance = Conformance::new();
+        conformance.set_get(true).unwrap();
+        conformance.set_selective_access(true).unwrap();
+        conn.negotiated_conformance = Some(conformance);
+
+        let access = SelectiveAccessDescriptor::new(1, DataObject::Unsigned16(1));
+        let data = conn
+            .get_attribute_with_selective_access(ObisCode::new(1, 0, 99, 1, 0, 255), 7, 2, access)
+            .await
+            .unwrap();
+        assert_eq!(data, DataObject::Unsigned32(7));
+    }
+
+    #[tokio::test]
+    async fn test_get_attribute_reassembles_unexpected_data_block_response() {
+        // A value whose A-XDR encoding is split across two blocks
+        let value = DataObject::OctetString((0..60u8).collect());
+        let mut encoder = dlms_asn1::AxdrEncoder::new();
+        encoder.encode_data_object(&value).unwrap();
+        let encoded = encoder.into_bytes();
+        let (first_half, second_half) = encoded.split_at(encoded.len() / 2);
+
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let first_block = GetResponse::WithDataBlock {
+            invoke_id_and_priority: invoke,
+            block_number: 1,
+            last_block: false,
+            block_data: first_half.to_vec(),
+        };
+        let last_block = GetResponse::WithDataBlock {
+            invoke_id_and_priority: invoke,
+            block_number: 2,
+            last_block: true,
+            block_data: second_half.to_vec(),
+        };
+
+        let mut conn = scripted_get_peer_connection(vec![
+            first_block.encode().unwrap(),
+            last_block.encode().unwrap(),
+        ])
+        .await;
+
+        let data = conn
+            .get_attribute(ObisCode::new(1, 0, 99, 1, 0, 255), 7, 2)
+            .await
+            .unwrap();
+        assert_eq!(data, value);
+    }
+
+    #[tokio::test]
+    async fn test_get_attribute_rejects_out_of_sequence_block() {
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let first_block = GetResponse::WithDataBlock {
+            invoke_id_and_priority: invoke,
+            block_number: 1,
+            last_block: false,
+            block_data: vec![0x09, 0x01],
+        };
+        // The continuation skips ahead to block 3
+        let skipped_block = GetResponse::WithDataBlock {
+            invoke_id_and_priority: invoke,
+            block_number: 3,
+            last_block: true,
+            block_data: vec![0xAA],
+        };
+
+        let mut conn = scripted_get_peer_connection(vec![
+            first_block.encode().unwrap(),
+            skipped_block.encode().unwrap(),
+        ])
+        .await;
+
+        let result = conn.get_attribute(ObisCode::new(1, 0, 99, 1, 0, 255), 7, 2).await;
+        match result {
+            Err(DlmsError::Protocol(message)) => {
+                assert!(message.contains("Out-of-sequence"), "{}", message);
+            }
+            other => panic!("Expected Protocol error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_action_rejected_without_conformance_bit() {
+        let mut conn = silent_peer_connection(Duration::from_millis(200)).await;
+        conn.negotiated_conformance = Some(Conformance::new());
+
+        let result = conn
+            .invoke_method(ObisCode::new(0, 0, 10, 0, 0, 255), 9, 1, None)
+            .await;
+
+        match result {
+            Err(DlmsError::InvalidData(message)) => {
+                assert_eq!(message, "ACTION service not supported by server");
+            }
+            other => panic!("Expected InvalidData error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_listen_notifications_dispatches_unsolicited_event() {
+        let obis = ObisCode::new(0, 0, 97, 98, 0, 255);
+        let attr_desc = CosemAttributeDescriptor::new_logical_name(1, obis, 2).unwrap();
+        let notification =
+            EventNotification::new(None, attr_desc, DataObject::Unsigned32(42));
+        let mut apdu = vec![EVENT_NOTIFICATION_TAG];
+        apdu.extend_from_slice(&notification.encode().unwrap());
+
+        let mut conn = push_peer_connection(Duration::from_millis(100), apdu).await;
+
+        let mut seen = Vec::new();
+        let dispatched = conn
+            .listen_notifications(|n| {
+                seen.push(n.attribute_value.clone());
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(dispatched, 1);
+        assert_eq!(seen, vec![DataObject::Unsigned32(42)]);
+        assert!(conn.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_listen_notifications_rejects_other_apdu_tags() {
+        // A GetResponse tag arriving while no request is pending is a
+        // protocol violation, not something to dispatch as an event
+        let mut conn =
+            push_peer_connection(Duration::from_millis(100), vec![196, 0x01]).await;
+
+        let result = conn.listen_notifications(|_| Ok(())).await;
+        assert!(matches!(result, Err(DlmsError::Protocol(_))));
+    }
+
+    #[tokio::test]
+    async fn test_access_batch_correlates_results_positionally() {
+        use dlms_application::pdu::{AccessResponse, AccessResponseSpecification};
+
+        let invoke = InvokeIdAndPriority::new(1, false).unwrap();
+        let results = vec![
+            AccessResponseSpecification::Get(GetDataResult::Data(DataObject::Unsigned32(42))),
+            AccessResponseSpecification::Set(SetDataResult::Success),
+            AccessResponseSpecification::Action(ActionResult::Success),
+        ];
+        let reply = AccessResponse::new(invoke, results.clone())
+            .unwrap()
+            .encode()
+            .unwrap();
+        let mut conn = scripted_get_peer_connection(vec![reply]).await;
+
+        let obis = ObisCode::new(1, 0, 1, 8, 0, 255);
+        let specs = vec![
+            AccessRequestSpecification::Get {
+                cosem_attribute_descriptor: CosemAttributeDescriptor::LogicalName(
+                    LogicalNameReference::new(3, obis, 2).unwrap(),
+                ),
+                access_selection: None,
+            },
+            AccessRequestSpecification::Set {
+                cosem_attribute_descriptor: CosemAttributeDescriptor::LogicalName(
+                    LogicalNameReference::new(3, obis, 3).unwrap(),
+                ),
+                access_selection: None,
+                value: DataObject::Unsigned8(7),
+            },
+            AccessRequestSpecification::Action {
+                cosem_method_descriptor: CosemMethodDescriptor::LogicalName(
+                    LogicalNameReference::new(3, obis, 1).unwrap(),
+                ),
+                method_invocation_parameters: None,
+            },
+        ];
+
+        let received = conn.access(specs).await.unwrap();
+        assert_eq!(received, results);
+    }
+
+    #[tokio::test]
+    async fn test_access_rejects_empty_batch() {
+        let mut conn = silent_peer_connection(Duration::from_millis(100)).await;
+        let result = conn.access(Vec::new()).await;
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+
+    #[tokio::test]
+    async fn test_on_state_change_records_full_lifecycle() {
+        use std::sync::{Arc, Mutex};
+
+        // Peer that consumes the InitiateRequest and answers with an
+        // InitiateResponse so open() completes the full handshake
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 256];
+            let _ = socket.read(&mut buf).await.unwrap();
+
+            let response = InitiateResponse::new(6, Conformance::default(), 1024, 0x0007)
+                .unwrap()
+                .encode()
+                .unwrap();
+            let mut frame = vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x10];
+            frame.extend_from_slice(&(response.len() as u16).to_be_bytes());
+            frame.extend_from_slice(&response);
+            socket.write_all(&frame).await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let mut conn = LnConnection::new(LnConnectionConfig {
+            transport: Some(TransportConfig::Tcp {
+                address: addr.to_string(),
+            }),
+            ..LnConnectionConfig::default()
+        });
+
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&transitions);
+        conn.on_state_change(Box::new(move |old, new| {
+            recorded.lock().unwrap().push((old, new));
+        }));
+
+        conn.open().await.unwrap();
+        conn.close().await.unwrap();
+
+        assert_eq!(
+            *transitions.lock().unwrap(),
+            vec![
+                (ConnectionState::Closed, ConnectionState::SessionOpen),
+                (ConnectionState::SessionOpen, ConnectionState::Ready),
+                (ConnectionState::Ready, ConnectionState::Closed),
+            ]
+        );
+    }
+
+    /// Run with `cargo test -p dlms-client --features tracing`
+    #[cfg(feature = "tracing")]
+    mod tracing_tests {
+        use super::*;
+        use std::collections::HashMap;
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Captured spans: name plus all recorded fields as strings
+        type CapturedSpans = Arc<Mutex<Vec<(&'static str, HashMap<String, String>)>>>;
+
+        struct FieldCollector<'a>(&'a mut HashMap<String, String>);
+
+        impl Visit for FieldCollector<'_> {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.insert(field.name().to_string(), value.to_string());
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0
+                    .insert(field.name().to_string(), format!("{:?}", value));
+            }
+        }
+
+        /// Minimal subscriber recording span creation and later field records
+        struct CaptureSubscriber {
+            spans: CapturedSpans,
+            /// Ids of spans that were entered at least once
+            entered: Arc<Mutex<Vec<u64>>>,
+        }
+
+        impl Subscriber for CaptureSubscriber {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut fields = HashMap::new();
+                span.record(&mut FieldCollector(&mut fields));
+                let mut spans = self.spans.lock().unwrap();
+                spans.push((span.metadata().name(), fields));
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.spans.lock().unwrap();
+                let index = (span.into_u64() - 1) as usize;
+                values.record(&mut FieldCollector(&mut spans[index].1));
+            }
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, _event: &Event<'_>) {}
+
+            fn enter(&self, span: &Id) {
+                self.entered.lock().unwrap().push(span.into_u64());
+            }
+
+            fn exit(&self, _span: &Id) {}
+        }
+
+        #[tokio::test]
+        async fn test_get_attribute_emits_span_with_fields() {
+            let spans: CapturedSpans = Arc::new(Mutex::new(Vec::new()));
+            let entered = Arc::new(Mutex::new(Vec::new()));
+            let _guard = tracing::subscriber::set_default(CaptureSubscriber {
+                spans: spans.clone(),
+                entered: entered.clone(),
+            });
+
+            let mut conn =
+                get_reply_peer_connection(GetDataResult::Data(DataObject::Unsigned32(42))).await;
+            let obis = ObisCode::new(1, 0, 1, 8, 0, 255);
+            let value = conn.get_attribute(obis, 3, 2).await.unwrap();
+            assert_eq!(value, DataObject::Unsigned32(42));
+
+            let spans = spans.lock().unwrap();
+            let (index, (_, fields)) = spans
+                .iter()
+                .enumerate()
+                .find(|(_, (name, _))| *name == "dlms.get")
+                .expect("GET did not create a dlms.get span");
+
+            // The request future is instrumented with the span, so the
+            // subscriber must see it entered while the request runs
+            assert!(
+                entered.lock().unwrap().contains(&(index as u64 + 1)),
+                "dlms.get span was never entered"
+            );
+
+            assert_eq!(fields.get("invoke_id").map(String::as_str), Some("1"));
+            assert_eq!(
+                fields.get("obis").map(String::as_str),
+                Some("1.0.1.8.0.255")
+            );
+            assert_eq!(fields.get("class_id").map(String::as_str), Some("3"));
+            assert_eq!(fields.get("attribute_id").map(String::as_str), Some("2"));
+            assert_eq!(fields.get("result").map(String::as_str), Some("success"));
+        }
+    }
+}
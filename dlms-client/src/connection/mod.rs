@@ -34,6 +34,6 @@ pub mod ln_connection;
 pub mod sn_connection;
 
 pub use connection::{Connection, ConnectionState};
-pub use ln_connection::{LnConnection, LnConnectionConfig};
+pub use ln_connection::{LnConnection, LnConnectionConfig, StateChangeCallback};
 pub use sn_connection::{SnConnection, SnConnectionConfig};
 pub use builder::ConnectionBuilder;
\ No newline at end of file
@@ -38,7 +38,10 @@ use dlms_application::service::{GetService, SetService, ActionService};
 use dlms_application::pdu::{
     InitiateRequest, InitiateResponse, GetRequest, GetResponse, SetRequest, SetResponse,
     ActionRequest, ActionResponse, CosemAttributeDescriptor, CosemMethodDescriptor,
-    InvokeIdAndPriority, Conformance,
+    InvokeIdAndPriority, Conformance, GetDataResult, SetDataResult,
+};
+use dlms_application::sn_pdu::{
+    ReadRequest, ReadResponse, ShortName, UnconfirmedWriteRequest, WriteRequest, WriteResponse,
 };
 // ShortNameReference is no longer directly used - we use CosemAttributeDescriptor::new_short_name() instead
 use dlms_core::{DlmsError, DlmsResult, DataObject};
@@ -549,4 +552,238 @@ impl SnConnection {
         // Process response using ActionService
         ActionService::process_response(&response)
     }
+
+    /// Resolve the effective short name of an attribute
+    ///
+    /// In SN addressing each attribute of an object occupies eight address
+    /// units above the object's base name, so the attribute at `offset`
+    /// lives at `base_name + 8 * offset` (offset 0 is the base name itself).
+    fn short_name_at(base_name: u16, offset: u8) -> DlmsResult<ShortName> {
+        base_name
+            .checked_add(u16::from(offset) * 8)
+            .map(ShortName::new)
+            .ok_or_else(|| {
+                DlmsError::InvalidData(format!(
+                    "Attribute offset {} overflows base name 0x{:04X}",
+                    offset, base_name
+                ))
+            })
+    }
+
+    /// Read a variable using the SN READ service
+    ///
+    /// Builds a `ReadRequest` PDU for the variable at `base_name + 8 * offset`
+    /// and decodes the `ReadResponse` from the server.
+    ///
+    /// # Arguments
+    /// * `base_name` - 16-bit base address of the object
+    /// * `offset` - Attribute offset from the base name (0 = base name itself)
+    ///
+    /// # Returns
+    /// The variable value as a `DataObject`
+    ///
+    /// # Errors
+    /// Returns error if the connection is not open, if the request fails, or if the response indicates an error
+    pub async fn read(&mut self, base_name: u16, offset: u8) -> DlmsResult<DataObject> {
+        if !self.is_open() {
+            return Err(DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Connection is not open",
+            )));
+        }
+
+        let short_name = Self::short_name_at(base_name, offset)?;
+
+        // READ re-uses the GET invoke ID sequence (it is the SN GetRequest)
+        let invoke_id = self.get_service.next_invoke_id();
+        let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
+            .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
+
+        let request = ReadRequest::new(invoke_id_and_priority, short_name);
+        let request_bytes = request.encode()?;
+
+        let response_bytes = self.send_request(&request_bytes, Some(Duration::from_secs(30))).await?;
+        let response = ReadResponse::decode(&response_bytes)?;
+
+        match response.result {
+            GetDataResult::Data(data) => Ok(data),
+            GetDataResult::DataBlock(_) => Err(DlmsError::InvalidData(
+                "Data blocks are not supported for SN READ".to_string(),
+            )),
+            GetDataResult::DataAccessResult(code) => Err(DlmsError::DataAccess {
+                code,
+                description: GetDataResult::DataAccessResult(code)
+                    .error_description()
+                    .to_string(),
+            }),
+        }
+    }
+
+    /// Write a variable using the SN WRITE service
+    ///
+    /// Builds a `WriteRequest` PDU for the variable at `base_name + 8 * offset`
+    /// and checks the `WriteResponse` from the server.
+    ///
+    /// # Arguments
+    /// * `base_name` - 16-bit base address of the object
+    /// * `offset` - Attribute offset from the base name (0 = base name itself)
+    /// * `data` - Value to write
+    ///
+    /// # Errors
+    /// Returns error if the connection is not open, if the request fails, or if the response indicates an error
+    pub async fn write(&mut self, base_name: u16, offset: u8, data: DataObject) -> DlmsResult<()> {
+        if !self.is_open() {
+            return Err(DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Connection is not open",
+            )));
+        }
+
+        let short_name = Self::short_name_at(base_name, offset)?;
+
+        // WRITE re-uses the SET invoke ID sequence (it is the SN SetRequest)
+        let invoke_id = self.set_service.next_invoke_id();
+        let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)
+            .map_err(|e| DlmsError::InvalidData(format!("Invalid invoke ID: {}", e)))?;
+
+        let request = WriteRequest::new(invoke_id_and_priority, short_name, data);
+        let request_bytes = request.encode()?;
+
+        let response_bytes = self.send_request(&request_bytes, Some(Duration::from_secs(30))).await?;
+        let response = WriteResponse::decode(&response_bytes)?;
+
+        match response.result {
+            SetDataResult::Success => Ok(()),
+            SetDataResult::DataAccessResult(code) => Err(DlmsError::DataAccess {
+                code,
+                description: SetDataResult::DataAccessResult(code)
+                    .error_description()
+                    .to_string(),
+            }),
+        }
+    }
+
+    /// Write a variable using the SN UNCONFIRMED-WRITE service
+    ///
+    /// Builds an `UnconfirmedWriteRequest` PDU for the variable at
+    /// `base_name + 8 * offset` and sends it without waiting for a response;
+    /// the server does not confirm unconfirmed writes.
+    ///
+    /// # Arguments
+    /// * `base_name` - 16-bit base address of the object
+    /// * `offset` - Attribute offset from the base name (0 = base name itself)
+    /// * `data` - Value to write
+    ///
+    /// # Errors
+    /// Returns error if the connection is not open or if sending the request fails
+    pub async fn unconfirmed_write(
+        &mut self,
+        base_name: u16,
+        offset: u8,
+        data: DataObject,
+    ) -> DlmsResult<()> {
+        if !self.is_open() {
+            return Err(DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "Connection is not open",
+            )));
+        }
+
+        let short_name = Self::short_name_at(base_name, offset)?;
+
+        let request = UnconfirmedWriteRequest::new(short_name, data);
+        let request_bytes = request.encode()?;
+
+        self.send_session_data(&request_bytes).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Build a Ready SN connection over a Wrapper session to the given peer
+    async fn wrapper_connection(addr: SocketAddr) -> SnConnection {
+        let transport = TcpTransport::new(TcpSettings::new(addr));
+        let mut wrapper = WrapperSession::new(transport, 0x10, 0x01);
+        wrapper.open().await.unwrap();
+
+        let mut conn = SnConnection::new(SnConnectionConfig::default());
+        conn.session = Some(SessionLayer::WrapperTcp(wrapper));
+        conn.state = ConnectionState::Ready;
+        conn
+    }
+
+    /// Read one wrapper-framed PDU from the socket and return its payload
+    async fn read_wrapper_payload(socket: &mut tokio::net::TcpStream) -> Vec<u8> {
+        let mut header = [0u8; 8];
+        socket.read_exact(&mut header).await.unwrap();
+        let length = usize::from(u16::from_be_bytes([header[6], header[7]]));
+        let mut payload = vec![0u8; length];
+        socket.read_exact(&mut payload).await.unwrap();
+        payload
+    }
+
+    #[tokio::test]
+    async fn test_read_returns_decoded_data() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let payload = read_wrapper_payload(&mut socket).await;
+            let request = ReadRequest::decode(&payload).unwrap();
+
+            // Reply with a wrapper-framed ReadResponse carrying the value
+            let response = ReadResponse::new(
+                request.invoke_id,
+                GetDataResult::Data(DataObject::Unsigned32(42)),
+            );
+            let response_bytes = response.encode().unwrap();
+            let mut frame = vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x10];
+            frame.extend_from_slice(&(response_bytes.len() as u16).to_be_bytes());
+            frame.extend_from_slice(&response_bytes);
+            socket.write_all(&frame).await.unwrap();
+        });
+
+        let mut conn = wrapper_connection(addr).await;
+
+        let value = conn.read(0xFA00, 1).await.unwrap();
+        assert_eq!(value, DataObject::Unsigned32(42));
+    }
+
+    #[tokio::test]
+    async fn test_unconfirmed_write_completes_without_response() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (payload_tx, payload_rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let payload = read_wrapper_payload(&mut socket).await;
+            let _ = payload_tx.send(payload);
+            // Never reply: unconfirmed writes have no response
+            std::future::pending::<()>().await;
+        });
+
+        let mut conn = wrapper_connection(addr).await;
+
+        // Completes immediately even though the peer stays silent
+        conn.unconfirmed_write(0x6C00, 0, DataObject::Unsigned16(7))
+            .await
+            .unwrap();
+
+        let request = UnconfirmedWriteRequest::decode(&payload_rx.await.unwrap()).unwrap();
+        assert_eq!(request.short_name.value(), 0x6C00);
+        assert_eq!(request.data, DataObject::Unsigned16(7));
+    }
+
+    #[test]
+    fn test_short_name_at_applies_attribute_offset() {
+        assert_eq!(
+            SnConnection::short_name_at(0xFA00, 2).unwrap(),
+            ShortName::new(0xFA10)
+        );
+        assert!(SnConnection::short_name_at(0xFFF8, 2).is_err());
+    }
 }
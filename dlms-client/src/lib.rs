@@ -45,10 +45,11 @@ pub mod reconnect;
 pub mod connection_pool;
 pub mod event_handler;
 pub mod client_api;
+pub mod request_builder;
 
 pub use connection::{
     Connection, ConnectionState, LnConnection, LnConnectionConfig,
-    SnConnection, SnConnectionConfig, ConnectionBuilder,
+    SnConnection, SnConnectionConfig, ConnectionBuilder, StateChangeCallback,
 };
 
 pub use browser::{ObjectBrowser, CosemObjectDescriptor};
@@ -79,3 +80,4 @@ pub use client_api::{
     DlmsClient, ClientConfig,
     TryFromDataObject, IntoDataObject,
 };
+pub use request_builder::RequestBuilder;
@@ -0,0 +1,174 @@
+//! Fluent builder for client request PDUs
+//!
+//! Assembling a `GetRequest` by hand means juggling the invoke id, the
+//! attribute descriptor and the optional selective access descriptor in
+//! the right order. The builder collects those pieces fluently and
+//! allocates the invoke id from the connection's GET service, so the ids
+//! stay in sequence with requests sent through the regular methods.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use dlms_client::{LnConnection, LnConnectionConfig};
+//! # use dlms_core::ObisCode;
+//! # fn main() -> dlms_core::DlmsResult<()> {
+//! let mut connection = LnConnection::new(LnConnectionConfig::default());
+//! let request = connection
+//!     .request()
+//!     .get(ObisCode::new(1, 0, 1, 8, 0, 255), 3, 2)
+//!     .high_priority()
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use dlms_application::pdu::{
+    CosemAttributeDescriptor, GetRequest, InvokeIdAndPriority, SelectiveAccessDescriptor,
+};
+use dlms_core::{DlmsError, DlmsResult, ObisCode};
+
+use crate::connection::ln_connection::LnConnection;
+
+impl LnConnection {
+    /// Start building a request PDU against this connection
+    ///
+    /// The builder borrows the connection mutably so that `build()` can
+    /// allocate the next invoke id from the same counter used by
+    /// `get_attribute` and friends.
+    pub fn request(&mut self) -> RequestBuilder<'_> {
+        RequestBuilder {
+            connection: self,
+            target: None,
+            access_selection: None,
+            high_priority: false,
+        }
+    }
+}
+
+/// Builder for a single request PDU, created by [`LnConnection::request`]
+pub struct RequestBuilder<'a> {
+    connection: &'a mut LnConnection,
+    /// (class_id, logical name, attribute id) of the target attribute
+    target: Option<(u16, ObisCode, u8)>,
+    access_selection: Option<SelectiveAccessDescriptor>,
+    high_priority: bool,
+}
+
+impl RequestBuilder<'_> {
+    /// Target an attribute for a GET request
+    ///
+    /// # Arguments
+    /// * `obis_code` - Logical name of the object
+    /// * `class_id` - COSEM interface class ID
+    /// * `attribute_id` - Attribute ID to read
+    pub fn get(mut self, obis_code: ObisCode, class_id: u16, attribute_id: u8) -> Self {
+        self.target = Some((class_id, obis_code, attribute_id));
+        self
+    }
+
+    /// Attach a selective access descriptor to the request
+    pub fn with_selective_access(mut self, descriptor: SelectiveAccessDescriptor) -> Self {
+        self.access_selection = Some(descriptor);
+        self
+    }
+
+    /// Mark the request as high priority
+    pub fn high_priority(mut self) -> Self {
+        self.high_priority = true;
+        self
+    }
+
+    /// Build the request, allocating its invoke id from the connection
+    ///
+    /// # Errors
+    /// Returns `DlmsError::InvalidData` if no target attribute was set
+    pub fn build(self) -> DlmsResult<GetRequest> {
+        let (class_id, obis_code, attribute_id) = self.target.ok_or_else(|| {
+            DlmsError::InvalidData(
+                "Request builder has no target attribute; call get() first".to_string(),
+            )
+        })?;
+
+        let invoke_id = self.connection.next_get_invoke_id();
+        let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, self.high_priority)?;
+        let attribute_descriptor =
+            CosemAttributeDescriptor::new_logical_name(class_id, obis_code, attribute_id)?;
+
+        Ok(GetRequest::new_normal(
+            invoke_id_and_priority,
+            attribute_descriptor,
+            self.access_selection,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::ln_connection::LnConnectionConfig;
+    use dlms_core::DataObject;
+
+    #[test]
+    fn test_builder_normal_get() {
+        let mut conn = LnConnection::new(LnConnectionConfig::default());
+        let obis = ObisCode::new(1, 0, 1, 8, 0, 255);
+
+        let request = conn.request().get(obis, 3, 2).build().unwrap();
+
+        match request {
+            GetRequest::Normal(normal) => {
+                assert_eq!(normal.invoke_id_and_priority.invoke_id(), 1);
+                assert!(!normal.invoke_id_and_priority.is_high_priority());
+                match normal.cosem_attribute_descriptor {
+                    CosemAttributeDescriptor::LogicalName(ln_ref) => {
+                        assert_eq!(ln_ref.class_id, 3);
+                        assert_eq!(ln_ref.instance_id, obis);
+                        assert_eq!(ln_ref.id, 2);
+                    }
+                    other => panic!("Expected LN descriptor, got {:?}", other),
+                }
+                assert!(normal.access_selection.is_none());
+            }
+            other => panic!("Expected Normal request, got {:?}", other),
+        }
+
+        // The invoke id counter advances with each built request
+        let request = conn.request().get(obis, 3, 2).build().unwrap();
+        match request {
+            GetRequest::Normal(normal) => {
+                assert_eq!(normal.invoke_id_and_priority.invoke_id(), 2);
+            }
+            other => panic!("Expected Normal request, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builder_selective_access_high_priority_get() {
+        let mut conn = LnConnection::new(LnConnectionConfig::default());
+        let obis = ObisCode::new(1, 0, 99, 1, 0, 255);
+        let selective = SelectiveAccessDescriptor::new(2, DataObject::Unsigned32(5));
+
+        let request = conn
+            .request()
+            .get(obis, 7, 2)
+            .with_selective_access(selective.clone())
+            .high_priority()
+            .build()
+            .unwrap();
+
+        match request {
+            GetRequest::Normal(normal) => {
+                assert!(normal.invoke_id_and_priority.is_high_priority());
+                assert_eq!(normal.access_selection, Some(selective));
+            }
+            other => panic!("Expected Normal request, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builder_without_target_is_rejected() {
+        let mut conn = LnConnection::new(LnConnectionConfig::default());
+        let result = conn.request().build();
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+}
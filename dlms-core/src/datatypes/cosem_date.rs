@@ -205,6 +205,72 @@ impl CosemDate {
             Ok(())
         }
     }
+
+    /// Compute the day of week for this date
+    ///
+    /// Uses Sakamoto's algorithm on the Gregorian calendar.
+    ///
+    /// # Returns
+    ///
+    /// The day of week from 1 (Monday) to 7 (Sunday), or `None` if the year,
+    /// month, or day of month contains a wildcard or special value
+    pub fn compute_weekday(&self) -> Option<u8> {
+        let year = ((self.octet_string[0] as u16) << 8) | (self.octet_string[1] as u16);
+        let month = self.octet_string[2];
+        let day = self.octet_string[3];
+
+        if year == 0xffff || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+
+        // Sakamoto's algorithm: 0 = Sunday .. 6 = Saturday
+        const OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let mut y = year as u32;
+        if month < 3 {
+            y -= 1;
+        }
+        let w = (y + y / 4 - y / 100 + y / 400 + OFFSETS[(month - 1) as usize] + day as u32) % 7;
+
+        // Convert to ISO numbering: 1 = Monday .. 7 = Sunday
+        Some(if w == 0 { 7 } else { w as u8 })
+    }
+
+    /// Check whether this date refers to the last day of its month
+    ///
+    /// Honors the 0xfe "last day of month" wildcard; for fully specified
+    /// dates the day of month is compared against the actual month length
+    /// (accounting for leap years).
+    pub fn is_last_day_of_month(&self) -> bool {
+        let year = ((self.octet_string[0] as u16) << 8) | (self.octet_string[1] as u16);
+        let month = self.octet_string[2];
+        let day = self.octet_string[3];
+
+        if day == LAST_DAY_OF_MONTH {
+            return true;
+        }
+        if year == 0xffff || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return false;
+        }
+        day == Self::days_in_month(year, month)
+    }
+
+    /// Number of days in a Gregorian calendar month
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 => {
+                let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100))
+                    || year.is_multiple_of(400);
+                if leap {
+                    29
+                } else {
+                    28
+                }
+            }
+            _ => 0,
+        }
+    }
 }
 
 impl CosemDateFormat for CosemDate {
@@ -270,4 +336,43 @@ mod tests {
         assert!(CosemDate::new(2024, 1, 32).is_err()); // Invalid day
         assert!(CosemDate::new(2024, 1, 0).is_err());  // Invalid day (zero)
     }
+
+    #[test]
+    fn test_cosem_date_compute_weekday() {
+        // 2024-01-15 was a Monday
+        let date = CosemDate::new(2024, 1, 15).unwrap();
+        assert_eq!(date.compute_weekday(), Some(1));
+
+        // 2024-02-29 (leap day) was a Thursday
+        let date = CosemDate::new(2024, 2, 29).unwrap();
+        assert_eq!(date.compute_weekday(), Some(4));
+
+        // 2023-12-31 was a Sunday
+        let date = CosemDate::new(2023, 12, 31).unwrap();
+        assert_eq!(date.compute_weekday(), Some(7));
+    }
+
+    #[test]
+    fn test_cosem_date_compute_weekday_wildcards() {
+        // Month not specified
+        let date = CosemDate::new(2024, NOT_SPECIFIED, 15).unwrap();
+        assert_eq!(date.compute_weekday(), None);
+
+        // Day of month is the "last day" wildcard
+        let date = CosemDate::new(2024, 1, LAST_DAY_OF_MONTH).unwrap();
+        assert_eq!(date.compute_weekday(), None);
+    }
+
+    #[test]
+    fn test_cosem_date_is_last_day_of_month() {
+        // 0xfe wildcard always matches
+        let date = CosemDate::new(2024, 1, LAST_DAY_OF_MONTH).unwrap();
+        assert!(date.is_last_day_of_month());
+
+        // Concrete last days, including a leap February
+        assert!(CosemDate::new(2024, 1, 31).unwrap().is_last_day_of_month());
+        assert!(CosemDate::new(2024, 2, 29).unwrap().is_last_day_of_month());
+        assert!(!CosemDate::new(2023, 2, 29).unwrap().is_last_day_of_month());
+        assert!(!CosemDate::new(2024, 1, 30).unwrap().is_last_day_of_month());
+    }
 }
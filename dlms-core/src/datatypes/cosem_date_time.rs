@@ -195,6 +195,122 @@ impl CosemDateTime {
     pub fn clock_status(&self) -> Vec<ClockStatus> {
         ClockStatus::from_byte(self.clock_status)
     }
+
+    /// Re-express the same instant with a different deviation
+    ///
+    /// The deviation gives the minutes from local time to GMT, so the wall
+    /// clock shifts by the difference between the current and the new
+    /// deviation while the instant stays the same. Day, month and year
+    /// roll over as needed.
+    ///
+    /// # Returns
+    /// `None` if the deviation is not specified, if any of the year, month,
+    /// day, hour or minute fields is a wildcard or special value, or if the
+    /// new deviation is out of range
+    pub fn with_deviation(&self, deviation: i16) -> Option<CosemDateTime> {
+        Self::validate_deviation(deviation).ok()?;
+        if self.deviation == DEVIATION_NOT_SPECIFIED {
+            return None;
+        }
+
+        // All fields that take part in the shift must be concrete
+        let year = self.get(Field::Year).ok()?;
+        let month = self.get(Field::Month).ok()?;
+        let day = self.get(Field::DayOfMonth).ok()?;
+        let hour = self.get(Field::Hour).ok()?;
+        let minute = self.get(Field::Minute).ok()?;
+        if year == 0xffff
+            || !(1..=12).contains(&month)
+            || !(1..=31).contains(&day)
+            || hour > 23
+            || minute > 59
+        {
+            return None;
+        }
+
+        // GMT = local + deviation, so the wall clock moves by the
+        // difference between the old and the new deviation
+        let shift = i32::from(self.deviation) - i32::from(deviation);
+        let mut year = year as i32;
+        let mut month = month as i32;
+        let mut day = day as i32;
+        let mut minutes = (hour as i32) * 60 + minute as i32 + shift;
+
+        while minutes < 0 {
+            minutes += 24 * 60;
+            day -= 1;
+            if day < 1 {
+                month -= 1;
+                if month < 1 {
+                    month = 12;
+                    year -= 1;
+                }
+                day = Self::days_in_month(year as u16, month as u8) as i32;
+            }
+        }
+        while minutes >= 24 * 60 {
+            minutes -= 24 * 60;
+            day += 1;
+            if day > Self::days_in_month(year as u16, month as u8) as i32 {
+                day = 1;
+                month += 1;
+                if month > 12 {
+                    month = 1;
+                    year += 1;
+                }
+            }
+        }
+        if !(0..0xffff).contains(&year) {
+            return None;
+        }
+
+        // The day of week is dropped rather than recomputed; seconds and
+        // hundredths are unaffected by a whole-minute shift
+        let second = self.get(Field::Second).ok()? as u8;
+        let hundredths = self.get(Field::Hundredths).ok()? as u8;
+        let date = CosemDate::new_with_day_of_week(year as u16, month as u8, day as u8, 0xff).ok()?;
+        let time = CosemTime::new_with_hundredths(
+            (minutes / 60) as u8,
+            (minutes % 60) as u8,
+            second,
+            hundredths,
+        )
+        .ok()?;
+
+        Some(Self {
+            date,
+            time,
+            deviation,
+            clock_status: self.clock_status,
+        })
+    }
+
+    /// Normalize this timestamp to UTC by applying the deviation
+    ///
+    /// # Returns
+    /// The same instant with deviation 0, or `None` if the deviation is not
+    /// specified or the date/time contains wildcards
+    pub fn to_utc(&self) -> Option<CosemDateTime> {
+        self.with_deviation(0)
+    }
+
+    /// Days in `month` of `year`, accounting for leap years
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 => {
+                let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100))
+                    || year.is_multiple_of(400);
+                if leap {
+                    29
+                } else {
+                    28
+                }
+            }
+            _ => 30,
+        }
+    }
 }
 
 impl CosemDateFormat for CosemDateTime {
@@ -259,4 +375,38 @@ mod tests {
         let dt = CosemDateTime::decode(&bytes).unwrap();
         assert_eq!(dt.get(Field::Year).unwrap(), 2024);
     }
+
+    #[test]
+    fn test_to_utc_applies_deviation() {
+        // 23:30 local with +120 minutes to GMT is 01:30 UTC the next day
+        let dt = CosemDateTime::new(2024, 1, 15, 23, 30, 0, 120, &[]).unwrap();
+        let utc = dt.to_utc().unwrap();
+
+        assert_eq!(utc.get(Field::DayOfMonth).unwrap(), 16);
+        assert_eq!(utc.get(Field::Hour).unwrap(), 1);
+        assert_eq!(utc.get(Field::Minute).unwrap(), 30);
+        assert_eq!(utc.deviation(), 0);
+
+        // An unspecified deviation cannot be normalized
+        let unspecified = CosemDateTime::new(2024, 1, 15, 23, 30, 0, -32768, &[]).unwrap();
+        assert!(unspecified.to_utc().is_none());
+    }
+
+    #[test]
+    fn test_with_deviation_re_expresses_instant() {
+        let dt = CosemDateTime::new(2024, 1, 15, 23, 30, 0, 120, &[]).unwrap();
+
+        // The same instant at -60 minutes to GMT is 02:30 the next day
+        let shifted = dt.with_deviation(-60).unwrap();
+        assert_eq!(shifted.get(Field::DayOfMonth).unwrap(), 16);
+        assert_eq!(shifted.get(Field::Hour).unwrap(), 2);
+        assert_eq!(shifted.get(Field::Minute).unwrap(), 30);
+        assert_eq!(shifted.deviation(), -60);
+
+        // Both expressions normalize to the same UTC instant
+        assert_eq!(dt.to_utc(), shifted.to_utc());
+
+        // An out-of-range deviation is rejected
+        assert!(dt.with_deviation(800).is_none());
+    }
 }
@@ -50,6 +50,67 @@ impl CosemTime {
         Ok(Self { octet_string })
     }
 
+    /// Constructs a COSEM Time from hour, minute, second, and hundredths
+    ///
+    /// Alias for [`new_with_hundredths`](Self::new_with_hundredths) with the
+    /// canonical `HH:MM:SS.hh` field order.
+    pub fn from_hms_hundredths(hour: u8, minute: u8, second: u8, hundredths: u8) -> DlmsResult<Self> {
+        Self::new_with_hundredths(hour, minute, second, hundredths)
+    }
+
+    /// Parse a COSEM Time from string format
+    ///
+    /// Supports the canonical `HH:MM:SS.hh` form as well as `HH:MM:SS`.
+    /// Fields given as `FF` are treated as the 0xff wildcard; a missing
+    /// hundredths field is also treated as not specified.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - String representation of the time, e.g. "14:30:45.50" or "14:30:FF"
+    pub fn from_string(s: &str) -> DlmsResult<Self> {
+        let (hms, hundredths) = match s.split_once('.') {
+            Some((hms, hundredths)) => (hms, Self::parse_field(hundredths)?),
+            None => (s, NOT_SPECIFIED),
+        };
+
+        let parts: Vec<&str> = hms.split(':').collect();
+        if parts.len() != 3 {
+            return Err(DlmsError::InvalidData(format!(
+                "Invalid COSEM time format: {}",
+                s
+            )));
+        }
+
+        Self::new_with_hundredths(
+            Self::parse_field(parts[0])?,
+            Self::parse_field(parts[1])?,
+            Self::parse_field(parts[2])?,
+            hundredths,
+        )
+    }
+
+    fn parse_field(part: &str) -> DlmsResult<u8> {
+        if part.eq_ignore_ascii_case("ff") {
+            return Ok(NOT_SPECIFIED);
+        }
+        part.parse::<u8>()
+            .map_err(|_| DlmsError::InvalidData(format!("Invalid time field: {}", part)))
+    }
+
+    /// Total seconds since midnight, ignoring hundredths
+    ///
+    /// # Returns
+    ///
+    /// `hour * 3600 + minute * 60 + second`, or `None` if the hour, minute,
+    /// or second field is the 0xff wildcard
+    pub fn total_seconds(&self) -> Option<u32> {
+        let [hour, minute, second, _] = self.octet_string;
+        if hour == NOT_SPECIFIED || minute == NOT_SPECIFIED || second == NOT_SPECIFIED {
+            return None;
+        }
+        Some(hour as u32 * 3600 + minute as u32 * 60 + second as u32)
+    }
+
     /// Decode a COSEM Time from a byte array
     pub fn decode(octet_string: &[u8]) -> DlmsResult<Self> {
         if octet_string.len() != Self::LENGTH {
@@ -102,10 +163,22 @@ impl CosemDateFormat for CosemTime {
 
 impl fmt::Display for CosemTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let hour = self.get(Field::Hour).unwrap_or(0);
-        let minute = self.get(Field::Minute).unwrap_or(0);
-        let second = self.get(Field::Second).unwrap_or(0);
-        write!(f, "{:02}:{:02}:{:02}", hour, minute, second)
+        // Canonical HH:MM:SS.hh form, wildcard fields rendered as "FF"
+        let field = |value: u8| -> String {
+            if value == NOT_SPECIFIED {
+                "FF".to_string()
+            } else {
+                format!("{:02}", value)
+            }
+        };
+        write!(
+            f,
+            "{}:{}:{}.{}",
+            field(self.octet_string[0]),
+            field(self.octet_string[1]),
+            field(self.octet_string[2]),
+            field(self.octet_string[3])
+        )
     }
 }
 
@@ -134,4 +207,26 @@ mod tests {
         assert!(CosemTime::new(0, 60, 0).is_err());
         assert!(CosemTime::new(0, 0, 60).is_err());
     }
+
+    #[test]
+    fn test_cosem_time_full_roundtrip() {
+        let time = CosemTime::from_hms_hundredths(14, 30, 45, 50).unwrap();
+        assert_eq!(time.to_string(), "14:30:45.50");
+        assert_eq!(CosemTime::from_string("14:30:45.50").unwrap(), time);
+    }
+
+    #[test]
+    fn test_cosem_time_wildcard_seconds() {
+        let time = CosemTime::new(14, 30, 0xFF).unwrap();
+        assert_eq!(time.to_string(), "14:30:FF.FF");
+        assert_eq!(CosemTime::from_string("14:30:FF").unwrap(), time);
+        assert_eq!(time.total_seconds(), None);
+    }
+
+    #[test]
+    fn test_cosem_time_total_seconds() {
+        let time = CosemTime::new(14, 30, 45).unwrap();
+        assert_eq!(time.total_seconds(), Some(14 * 3600 + 30 * 60 + 45));
+        assert_eq!(CosemTime::new(0, 0, 0).unwrap().total_seconds(), Some(0));
+    }
 }
@@ -441,6 +441,300 @@ impl DataObject {
             ))),
         }
     }
+
+    /// Compute the A-XDR encoded size in bytes without encoding
+    ///
+    /// Includes the type tag and any length prefixes, so the result equals
+    /// `encode().len()` for the A-XDR encoder. Useful for pre-sizing
+    /// buffers or checking whether a value fits in the negotiated PDU size
+    /// before building the request.
+    ///
+    /// For `CompactArray` the contents must be serialized to know their
+    /// size; an unencodable compact array contributes only its tag byte.
+    pub fn encoded_len(&self) -> usize {
+        // Every variant starts with a one-byte type tag
+        1 + match self {
+            DataObject::Null => 0,
+            DataObject::Boolean(_)
+            | DataObject::Integer8(_)
+            | DataObject::Unsigned8(_)
+            | DataObject::Enumerate(_)
+            | DataObject::Bcd(_) => 1,
+            DataObject::Integer16(_) | DataObject::Unsigned16(_) => 2,
+            DataObject::Integer32(_) | DataObject::Unsigned32(_) | DataObject::Float32(_) => 4,
+            DataObject::Integer64(_) | DataObject::Unsigned64(_) | DataObject::Float64(_) => 8,
+            DataObject::OctetString(s) | DataObject::VisibleString(s) | DataObject::Utf8String(s) => {
+                Self::length_prefix_len(s.len()) + s.len()
+            }
+            // Bit strings are length-prefixed by bit count, not byte count
+            DataObject::BitString(bs) => {
+                Self::length_prefix_len(bs.num_bits()) + bs.as_bytes().len()
+            }
+            DataObject::Array(items) | DataObject::Structure(items) => {
+                Self::length_prefix_len(items.len())
+                    + items.iter().map(DataObject::encoded_len).sum::<usize>()
+            }
+            DataObject::CompactArray(ca) => ca
+                .encode()
+                .map(|bytes| Self::length_prefix_len(bytes.len()) + bytes.len())
+                .unwrap_or(0),
+            DataObject::Date(_) => CosemDate::LENGTH,
+            DataObject::Time(_) => CosemTime::LENGTH,
+            DataObject::DateTime(_) => CosemDateTime::LENGTH,
+        }
+    }
+
+    /// Size of an A-XDR length prefix: one byte short form below 128,
+    /// otherwise a length-of-length byte plus the minimal big-endian length
+    fn length_prefix_len(len: usize) -> usize {
+        if len < 128 {
+            1
+        } else {
+            let mut bytes = 0;
+            let mut remaining = len;
+            while remaining > 0 {
+                bytes += 1;
+                remaining >>= 8;
+            }
+            1 + bytes
+        }
+    }
+
+    /// Deep equality with a tolerance for floating point values
+    ///
+    /// The derived `PartialEq` compares `Float32`/`Float64` bit-exactly,
+    /// which causes spurious mismatches when comparing a value read from a
+    /// meter against an expected one. This method treats floats as equal
+    /// when their absolute difference is at most `epsilon` and recurses
+    /// into arrays and structures; all other variants fall back to exact
+    /// equality.
+    pub fn approx_eq(&self, other: &DataObject, epsilon: f64) -> bool {
+        match (self, other) {
+            (DataObject::Float32(a), DataObject::Float32(b)) => {
+                (f64::from(*a) - f64::from(*b)).abs() <= epsilon
+            }
+            (DataObject::Float64(a), DataObject::Float64(b)) => (a - b).abs() <= epsilon,
+            (DataObject::Array(a), DataObject::Array(b))
+            | (DataObject::Structure(a), DataObject::Structure(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|(x, y)| x.approx_eq(y, epsilon))
+            }
+            _ => self == other,
+        }
+    }
+}
+
+/// Tagged JSON conversion for test fixtures and logging
+///
+/// The representation is a JSON object with a snake_case `"type"` tag and
+/// a `"value"` payload, e.g. `{"type":"unsigned32","value":12345}`;
+/// arrays and structures recurse. This is deliberately independent of the
+/// serde derive on `DataObject`, whose shape follows the Rust enum rather
+/// than a stable fixture format.
+#[cfg(feature = "serde_json")]
+impl DataObject {
+    /// Convert to the tagged JSON representation
+    ///
+    /// Octet strings become uppercase hex; visible and UTF-8 strings become
+    /// JSON text (invalid UTF-8 bytes are replaced, so only the hex-based
+    /// `OctetString` is lossless for arbitrary bytes). Bit strings, compact
+    /// arrays and date/time values embed their serde representation.
+    ///
+    /// ```
+    /// use dlms_core::DataObject;
+    ///
+    /// let json = DataObject::Unsigned32(12345).to_json();
+    /// assert_eq!(json["type"], "unsigned32");
+    /// assert_eq!(json["value"], 12345);
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::json;
+
+        match self {
+            DataObject::Null => json!({"type": "null"}),
+            DataObject::Boolean(v) => json!({"type": "boolean", "value": v}),
+            DataObject::Integer8(v) => json!({"type": "integer8", "value": v}),
+            DataObject::Integer16(v) => json!({"type": "integer16", "value": v}),
+            DataObject::Integer32(v) => json!({"type": "integer32", "value": v}),
+            DataObject::Integer64(v) => json!({"type": "integer64", "value": v}),
+            DataObject::Unsigned8(v) => json!({"type": "unsigned8", "value": v}),
+            DataObject::Unsigned16(v) => json!({"type": "unsigned16", "value": v}),
+            DataObject::Unsigned32(v) => json!({"type": "unsigned32", "value": v}),
+            DataObject::Unsigned64(v) => json!({"type": "unsigned64", "value": v}),
+            DataObject::Float32(v) => json!({"type": "float32", "value": v}),
+            DataObject::Float64(v) => json!({"type": "float64", "value": v}),
+            DataObject::Enumerate(v) => json!({"type": "enumerate", "value": v}),
+            DataObject::Bcd(v) => json!({"type": "bcd", "value": v}),
+            DataObject::OctetString(s) => json!({"type": "octet_string", "value": Self::bytes_to_hex(s)}),
+            DataObject::VisibleString(s) => {
+                json!({"type": "visible_string", "value": String::from_utf8_lossy(s)})
+            }
+            DataObject::Utf8String(s) => {
+                json!({"type": "utf8_string", "value": String::from_utf8_lossy(s)})
+            }
+            DataObject::BitString(bs) => json!({"type": "bit_string", "value": Self::nested_json(bs)}),
+            DataObject::Array(items) => {
+                json!({"type": "array", "value": items.iter().map(DataObject::to_json).collect::<Vec<_>>()})
+            }
+            DataObject::Structure(items) => {
+                json!({"type": "structure", "value": items.iter().map(DataObject::to_json).collect::<Vec<_>>()})
+            }
+            DataObject::CompactArray(ca) => {
+                json!({"type": "compact_array", "value": Self::nested_json(ca)})
+            }
+            DataObject::Date(d) => json!({"type": "date", "value": Self::nested_json(d)}),
+            DataObject::Time(t) => json!({"type": "time", "value": Self::nested_json(t)}),
+            DataObject::DateTime(dt) => json!({"type": "date_time", "value": Self::nested_json(dt)}),
+        }
+    }
+
+    /// Parse the tagged JSON representation produced by [`to_json`](Self::to_json)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for a missing or unknown `"type"` tag, a missing
+    /// `"value"`, or a payload that does not fit the tagged type.
+    pub fn from_json(value: &serde_json::Value) -> DlmsResult<DataObject> {
+        let tag = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| {
+                DlmsError::InvalidData(
+                    "JSON data object must be an object with a string \"type\" tag".to_string(),
+                )
+            })?;
+
+        // Null carries no payload; every other tag requires one
+        if tag == "null" {
+            return Ok(DataObject::Null);
+        }
+        let payload = value.get("value").ok_or_else(|| {
+            DlmsError::InvalidData(format!(
+                "JSON data object of type \"{}\" is missing its \"value\"",
+                tag
+            ))
+        })?;
+
+        match tag {
+            "boolean" => Ok(DataObject::Boolean(payload.as_bool().ok_or_else(|| {
+                DlmsError::InvalidData("Expected a JSON boolean for type \"boolean\"".to_string())
+            })?)),
+            "integer8" => Ok(DataObject::Integer8(Self::json_signed(tag, payload)?)),
+            "integer16" => Ok(DataObject::Integer16(Self::json_signed(tag, payload)?)),
+            "integer32" => Ok(DataObject::Integer32(Self::json_signed(tag, payload)?)),
+            "integer64" => Ok(DataObject::Integer64(Self::json_signed(tag, payload)?)),
+            "unsigned8" => Ok(DataObject::Unsigned8(Self::json_unsigned(tag, payload)?)),
+            "unsigned16" => Ok(DataObject::Unsigned16(Self::json_unsigned(tag, payload)?)),
+            "unsigned32" => Ok(DataObject::Unsigned32(Self::json_unsigned(tag, payload)?)),
+            "unsigned64" => Ok(DataObject::Unsigned64(Self::json_unsigned(tag, payload)?)),
+            "float32" => Ok(DataObject::Float32(Self::json_float(tag, payload)? as f32)),
+            "float64" => Ok(DataObject::Float64(Self::json_float(tag, payload)?)),
+            "enumerate" => Ok(DataObject::Enumerate(Self::json_unsigned(tag, payload)?)),
+            "bcd" => Ok(DataObject::Bcd(Self::json_unsigned(tag, payload)?)),
+            "octet_string" => Ok(DataObject::OctetString(Self::hex_to_bytes(payload)?)),
+            "visible_string" => Ok(DataObject::VisibleString(Self::json_text(tag, payload)?)),
+            "utf8_string" => Ok(DataObject::Utf8String(Self::json_text(tag, payload)?)),
+            "bit_string" => Ok(DataObject::BitString(Self::json_nested(tag, payload)?)),
+            "array" => Ok(DataObject::Array(Self::json_elements(tag, payload)?)),
+            "structure" => Ok(DataObject::Structure(Self::json_elements(tag, payload)?)),
+            "compact_array" => Ok(DataObject::CompactArray(Self::json_nested(tag, payload)?)),
+            "date" => Ok(DataObject::Date(Self::json_nested(tag, payload)?)),
+            "time" => Ok(DataObject::Time(Self::json_nested(tag, payload)?)),
+            "date_time" => Ok(DataObject::DateTime(Self::json_nested(tag, payload)?)),
+            _ => Err(DlmsError::InvalidData(format!(
+                "Unknown JSON data object type tag \"{}\"",
+                tag
+            ))),
+        }
+    }
+
+    fn bytes_to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02X}", b)).collect()
+    }
+
+    fn hex_to_bytes(payload: &serde_json::Value) -> DlmsResult<Vec<u8>> {
+        let hex = payload.as_str().ok_or_else(|| {
+            DlmsError::InvalidData("Expected a hex string for type \"octet_string\"".to_string())
+        })?;
+        if hex.len() % 2 != 0 {
+            return Err(DlmsError::InvalidData(format!(
+                "Hex string has odd length {}",
+                hex.len()
+            )));
+        }
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| {
+                u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| {
+                    DlmsError::InvalidData(format!("Invalid hex digits \"{}\"", &hex[i..i + 2]))
+                })
+            })
+            .collect()
+    }
+
+    /// Serialize an embedded data struct; these contain only numbers and
+    /// byte vectors, so conversion to a JSON value cannot fail
+    fn nested_json<T: Serialize>(value: &T) -> serde_json::Value {
+        serde_json::to_value(value).expect("plain data struct serializes to JSON")
+    }
+
+    fn json_nested<T: serde::de::DeserializeOwned>(
+        tag: &str,
+        payload: &serde_json::Value,
+    ) -> DlmsResult<T> {
+        serde_json::from_value(payload.clone()).map_err(|e| {
+            DlmsError::InvalidData(format!("Invalid payload for type \"{}\": {}", tag, e))
+        })
+    }
+
+    fn json_signed<T: TryFrom<i64>>(tag: &str, payload: &serde_json::Value) -> DlmsResult<T> {
+        let number = payload.as_i64().ok_or_else(|| {
+            DlmsError::InvalidData(format!("Expected a JSON integer for type \"{}\"", tag))
+        })?;
+        T::try_from(number).map_err(|_| {
+            DlmsError::InvalidData(format!("Value {} is out of range for type \"{}\"", number, tag))
+        })
+    }
+
+    fn json_unsigned<T: TryFrom<u64>>(tag: &str, payload: &serde_json::Value) -> DlmsResult<T> {
+        let number = payload.as_u64().ok_or_else(|| {
+            DlmsError::InvalidData(format!(
+                "Expected an unsigned JSON integer for type \"{}\"",
+                tag
+            ))
+        })?;
+        T::try_from(number).map_err(|_| {
+            DlmsError::InvalidData(format!("Value {} is out of range for type \"{}\"", number, tag))
+        })
+    }
+
+    fn json_float(tag: &str, payload: &serde_json::Value) -> DlmsResult<f64> {
+        payload.as_f64().ok_or_else(|| {
+            DlmsError::InvalidData(format!("Expected a JSON number for type \"{}\"", tag))
+        })
+    }
+
+    fn json_text(tag: &str, payload: &serde_json::Value) -> DlmsResult<Vec<u8>> {
+        payload
+            .as_str()
+            .map(|s| s.as_bytes().to_vec())
+            .ok_or_else(|| {
+                DlmsError::InvalidData(format!("Expected a JSON string for type \"{}\"", tag))
+            })
+    }
+
+    fn json_elements(tag: &str, payload: &serde_json::Value) -> DlmsResult<Vec<DataObject>> {
+        payload
+            .as_array()
+            .ok_or_else(|| {
+                DlmsError::InvalidData(format!("Expected a JSON array for type \"{}\"", tag))
+            })?
+            .iter()
+            .map(DataObject::from_json)
+            .collect()
+    }
 }
 
 impl fmt::Display for DataObject {
@@ -496,6 +790,161 @@ impl fmt::Display for DataObject {
     }
 }
 
+/// Fluent builder for nested `DataObject` structures
+///
+/// Collects fields in order and produces a `Structure`, avoiding deeply
+/// nested `DataObject::new_structure(vec![...])` trees:
+///
+/// ```
+/// use dlms_core::datatypes::DataObjectBuilder;
+///
+/// let obj = DataObjectBuilder::structure()
+///     .field_u32(42)
+///     .field_octets(vec![0x01, 0x02])
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DataObjectBuilder {
+    fields: Vec<DataObject>,
+}
+
+impl DataObjectBuilder {
+    /// Start building a structure
+    pub fn structure() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Append an already-constructed field
+    pub fn field(mut self, value: DataObject) -> Self {
+        self.fields.push(value);
+        self
+    }
+
+    /// Append a null field
+    pub fn field_null(self) -> Self {
+        self.field(DataObject::Null)
+    }
+
+    /// Append a boolean field
+    pub fn field_bool(self, value: bool) -> Self {
+        self.field(DataObject::Boolean(value))
+    }
+
+    /// Append an integer 8-bit field
+    pub fn field_i8(self, value: i8) -> Self {
+        self.field(DataObject::Integer8(value))
+    }
+
+    /// Append an integer 16-bit field
+    pub fn field_i16(self, value: i16) -> Self {
+        self.field(DataObject::Integer16(value))
+    }
+
+    /// Append an integer 32-bit field
+    pub fn field_i32(self, value: i32) -> Self {
+        self.field(DataObject::Integer32(value))
+    }
+
+    /// Append an integer 64-bit field
+    pub fn field_i64(self, value: i64) -> Self {
+        self.field(DataObject::Integer64(value))
+    }
+
+    /// Append an unsigned 8-bit field
+    pub fn field_u8(self, value: u8) -> Self {
+        self.field(DataObject::Unsigned8(value))
+    }
+
+    /// Append an unsigned 16-bit field
+    pub fn field_u16(self, value: u16) -> Self {
+        self.field(DataObject::Unsigned16(value))
+    }
+
+    /// Append an unsigned 32-bit field
+    pub fn field_u32(self, value: u32) -> Self {
+        self.field(DataObject::Unsigned32(value))
+    }
+
+    /// Append an unsigned 64-bit field
+    pub fn field_u64(self, value: u64) -> Self {
+        self.field(DataObject::Unsigned64(value))
+    }
+
+    /// Append an enumeration field
+    pub fn field_enumerate(self, value: u8) -> Self {
+        self.field(DataObject::Enumerate(value))
+    }
+
+    /// Append an octet string field
+    pub fn field_octets(self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.field(DataObject::OctetString(bytes.into()))
+    }
+
+    /// Append a visible string field
+    pub fn field_visible_string(self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.field(DataObject::VisibleString(bytes.into()))
+    }
+
+    /// Append an array field built from the given elements
+    ///
+    /// Unlike `DataObject::new_array` this does not enforce element-type
+    /// homogeneity; validate with `new_array` first when that matters.
+    pub fn array_of(self, elements: impl IntoIterator<Item = DataObject>) -> Self {
+        self.field(DataObject::Array(elements.into_iter().collect()))
+    }
+
+    /// Append a nested structure field built by the given closure
+    pub fn structure_of(self, build: impl FnOnce(DataObjectBuilder) -> DataObjectBuilder) -> Self {
+        let nested = build(DataObjectBuilder::structure());
+        self.field(nested.build())
+    }
+
+    /// Finish the builder, producing the structure
+    pub fn build(self) -> DataObject {
+        DataObject::Structure(self.fields)
+    }
+}
+
+/// Declarative construction of nested `DataObject` trees
+///
+/// Each element is a parenthesised `(kind value)` pair; `struct` and
+/// `array` take a braced, comma-separated list of elements and nest
+/// arbitrarily:
+///
+/// ```
+/// use dlms_core::dobj;
+///
+/// let obj = dobj!(struct {
+///     (u32 42),
+///     (octets vec![0x01, 0x02]),
+///     (array { (u8 1), (u8 2) }),
+/// });
+/// ```
+#[macro_export]
+macro_rules! dobj {
+    (null) => { $crate::DataObject::Null };
+    (bool $v:expr) => { $crate::DataObject::Boolean($v) };
+    (i8 $v:expr) => { $crate::DataObject::Integer8($v) };
+    (i16 $v:expr) => { $crate::DataObject::Integer16($v) };
+    (i32 $v:expr) => { $crate::DataObject::Integer32($v) };
+    (i64 $v:expr) => { $crate::DataObject::Integer64($v) };
+    (u8 $v:expr) => { $crate::DataObject::Unsigned8($v) };
+    (u16 $v:expr) => { $crate::DataObject::Unsigned16($v) };
+    (u32 $v:expr) => { $crate::DataObject::Unsigned32($v) };
+    (u64 $v:expr) => { $crate::DataObject::Unsigned64($v) };
+    (f32 $v:expr) => { $crate::DataObject::Float32($v) };
+    (f64 $v:expr) => { $crate::DataObject::Float64($v) };
+    (enum $v:expr) => { $crate::DataObject::Enumerate($v) };
+    (octets $v:expr) => { $crate::DataObject::OctetString(::std::convert::Into::into($v)) };
+    (visible $v:expr) => { $crate::DataObject::VisibleString(::std::convert::Into::into($v)) };
+    (struct { $( ( $($elem:tt)* ) ),* $(,)? }) => {
+        $crate::DataObject::Structure(vec![ $( $crate::dobj!($($elem)*) ),* ])
+    };
+    (array { $( ( $($elem:tt)* ) ),* $(,)? }) => {
+        $crate::DataObject::Array(vec![ $( $crate::dobj!($($elem)*) ),* ])
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -534,4 +983,134 @@ mod tests {
         ];
         assert!(DataObject::new_array(arr).is_err());
     }
+
+    /// Manual tree the builder and macro tests compare against
+    fn manual_structure() -> DataObject {
+        DataObject::new_structure(vec![
+            DataObject::new_unsigned32(42),
+            DataObject::new_octet_string(vec![0x01, 0x02, 0x03]),
+            DataObject::Array(vec![
+                DataObject::new_unsigned8(1),
+                DataObject::new_unsigned8(2),
+            ]),
+            DataObject::new_structure(vec![
+                DataObject::new_bool(true),
+                DataObject::new_null(),
+            ]),
+        ])
+    }
+
+    #[test]
+    fn test_data_object_builder_matches_manual_structure() {
+        let built = DataObjectBuilder::structure()
+            .field_u32(42)
+            .field_octets(vec![0x01, 0x02, 0x03])
+            .array_of([DataObject::new_unsigned8(1), DataObject::new_unsigned8(2)])
+            .structure_of(|nested| nested.field_bool(true).field_null())
+            .build();
+
+        assert_eq!(built, manual_structure());
+    }
+
+    #[test]
+    fn test_dobj_macro_matches_manual_structure() {
+        let built = crate::dobj!(struct {
+            (u32 42),
+            (octets vec![0x01, 0x02, 0x03]),
+            (array { (u8 1), (u8 2) }),
+            (struct { (bool true), (null) }),
+        });
+
+        assert_eq!(built, manual_structure());
+    }
+
+    #[test]
+    fn test_data_object_json_roundtrip() {
+        let obj = manual_structure();
+        let json = serde_json::to_string(&obj).unwrap();
+
+        // Externally tagged: the variant name carries the COSEM type
+        assert!(json.contains("\"Unsigned32\":42"));
+
+        let decoded: DataObject = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, obj);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_data_object_tagged_json_roundtrip() {
+        let obj = DataObject::new_structure(vec![
+            DataObject::new_unsigned32(12345),
+            DataObject::new_integer16(-42),
+            DataObject::new_octet_string(vec![0x00, 0x01, 0xFF]),
+            DataObject::new_visible_string(b"meter".to_vec()),
+            DataObject::Array(vec![
+                DataObject::new_bool(true),
+                DataObject::new_bool(false),
+            ]),
+            DataObject::new_structure(vec![
+                DataObject::new_null(),
+                DataObject::new_date(CosemDate::new(2026, 8, 29).unwrap()),
+            ]),
+        ]);
+
+        let json = obj.to_json();
+        assert_eq!(json["type"], "structure");
+        assert_eq!(json["value"][0]["type"], "unsigned32");
+        assert_eq!(json["value"][0]["value"], 12345);
+        assert_eq!(json["value"][2]["value"], "0001FF");
+        assert_eq!(json["value"][3]["value"], "meter");
+
+        assert_eq!(DataObject::from_json(&json).unwrap(), obj);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_data_object_from_json_rejects_bad_input() {
+        let unknown = serde_json::json!({"type": "unsigned128", "value": 1});
+        let err = DataObject::from_json(&unknown).unwrap_err();
+        assert!(err.to_string().contains("unsigned128"));
+
+        // Missing tag, missing value, and out-of-range payload all fail
+        assert!(DataObject::from_json(&serde_json::json!(42)).is_err());
+        assert!(DataObject::from_json(&serde_json::json!({"type": "unsigned8"})).is_err());
+        assert!(
+            DataObject::from_json(&serde_json::json!({"type": "unsigned8", "value": 256}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_approx_eq_floats_within_epsilon() {
+        let a = DataObject::new_float64(230.0);
+        let b = DataObject::new_float64(230.0 + 1e-7);
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-6));
+        assert!(!a.approx_eq(&b, 1e-9));
+
+        let c = DataObject::new_float32(0.1 + 0.2);
+        let d = DataObject::new_float32(0.3);
+        assert!(c.approx_eq(&d, 1e-6));
+    }
+
+    #[test]
+    fn test_approx_eq_nested_structure() {
+        let read = DataObject::new_structure(vec![
+            DataObject::new_unsigned32(42),
+            DataObject::new_structure(vec![DataObject::new_float64(1.0 + 5e-8)]),
+        ]);
+        let expected = DataObject::new_structure(vec![
+            DataObject::new_unsigned32(42),
+            DataObject::new_structure(vec![DataObject::new_float64(1.0)]),
+        ]);
+
+        assert!(read.approx_eq(&expected, 1e-6));
+
+        // Non-float mismatches stay exact
+        let other = DataObject::new_structure(vec![
+            DataObject::new_unsigned32(43),
+            DataObject::new_structure(vec![DataObject::new_float64(1.0)]),
+        ]);
+        assert!(!read.approx_eq(&other, 1e-6));
+    }
 }
@@ -13,7 +13,7 @@ pub use compact_array::{CompactArray, TypeDesc as CompactArrayTypeDesc, Type as
 pub use cosem_date::{CosemDate, CosemDateFormat, Field, Month};
 pub use cosem_time::CosemTime;
 pub use cosem_date_time::{CosemDateTime, ClockStatus};
-pub use data_object::{DataObject, DataObjectType};
+pub use data_object::{DataObject, DataObjectBuilder, DataObjectType};
 
 // Re-export types when implemented
 // pub use data_object::{DataObject, DataObjectType};
@@ -5,31 +5,161 @@ use thiserror::Error;
 pub enum DlmsError {
     #[error("Connection error: {0}")]
     Connection(#[from] std::io::Error),
-    
+
     #[error("Protocol error: {0}")]
     Protocol(String),
-    
+
     #[error("Security error: {0}")]
     Security(String),
-    
+
     #[error("Timeout")]
     Timeout,
-    
+
     #[error("Invalid data: {0}")]
     InvalidData(String),
-    
+
     #[error("ASN.1 encoding error: {0}")]
     Asn1Encoding(String),
-    
+
     #[error("ASN.1 decoding error: {0}")]
     Asn1Decoding(String),
-    
+
     #[error("Frame invalid: {0}")]
     FrameInvalid(String),
 
+    #[error("Length mismatch: {0}")]
+    LengthMismatch(String),
+
     #[error("Access denied: {0}")]
     AccessDenied(String),
+
+    #[error("Data access error {code} ({}): {description}", data_access_result_text(*code))]
+    DataAccess { code: u8, description: String },
+
+    #[error("Exception response: {}", format_exception(*state_error, *service_error))]
+    Exception {
+        state_error: Option<u8>,
+        service_error: u8,
+    },
+
+    #[error("Decode error at offset {offset}: {context}")]
+    DecodeError { offset: usize, context: String },
 }
 
 /// Result type alias for jDLMS operations
 pub type DlmsResult<T> = Result<T, DlmsError>;
+
+/// Standard Green Book text for a Data-Access-Result code
+///
+/// Unassigned codes map to "unknown".
+pub fn data_access_result_text(code: u8) -> &'static str {
+    match code {
+        0 => "success",
+        1 => "hardware-fault",
+        2 => "temporary-failure",
+        3 => "read-write-denied",
+        4 => "object-undefined",
+        9 => "object-class-inconsistent",
+        11 => "object-unavailable",
+        12 => "type-unmatched",
+        13 => "scope-of-access-violated",
+        14 => "data-block-unavailable",
+        15 => "long-get-aborted",
+        16 => "no-long-get-in-progress",
+        17 => "long-set-aborted",
+        18 => "no-long-set-in-progress",
+        19 => "data-block-number-invalid",
+        250 => "other-reason",
+        _ => "unknown",
+    }
+}
+
+/// Standard Green Book text for an ExceptionResponse state-error code
+pub fn state_error_text(code: u8) -> &'static str {
+    match code {
+        1 => "service-not-allowed",
+        2 => "service-unknown",
+        _ => "unknown",
+    }
+}
+
+/// Standard Green Book text for an ExceptionResponse service-error code
+pub fn service_error_text(code: u8) -> &'static str {
+    match code {
+        1 => "operation-not-possible",
+        2 => "service-not-supported",
+        3 => "other-reason",
+        4 => "pdu-too-long",
+        5 => "deciphering-error",
+        6 => "invalid-inquiry",
+        _ => "unknown",
+    }
+}
+
+/// Render the state/service error pair of an exception response
+fn format_exception(state_error: Option<u8>, service_error: u8) -> String {
+    match state_error {
+        Some(state) => format!(
+            "{}, {}",
+            state_error_text(state),
+            service_error_text(service_error)
+        ),
+        None => service_error_text(service_error).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_access_result_standard_text() {
+        assert_eq!(data_access_result_text(4), "object-undefined");
+        assert_eq!(data_access_result_text(12), "type-unmatched");
+        assert_eq!(data_access_result_text(13), "scope-of-access-violated");
+        assert_eq!(data_access_result_text(250), "other-reason");
+        assert_eq!(data_access_result_text(200), "unknown");
+    }
+
+    #[test]
+    fn test_state_and_service_error_standard_text() {
+        assert_eq!(state_error_text(1), "service-not-allowed");
+        assert_eq!(state_error_text(2), "service-unknown");
+        assert_eq!(service_error_text(2), "service-not-supported");
+        assert_eq!(service_error_text(5), "deciphering-error");
+    }
+
+    #[test]
+    fn test_data_access_display_includes_standard_text() {
+        let error = DlmsError::DataAccess {
+            code: 13,
+            description: "Read denied by association".to_string(),
+        };
+        let rendered = error.to_string();
+        assert!(rendered.contains("scope-of-access-violated"), "{}", rendered);
+        assert!(rendered.contains("13"), "{}", rendered);
+    }
+
+    #[test]
+    fn test_exception_display_includes_standard_text() {
+        let error = DlmsError::Exception {
+            state_error: Some(1),
+            service_error: 2,
+        };
+        let rendered = error.to_string();
+        assert!(rendered.contains("service-not-allowed"), "{}", rendered);
+        assert!(rendered.contains("service-not-supported"), "{}", rendered);
+
+        let error = DlmsError::Exception {
+            state_error: None,
+            service_error: 4,
+        };
+        assert_eq!(error.to_string(), "Exception response: pdu-too-long");
+    }
+
+    #[test]
+    fn test_dlms_error_is_std_error() {
+        fn assert_error<E: std::error::Error>() {}
+        assert_error::<DlmsError>();
+    }
+}
@@ -93,7 +93,7 @@
 //! let data = vec![1, 2, 3, 4, 5];
 //! let slice = ByteSlice::from(&data);
 //!
-//! assert_eq!(slice.as_bytes(), &[1, 2, 3, 4, 5]);
+//! assert_eq!(slice.as_ref(), &[1, 2, 3, 4, 5]);
 //! ```
 //!
 //! # Module Structure
@@ -114,6 +114,6 @@ pub mod datatypes;
 pub mod pool;
 
 pub use error::{DlmsError, DlmsResult};
-pub use obis_code::ObisCode;
+pub use obis_code::{well_known, ObisCode};
 pub use datatypes::*;
 pub use pool::{BufferPool, BufferPoolConfig, PooledBuffer, ByteSlice, Lazy, lazy};
@@ -1,12 +1,13 @@
 use crate::error::{DlmsError, DlmsResult};
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 /// OBIS (Object Identification System) code for identifying COSEM objects
 ///
 /// OBIS codes are 6-byte identifiers used in DLMS/COSEM to uniquely identify
 /// objects in a logical device.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ObisCode {
     bytes: [u8; 6],
 }
@@ -22,7 +23,7 @@ impl ObisCode {
     /// * `d` - Fourth byte (D value)
     /// * `e` - Fifth byte (E value)
     /// * `f` - Sixth byte (F value)
-    pub fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> Self {
+    pub const fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> Self {
         Self {
             bytes: [a, b, c, d, e, f],
         }
@@ -70,10 +71,46 @@ impl ObisCode {
         Ok(Self { bytes })
     }
     
-    fn parse_extended_format(_s: &str) -> DlmsResult<Self> {
-        // TODO: Implement extended format parsing
-        // Format: "A-B:C.D.E*F" or "A-B:C.D.E"
-        Err(DlmsError::InvalidData("Extended format not yet implemented".to_string()))
+    fn parse_extended_format(s: &str) -> DlmsResult<Self> {
+        // Format: "A-B:C.D.E.F", "A-B:C.D.E*F" or "A-B:C.D.E" (F defaults to 255)
+        let (a, rest) = s.split_once('-').ok_or_else(|| {
+            DlmsError::InvalidData("Expected '-' between A and B values".to_string())
+        })?;
+        let (b, rest) = rest.split_once(':').ok_or_else(|| {
+            DlmsError::InvalidData("Expected ':' between B and C values".to_string())
+        })?;
+
+        // F is separated by '*' or by a fourth '.'
+        let (cde, f) = match rest.split_once('*') {
+            Some((cde, f)) => (cde, Some(f)),
+            None => {
+                let parts: Vec<&str> = rest.splitn(4, '.').collect();
+                match parts.as_slice() {
+                    [c, d, e, f] => {
+                        return Self::from_parts(&[a, b, c, d, e, f]);
+                    }
+                    _ => (rest, None),
+                }
+            }
+        };
+
+        let parts: Vec<&str> = cde.split('.').collect();
+        match (parts.as_slice(), f) {
+            ([c, d, e], Some(f)) => Self::from_parts(&[a, b, c, d, e, f]),
+            ([c, d, e], None) => Self::from_parts(&[a, b, c, d, e, "255"]),
+            _ => Err(DlmsError::InvalidData(
+                "Expected C.D.E values after ':'".to_string(),
+            )),
+        }
+    }
+
+    fn from_parts(parts: &[&str; 6]) -> DlmsResult<Self> {
+        let mut bytes = [0u8; 6];
+        for (i, part) in parts.iter().enumerate() {
+            bytes[i] = part.parse::<u8>()
+                .map_err(|_| DlmsError::InvalidData(format!("Invalid byte value: {}", part)))?;
+        }
+        Ok(Self { bytes })
     }
     
     /// Get the OBIS code as a byte array
@@ -115,6 +152,52 @@ impl ObisCode {
     pub fn f(&self) -> u8 {
         self.bytes[5]
     }
+
+    /// Check whether this code matches a pattern
+    ///
+    /// Pattern octets with value 255 act as wildcards and match any value,
+    /// so the pattern `1.0.1.8.255.255` matches all `1-0:1.8.x` registers.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Pattern to match against, 255 octets are wildcards
+    pub fn matches(&self, pattern: &ObisCode) -> bool {
+        self.bytes
+            .iter()
+            .zip(pattern.bytes.iter())
+            .all(|(byte, pat)| *pat == 255 || byte == pat)
+    }
+
+    /// Check whether this code lies within an inclusive range
+    ///
+    /// Codes are compared lexicographically byte by byte (A first, F last).
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - Inclusive lower bound
+    /// * `high` - Inclusive upper bound
+    pub fn in_range(&self, low: &ObisCode, high: &ObisCode) -> bool {
+        self.bytes >= low.bytes && self.bytes <= high.bytes
+    }
+}
+
+impl Serialize for ObisCode {
+    /// Serialize as the canonical `A-B:C.D.E.F` string
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!(
+            "{}-{}:{}.{}.{}.{}",
+            self.bytes[0], self.bytes[1], self.bytes[2],
+            self.bytes[3], self.bytes[4], self.bytes[5]
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for ObisCode {
+    /// Deserialize from any string format accepted by [`ObisCode::from_string`]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_string(&s).map_err(D::Error::custom)
+    }
 }
 
 impl fmt::Display for ObisCode {
@@ -128,6 +211,45 @@ impl fmt::Display for ObisCode {
     }
 }
 
+/// Well-known OBIS codes for objects present in most logical devices
+///
+/// Spares callers from retyping the standard codes (and the typos that
+/// come with that). Names follow the Blue Book object descriptions.
+pub mod well_known {
+    use super::ObisCode;
+
+    /// Clock, class 8 (0.0.1.0.0.255)
+    pub const CLOCK: ObisCode = ObisCode::new(0, 0, 1, 0, 0, 255);
+    /// COSEM logical device name (0.0.42.0.0.255)
+    pub const LOGICAL_DEVICE_NAME: ObisCode = ObisCode::new(0, 0, 42, 0, 0, 255);
+    /// Current association (0.0.40.0.0.255)
+    pub const CURRENT_ASSOCIATION: ObisCode = ObisCode::new(0, 0, 40, 0, 0, 255);
+    /// SAP assignment (0.0.41.0.0.255)
+    pub const SAP_ASSIGNMENT: ObisCode = ObisCode::new(0, 0, 41, 0, 0, 255);
+    /// Security setup (0.0.43.0.0.255)
+    pub const SECURITY_SETUP: ObisCode = ObisCode::new(0, 0, 43, 0, 0, 255);
+    /// Image transfer (0.0.44.0.0.255)
+    pub const IMAGE_TRANSFER: ObisCode = ObisCode::new(0, 0, 44, 0, 0, 255);
+    /// Push setup for interval push (0.0.25.9.0.255)
+    pub const PUSH_SETUP: ObisCode = ObisCode::new(0, 0, 25, 9, 0, 255);
+    /// Meter serial number (0.0.96.1.0.255)
+    pub const METER_SERIAL_NUMBER: ObisCode = ObisCode::new(0, 0, 96, 1, 0, 255);
+    /// Disconnect control (0.0.96.3.10.255)
+    pub const DISCONNECT_CONTROL: ObisCode = ObisCode::new(0, 0, 96, 3, 10, 255);
+    /// Active energy import +A (1.0.1.8.0.255)
+    pub const ACTIVE_ENERGY_IMPORT: ObisCode = ObisCode::new(1, 0, 1, 8, 0, 255);
+    /// Active energy export -A (1.0.2.8.0.255)
+    pub const ACTIVE_ENERGY_EXPORT: ObisCode = ObisCode::new(1, 0, 2, 8, 0, 255);
+    /// Reactive energy import +R (1.0.3.8.0.255)
+    pub const REACTIVE_ENERGY_IMPORT: ObisCode = ObisCode::new(1, 0, 3, 8, 0, 255);
+    /// Reactive energy export -R (1.0.4.8.0.255)
+    pub const REACTIVE_ENERGY_EXPORT: ObisCode = ObisCode::new(1, 0, 4, 8, 0, 255);
+    /// Instantaneous active power import +P (1.0.1.7.0.255)
+    pub const ACTIVE_POWER_IMPORT: ObisCode = ObisCode::new(1, 0, 1, 7, 0, 255);
+    /// Load profile with recording interval 1 (1.0.99.1.0.255)
+    pub const LOAD_PROFILE: ObisCode = ObisCode::new(1, 0, 99, 1, 0, 255);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,9 +267,91 @@ mod tests {
         assert_eq!(code, ObisCode::new(1, 1, 1, 8, 0, 255));
     }
     
+    #[test]
+    fn test_obis_code_from_string_extended_format() {
+        assert_eq!(
+            ObisCode::from_string("1-0:1.8.0.255").unwrap(),
+            ObisCode::new(1, 0, 1, 8, 0, 255)
+        );
+        assert_eq!(
+            ObisCode::from_string("1-0:1.8.0*200").unwrap(),
+            ObisCode::new(1, 0, 1, 8, 0, 200)
+        );
+        // F defaults to 255 when omitted
+        assert_eq!(
+            ObisCode::from_string("1-0:1.8.0").unwrap(),
+            ObisCode::new(1, 0, 1, 8, 0, 255)
+        );
+        assert!(ObisCode::from_string("1-0:1.8").is_err());
+    }
+
+    #[test]
+    fn test_obis_code_json_roundtrip() {
+        let code = ObisCode::new(1, 0, 1, 8, 0, 255);
+        let json = serde_json::to_string(&code).unwrap();
+        assert_eq!(json, "\"1-0:1.8.0.255\"");
+
+        let decoded: ObisCode = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, code);
+
+        // Dot format from config files is accepted too
+        let decoded: ObisCode = serde_json::from_str("\"1.0.1.8.0.255\"").unwrap();
+        assert_eq!(decoded, code);
+    }
+
     #[test]
     fn test_obis_code_display() {
         let code = ObisCode::new(1, 1, 1, 8, 0, 255);
         assert_eq!(format!("{}", code), "1.1.1.8.0.255");
     }
+
+    #[test]
+    fn test_obis_code_matches_wildcard_pattern() {
+        // Matches all 1-0:1.8.x.255 energy registers
+        let pattern = ObisCode::new(1, 0, 1, 8, 255, 255);
+        assert!(ObisCode::new(1, 0, 1, 8, 0, 255).matches(&pattern));
+        assert!(ObisCode::new(1, 0, 1, 8, 1, 255).matches(&pattern));
+        assert!(ObisCode::new(1, 0, 1, 8, 2, 0).matches(&pattern));
+        assert!(!ObisCode::new(1, 0, 2, 8, 0, 255).matches(&pattern));
+        assert!(!ObisCode::new(0, 0, 1, 8, 0, 255).matches(&pattern));
+    }
+
+    #[test]
+    fn test_well_known_codes_have_expected_bytes() {
+        assert_eq!(well_known::CLOCK.to_bytes(), [0, 0, 1, 0, 0, 255]);
+        assert_eq!(
+            well_known::LOGICAL_DEVICE_NAME.to_bytes(),
+            [0, 0, 42, 0, 0, 255]
+        );
+        assert_eq!(
+            well_known::CURRENT_ASSOCIATION.to_bytes(),
+            [0, 0, 40, 0, 0, 255]
+        );
+        assert_eq!(
+            well_known::ACTIVE_ENERGY_IMPORT.to_bytes(),
+            [1, 0, 1, 8, 0, 255]
+        );
+        assert_eq!(
+            well_known::DISCONNECT_CONTROL.to_bytes(),
+            [0, 0, 96, 3, 10, 255]
+        );
+    }
+
+    #[test]
+    fn test_obis_code_matches_exact_pattern() {
+        let code = ObisCode::new(1, 0, 1, 8, 0, 200);
+        assert!(code.matches(&code));
+        assert!(!code.matches(&ObisCode::new(1, 0, 1, 8, 1, 200)));
+    }
+
+    #[test]
+    fn test_obis_code_in_range() {
+        let low = ObisCode::new(1, 0, 1, 0, 0, 255);
+        let high = ObisCode::new(1, 0, 1, 8, 255, 255);
+        assert!(ObisCode::new(1, 0, 1, 8, 0, 255).in_range(&low, &high));
+        assert!(ObisCode::new(1, 0, 1, 0, 0, 255).in_range(&low, &high)); // inclusive low
+        assert!(ObisCode::new(1, 0, 1, 8, 255, 255).in_range(&low, &high)); // inclusive high
+        assert!(!ObisCode::new(1, 0, 2, 8, 0, 255).in_range(&low, &high));
+        assert!(!ObisCode::new(0, 0, 96, 1, 0, 255).in_range(&low, &high));
+    }
 }
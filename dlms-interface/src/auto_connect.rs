@@ -16,7 +16,7 @@
 use async_trait::async_trait;
 use dlms_application::pdu::SelectiveAccessDescriptor;
 use dlms_core::{
-    datatypes::{CosemDateTime, CosemDateFormat},
+    datatypes::{CosemDateTime, CosemDateFormat, Field},
     DlmsError, DlmsResult, ObisCode, DataObject,
 };
 use std::sync::Arc;
@@ -34,6 +34,8 @@ pub enum AutoConnectMode {
     Always = 1,
     /// Connect only within defined time windows
     WaitingForWindow = 2,
+    /// Connect only when an alarm is pending
+    AlarmInitiated = 3,
 }
 
 impl AutoConnectMode {
@@ -43,6 +45,7 @@ impl AutoConnectMode {
             0 => Self::Disabled,
             1 => Self::Always,
             2 => Self::WaitingForWindow,
+            3 => Self::AlarmInitiated,
             _ => Self::Disabled,
         }
     }
@@ -54,7 +57,10 @@ impl AutoConnectMode {
 
     /// Check if enabled
     pub fn is_enabled(self) -> bool {
-        matches!(self, Self::Always | Self::WaitingForWindow)
+        matches!(
+            self,
+            Self::Always | Self::WaitingForWindow | Self::AlarmInitiated
+        )
     }
 }
 
@@ -173,6 +179,57 @@ impl ConnectionTimeWindow {
             )),
         }
     }
+
+    /// Check whether the given date/time falls inside this window
+    ///
+    /// Only the time-of-day of `start_time`/`end_time` is compared, so a
+    /// window repeats daily on the days selected by `days_of_week`.
+    /// Windows where the start is later than the end wrap over midnight.
+    pub fn contains(&self, now: &CosemDateTime) -> bool {
+        if !self.matches_day(now) {
+            return false;
+        }
+
+        let now_secs = Self::seconds_of_day(now);
+        let start_secs = Self::seconds_of_day(&self.start_time);
+        let end_secs = Self::seconds_of_day(&self.end_time);
+
+        if start_secs <= end_secs {
+            now_secs >= start_secs && now_secs < end_secs
+        } else {
+            // Window wraps over midnight
+            now_secs >= start_secs || now_secs < end_secs
+        }
+    }
+
+    /// Check whether the day-of-week bitmap allows the given date
+    fn matches_day(&self, now: &CosemDateTime) -> bool {
+        if self.days_of_week == 0 {
+            // No day restriction
+            return true;
+        }
+        match now.get(Field::DayOfWeek) {
+            Ok(dow @ 1..=7) => self.days_of_week & (1 << (dow - 1)) != 0,
+            // Day of week not specified: cannot restrict
+            _ => true,
+        }
+    }
+
+    /// Seconds since midnight, treating unspecified components as zero
+    fn seconds_of_day(dt: &CosemDateTime) -> u32 {
+        let hour = dt.get(Field::Hour).ok().filter(|h| *h <= 23).unwrap_or(0);
+        let minute = dt
+            .get(Field::Minute)
+            .ok()
+            .filter(|m| *m <= 59)
+            .unwrap_or(0);
+        let second = dt
+            .get(Field::Second)
+            .ok()
+            .filter(|s| *s <= 59)
+            .unwrap_or(0);
+        hour * 3600 + minute * 60 + second
+    }
 }
 
 /// Auto Connect interface class (Class ID: 45)
@@ -202,6 +259,9 @@ pub struct AutoConnect {
 
     /// Current connection status
     status: Arc<RwLock<ConnectionStatus>>,
+
+    /// Whether an alarm is waiting to be reported (AlarmInitiated mode)
+    alarm_pending: Arc<RwLock<bool>>,
 }
 
 impl AutoConnect {
@@ -235,6 +295,7 @@ impl AutoConnect {
             calling_windows: Arc::new(RwLock::new(Vec::new())),
             destination: Arc::new(RwLock::new(String::new())),
             status: Arc::new(RwLock::new(ConnectionStatus::NotConnected)),
+            alarm_pending: Arc::new(RwLock::new(false)),
         }
     }
 
@@ -322,6 +383,58 @@ impl AutoConnect {
     pub async fn is_enabled(&self) -> bool {
         self.mode().await.is_enabled()
     }
+
+    /// Raise an alarm (relevant for AlarmInitiated mode)
+    pub async fn raise_alarm(&self) {
+        *self.alarm_pending.write().await = true;
+    }
+
+    /// Clear a pending alarm
+    pub async fn clear_alarm(&self) {
+        *self.alarm_pending.write().await = false;
+    }
+
+    /// Check if an alarm is pending
+    pub async fn alarm_pending(&self) -> bool {
+        *self.alarm_pending.read().await
+    }
+
+    /// Decide whether a connection should be established at the given time
+    ///
+    /// The decision is based on the operating mode:
+    /// - `Disabled`: never connect
+    /// - `Always`: always connect
+    /// - `WaitingForWindow`: connect only when `now` falls inside one of
+    ///   the calling windows (no windows configured means never)
+    /// - `AlarmInitiated`: connect only while an alarm is pending
+    ///
+    /// An already established or in-progress connection is never re-dialed.
+    pub async fn should_connect(&self, now: &CosemDateTime) -> bool {
+        let status = self.status().await;
+        if status.is_connected() || status.is_in_progress() {
+            return false;
+        }
+
+        match self.mode().await {
+            AutoConnectMode::Disabled => false,
+            AutoConnectMode::Always => true,
+            AutoConnectMode::WaitingForWindow => {
+                let windows = self.calling_windows.read().await;
+                windows.iter().any(|w| w.contains(now))
+            }
+            AutoConnectMode::AlarmInitiated => self.alarm_pending().await,
+        }
+    }
+
+    /// Mark a connection as established, updating the status
+    pub async fn connect(&self) {
+        self.set_status(ConnectionStatus::Connected).await;
+    }
+
+    /// Mark the connection as released, updating the status
+    pub async fn disconnect(&self) {
+        self.set_status(ConnectionStatus::NotConnected).await;
+    }
 }
 
 #[async_trait]
@@ -677,6 +790,89 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_auto_connect_should_connect_in_window() {
+        let ac = AutoConnect::with_default_obis();
+        ac.set_mode(AutoConnectMode::WaitingForWindow).await;
+
+        let start = CosemDateTime::new(2024, 1, 1, 8, 0, 0, 0, &[]).unwrap();
+        let end = CosemDateTime::new(2024, 1, 1, 18, 0, 0, 0, &[]).unwrap();
+        ac.add_calling_window(ConnectionTimeWindow::new(start, end, 0))
+            .await;
+
+        let noon = CosemDateTime::new(2024, 1, 1, 12, 0, 0, 0, &[]).unwrap();
+        assert!(ac.should_connect(&noon).await);
+    }
+
+    #[tokio::test]
+    async fn test_auto_connect_should_connect_out_of_window() {
+        let ac = AutoConnect::with_default_obis();
+        ac.set_mode(AutoConnectMode::WaitingForWindow).await;
+
+        let start = CosemDateTime::new(2024, 1, 1, 8, 0, 0, 0, &[]).unwrap();
+        let end = CosemDateTime::new(2024, 1, 1, 18, 0, 0, 0, &[]).unwrap();
+        ac.add_calling_window(ConnectionTimeWindow::new(start, end, 0))
+            .await;
+
+        let night = CosemDateTime::new(2024, 1, 1, 22, 0, 0, 0, &[]).unwrap();
+        assert!(!ac.should_connect(&night).await);
+    }
+
+    #[tokio::test]
+    async fn test_auto_connect_window_day_of_week_mismatch() {
+        let ac = AutoConnect::with_default_obis();
+        ac.set_mode(AutoConnectMode::WaitingForWindow).await;
+
+        let start = CosemDateTime::new(2024, 1, 1, 8, 0, 0, 0, &[]).unwrap();
+        let end = CosemDateTime::new(2024, 1, 1, 18, 0, 0, 0, &[]).unwrap();
+        // Monday only (bit 0)
+        ac.add_calling_window(ConnectionTimeWindow::new(start, end, 0x01))
+            .await;
+
+        // Tuesday (day_of_week = 2) at noon
+        let tuesday =
+            CosemDateTime::new_with_details(2024, 1, 2, 2, 12, 0, 0, 0xff, 0, &[]).unwrap();
+        assert!(!ac.should_connect(&tuesday).await);
+
+        // Monday (day_of_week = 1) at noon
+        let monday =
+            CosemDateTime::new_with_details(2024, 1, 1, 1, 12, 0, 0, 0xff, 0, &[]).unwrap();
+        assert!(ac.should_connect(&monday).await);
+    }
+
+    #[tokio::test]
+    async fn test_auto_connect_alarm_initiated_mode() {
+        let ac = AutoConnect::with_default_obis();
+        ac.set_mode(AutoConnectMode::AlarmInitiated).await;
+
+        let noon = CosemDateTime::new(2024, 1, 1, 12, 0, 0, 0, &[]).unwrap();
+        assert!(!ac.should_connect(&noon).await);
+
+        ac.raise_alarm().await;
+        assert!(ac.should_connect(&noon).await);
+
+        ac.clear_alarm().await;
+        assert!(!ac.should_connect(&noon).await);
+    }
+
+    #[tokio::test]
+    async fn test_auto_connect_connect_disconnect() {
+        let ac = AutoConnect::with_default_obis();
+        ac.set_mode(AutoConnectMode::Always).await;
+
+        let noon = CosemDateTime::new(2024, 1, 1, 12, 0, 0, 0, &[]).unwrap();
+        assert!(ac.should_connect(&noon).await);
+
+        ac.connect().await;
+        assert_eq!(ac.status().await, ConnectionStatus::Connected);
+        // Already connected: no re-dial
+        assert!(!ac.should_connect(&noon).await);
+
+        ac.disconnect().await;
+        assert_eq!(ac.status().await, ConnectionStatus::NotConnected);
+        assert!(ac.should_connect(&noon).await);
+    }
+
     #[tokio::test]
     async fn test_auto_connect_invalid_method() {
         let ac = AutoConnect::with_default_obis();
@@ -15,6 +15,7 @@
 
 use async_trait::async_trait;
 use dlms_application::pdu::SelectiveAccessDescriptor;
+use dlms_core::datatypes::{CosemDateFormat, CosemDateTime};
 use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -60,6 +61,38 @@ impl ChargeType {
     }
 }
 
+/// A per-unit rate with an optional activation time
+///
+/// A charge can hold several unit charges; at any point in time the one
+/// with the latest activation time not in the future is active. An entry
+/// without an activation time is active from the start and acts as the
+/// base rate.
+#[derive(Debug, Clone)]
+pub struct UnitCharge {
+    /// Price per consumed unit
+    pub rate: i64,
+    /// When this rate becomes active; `None` means active from the start
+    pub activation_time: Option<CosemDateTime>,
+}
+
+impl UnitCharge {
+    /// Create a unit charge active from the start
+    pub fn new(rate: i64) -> Self {
+        Self {
+            rate,
+            activation_time: None,
+        }
+    }
+
+    /// Create a unit charge that activates at `activation_time`
+    pub fn new_with_activation(rate: i64, activation_time: CosemDateTime) -> Self {
+        Self {
+            rate,
+            activation_time: Some(activation_time),
+        }
+    }
+}
+
 /// Charge interface class (Class ID: 62)
 ///
 /// Default OBIS: 0-0:62.0.0.255
@@ -90,6 +123,18 @@ pub struct Charge {
 
     /// Whether the charge is active
     active: Arc<RwLock<bool>>,
+
+    /// Unit charges with their activation times
+    unit_charges: Arc<RwLock<Vec<UnitCharge>>>,
+
+    /// Tax added per charging period
+    period_tax: Arc<RwLock<i64>>,
+
+    /// Total amount paid through this charge
+    total_amount_paid: Arc<RwLock<i64>>,
+
+    /// Amount remaining to be collected
+    total_amount_remaining: Arc<RwLock<i64>>,
 }
 
 impl Charge {
@@ -126,6 +171,10 @@ impl Charge {
             currency: Arc::new(RwLock::new(String::new())),
             charge_per_unit: Arc::new(RwLock::new(0)),
             active: Arc::new(RwLock::new(false)),
+            unit_charges: Arc::new(RwLock::new(Vec::new())),
+            period_tax: Arc::new(RwLock::new(0)),
+            total_amount_paid: Arc::new(RwLock::new(0)),
+            total_amount_remaining: Arc::new(RwLock::new(0)),
         }
     }
 
@@ -230,6 +279,122 @@ impl Charge {
     pub async fn reset(&self) {
         *self.total_amount_charged.write().await = 0;
     }
+
+    /// Add a unit charge
+    pub async fn add_unit_charge(&self, unit_charge: UnitCharge) {
+        self.unit_charges.write().await.push(unit_charge);
+    }
+
+    /// Get the tax added per charging period
+    pub async fn period_tax(&self) -> i64 {
+        *self.period_tax.read().await
+    }
+
+    /// Set the tax added per charging period
+    pub async fn set_period_tax(&self, tax: i64) {
+        *self.period_tax.write().await = tax;
+    }
+
+    /// Get the total amount paid through this charge
+    pub async fn total_amount_paid(&self) -> i64 {
+        *self.total_amount_paid.read().await
+    }
+
+    /// Get the amount remaining to be collected
+    pub async fn total_amount_remaining(&self) -> i64 {
+        *self.total_amount_remaining.read().await
+    }
+
+    /// Set the amount remaining to be collected
+    pub async fn set_total_amount_remaining(&self, amount: i64) {
+        *self.total_amount_remaining.write().await = amount;
+    }
+
+    /// Apply the charge to a consumption reading
+    ///
+    /// Computes the charge as the active unit charge's rate at `now`
+    /// applied to `consumption`, plus the period tax, then accumulates it
+    /// into `total_amount_charged`/`total_amount_paid` and deducts it from
+    /// `total_amount_remaining`. Falls back to `charge_per_unit` when no
+    /// unit charges are configured. Returns the amount charged.
+    ///
+    /// # Errors
+    /// Returns error if `consumption` is not an integer data object
+    pub async fn apply(&self, consumption: &DataObject, now: CosemDateTime) -> DlmsResult<i64> {
+        let quantity = Self::consumption_value(consumption)?;
+        let rate = self.active_rate(&now).await;
+        let amount = rate
+            .saturating_mul(quantity)
+            .saturating_add(self.period_tax().await);
+
+        {
+            let mut charged = self.total_amount_charged.write().await;
+            *charged = charged.saturating_add(amount);
+        }
+        {
+            let mut paid = self.total_amount_paid.write().await;
+            *paid = paid.saturating_add(amount);
+        }
+        {
+            let mut remaining = self.total_amount_remaining.write().await;
+            *remaining = remaining.saturating_sub(amount);
+        }
+
+        Ok(amount)
+    }
+
+    /// Select the rate of the unit charge active at `now`
+    ///
+    /// Entries whose activation time is in the future are ignored; among
+    /// the rest, the one with the latest activation time wins, with
+    /// always-active entries (no activation time) losing to any dated one.
+    async fn active_rate(&self, now: &CosemDateTime) -> i64 {
+        let now_key = Self::activation_key(now);
+        let unit_charges = self.unit_charges.read().await;
+
+        let mut active: Option<(Option<[u8; 8]>, i64)> = None;
+        for entry in unit_charges.iter() {
+            let key = entry.activation_time.as_ref().map(Self::activation_key);
+            if let Some(ref k) = key
+                && *k > now_key
+            {
+                continue;
+            }
+            if active.as_ref().is_none_or(|(current, _)| key >= *current) {
+                active = Some((key, entry.rate));
+            }
+        }
+
+        match active {
+            Some((_, rate)) => rate,
+            None => i64::from(*self.charge_per_unit.read().await),
+        }
+    }
+
+    /// Build a sortable key from a date-time, skipping the day-of-week
+    /// byte that would break lexicographic ordering
+    fn activation_key(time: &CosemDateTime) -> [u8; 8] {
+        let bytes = time.encode();
+        [
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[5], bytes[6], bytes[7], bytes[8],
+        ]
+    }
+
+    /// Extract an integer quantity from a consumption reading
+    fn consumption_value(consumption: &DataObject) -> DlmsResult<i64> {
+        match consumption {
+            DataObject::Integer8(v) => Ok(i64::from(*v)),
+            DataObject::Integer16(v) => Ok(i64::from(*v)),
+            DataObject::Integer32(v) => Ok(i64::from(*v)),
+            DataObject::Integer64(v) => Ok(*v),
+            DataObject::Unsigned8(v) => Ok(i64::from(*v)),
+            DataObject::Unsigned16(v) => Ok(i64::from(*v)),
+            DataObject::Unsigned32(v) => Ok(i64::from(*v)),
+            _ => Err(DlmsError::InvalidData(
+                "Expected an integer data object for consumption".to_string(),
+            )),
+        }
+    }
 }
 
 #[async_trait]
@@ -586,4 +751,73 @@ mod tests {
         let result = c.invoke_method(1, None, None, None).await;
         assert!(result.is_err());
     }
+
+    fn date_time(year: u16, month: u8, day: u8) -> CosemDateTime {
+        CosemDateTime::new(year, month, day, 0, 0, 0, 0, &[]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_charge_apply_unit_charge_with_tax() {
+        let c = Charge::with_default_obis();
+        c.add_unit_charge(UnitCharge::new(10)).await;
+        c.set_period_tax(5).await;
+        c.set_total_amount_remaining(2000).await;
+
+        let amount = c
+            .apply(&DataObject::Unsigned32(100), date_time(2026, 8, 29))
+            .await
+            .unwrap();
+
+        assert_eq!(amount, 1005); // 100 * 10 + 5
+        assert_eq!(c.total_amount_charged().await, 1005);
+        assert_eq!(c.total_amount_paid().await, 1005);
+        assert_eq!(c.total_amount_remaining().await, 995);
+    }
+
+    #[tokio::test]
+    async fn test_charge_apply_switches_rate_by_activation_time() {
+        let c = Charge::with_default_obis();
+        c.add_unit_charge(UnitCharge::new(10)).await;
+        c.add_unit_charge(UnitCharge::new_with_activation(20, date_time(2026, 6, 1)))
+            .await;
+
+        // Before the dated entry activates, the base rate applies
+        let before = c
+            .apply(&DataObject::Unsigned32(10), date_time(2026, 5, 1))
+            .await
+            .unwrap();
+        assert_eq!(before, 100);
+
+        // After the activation time, the dated entry wins
+        let after = c
+            .apply(&DataObject::Unsigned32(10), date_time(2026, 7, 1))
+            .await
+            .unwrap();
+        assert_eq!(after, 200);
+        assert_eq!(c.total_amount_paid().await, 300);
+    }
+
+    #[tokio::test]
+    async fn test_charge_apply_falls_back_to_charge_per_unit() {
+        let c = Charge::with_default_obis();
+        c.set_charge_per_unit(7).await;
+
+        let amount = c
+            .apply(&DataObject::Unsigned16(3), date_time(2026, 8, 29))
+            .await
+            .unwrap();
+        assert_eq!(amount, 21);
+    }
+
+    #[tokio::test]
+    async fn test_charge_apply_rejects_non_integer_consumption() {
+        let c = Charge::with_default_obis();
+        let result = c
+            .apply(
+                &DataObject::OctetString(vec![1, 2, 3]),
+                date_time(2026, 8, 29),
+            )
+            .await;
+        assert!(result.is_err());
+    }
 }
@@ -33,12 +33,13 @@
 use async_trait::async_trait;
 use dlms_application::pdu::SelectiveAccessDescriptor;
 use dlms_core::{
-    datatypes::{CosemDate, CosemDateTime, CosemDateFormat},
+    datatypes::{ClockStatus, CosemDate, CosemDateTime, CosemDateFormat, Field},
     DlmsError, DlmsResult, ObisCode, DataObject,
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::method::DataObjectType;
 use crate::CosemObject;
 
 /// Clock interface class (Class ID: 8)
@@ -228,6 +229,73 @@ impl Clock {
         Ok(())
     }
 
+    /// Evaluate the DST attributes against `now` and store the result
+    ///
+    /// When `daylight_savings_enabled` is set and both begin and end dates
+    /// are configured, a `now` falling inside the DST period has the
+    /// deviation added to its wall clock (the instant is unchanged, so the
+    /// timestamp's own deviation shrinks by the same amount) and the
+    /// DaylightSavingActive status flag set. Outside the period, or with
+    /// DST disabled or unconfigured, `now` is stored as-is and the flag is
+    /// cleared. A begin date after the end date wraps the period over the
+    /// year boundary (southern hemisphere).
+    ///
+    /// # Returns
+    /// Whether the deviation was applied
+    pub async fn apply_dst(&self, now: CosemDateTime) -> DlmsResult<bool> {
+        let enabled = *self.daylight_savings_enabled.read().await;
+        let begin = self.daylight_savings_begin.read().await.clone();
+        let end = self.daylight_savings_end.read().await.clone();
+        let deviation = *self.daylight_savings_deviation.read().await;
+
+        let (begin, end) = match (enabled, begin, end) {
+            (true, Some(begin), Some(end)) => (begin, end),
+            _ => {
+                self.set_time(now).await;
+                *self.status.write().await &= !(ClockStatus::DaylightSavingActive as u8);
+                return Ok(false);
+            }
+        };
+
+        // Compare by (month, day); the begin/end dates normally carry a
+        // wildcard year since they recur annually
+        let month_day = |date: &CosemDate| -> DlmsResult<(u32, u32)> {
+            let month = date.get(Field::Month)?;
+            let day = date.get(Field::DayOfMonth)?;
+            if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+                return Err(DlmsError::InvalidData(
+                    "DST evaluation requires concrete month and day".to_string(),
+                ));
+            }
+            Ok((month, day))
+        };
+        let now_key = month_day(now.date())?;
+        let begin_key = month_day(&begin)?;
+        let end_key = month_day(&end)?;
+
+        // In the period from begin (inclusive) to end (exclusive)
+        let active = if begin_key <= end_key {
+            now_key >= begin_key && now_key < end_key
+        } else {
+            now_key >= begin_key || now_key < end_key
+        };
+
+        if active {
+            let shifted = now.with_deviation(now.deviation() - deviation).ok_or_else(|| {
+                DlmsError::InvalidData(
+                    "Cannot shift a timestamp with wildcards or unspecified deviation".to_string(),
+                )
+            })?;
+            self.set_time(shifted).await;
+            *self.status.write().await |= ClockStatus::DaylightSavingActive as u8;
+        } else {
+            self.set_time(now).await;
+            *self.status.write().await &= !(ClockStatus::DaylightSavingActive as u8);
+        }
+
+        Ok(active)
+    }
+
     /// Encode the time as DataObject
     async fn encode_time(&self) -> DataObject {
         let time = self.time.read().await;
@@ -418,6 +486,20 @@ impl CosemObject for Clock {
         }
     }
 
+    fn attribute_type(&self, attribute_id: u8) -> Option<DataObjectType> {
+        match attribute_id {
+            Self::ATTR_TIME => Some(DataObjectType::OctetString),
+            Self::ATTR_TIME_ZONE => Some(DataObjectType::LongInteger),
+            Self::ATTR_STATUS => Some(DataObjectType::Unsigned),
+            // dst_begin/dst_end accept an octet string or Null, which a
+            // single declared type cannot express
+            Self::ATTR_DAYLIGHT_SAVINGS_DEVIATION => Some(DataObjectType::LongInteger),
+            Self::ATTR_DAYLIGHT_SAVINGS_ENABLED => Some(DataObjectType::Boolean),
+            Self::ATTR_CLOCK_BASE => Some(DataObjectType::Unsigned),
+            _ => None,
+        }
+    }
+
     async fn invoke_method(
         &self,
         method_id: u8,
@@ -598,4 +680,79 @@ mod tests {
         clock.set_attribute(9, DataObject::Unsigned8(1), None, None).await.unwrap();
         assert_eq!(clock.clock_base().await, 1);
     }
+
+    /// Clock with DST configured: +60 minutes from Mar 29 to Oct 25
+    async fn dst_clock() -> Clock {
+        let clock = Clock::with_default_obis();
+        clock
+            .set_daylight_savings_begin(Some(CosemDate::new(2026, 3, 29).unwrap()))
+            .await;
+        clock
+            .set_daylight_savings_end(Some(CosemDate::new(2026, 10, 25).unwrap()))
+            .await;
+        clock.set_daylight_savings_deviation(60).await;
+        clock.set_daylight_savings_enabled(true).await;
+        clock
+    }
+
+    #[tokio::test]
+    async fn test_clock_apply_dst_inside_period() {
+        let clock = dst_clock().await;
+        let now = CosemDateTime::new(2026, 7, 1, 12, 0, 0, 0, &[]).unwrap();
+
+        assert!(clock.apply_dst(now).await.unwrap());
+
+        // Wall clock springs forward one hour; the deviation shrinks by
+        // the same amount so the instant is unchanged
+        let time = clock.time().await;
+        assert_eq!(time.time().get(Field::Hour).unwrap(), 13);
+        assert_eq!(time.deviation(), -60);
+        assert_ne!(clock.status().await & (ClockStatus::DaylightSavingActive as u8), 0);
+    }
+
+    #[tokio::test]
+    async fn test_clock_apply_dst_outside_period() {
+        let clock = dst_clock().await;
+        // Make sure an earlier DST application gets cleared again
+        let summer = CosemDateTime::new(2026, 7, 1, 12, 0, 0, 0, &[]).unwrap();
+        clock.apply_dst(summer).await.unwrap();
+
+        let winter = CosemDateTime::new(2026, 1, 15, 12, 0, 0, 0, &[]).unwrap();
+        assert!(!clock.apply_dst(winter.clone()).await.unwrap());
+
+        let time = clock.time().await;
+        assert_eq!(time.time().get(Field::Hour).unwrap(), 12);
+        assert_eq!(time.deviation(), 0);
+        assert_eq!(clock.status().await & (ClockStatus::DaylightSavingActive as u8), 0);
+    }
+
+    #[tokio::test]
+    async fn test_clock_apply_dst_disabled_stores_time_unchanged() {
+        let clock = dst_clock().await;
+        clock.set_daylight_savings_enabled(false).await;
+
+        let now = CosemDateTime::new(2026, 7, 1, 12, 0, 0, 0, &[]).unwrap();
+        assert!(!clock.apply_dst(now).await.unwrap());
+        assert_eq!(clock.time().await.time().get(Field::Hour).unwrap(), 12);
+    }
+
+    #[tokio::test]
+    async fn test_clock_attribute_types() {
+        let clock = Clock::with_default_obis();
+
+        assert_eq!(clock.attribute_type(2), Some(DataObjectType::OctetString));
+        assert_eq!(clock.attribute_type(3), Some(DataObjectType::LongInteger));
+        assert_eq!(clock.attribute_type(8), Some(DataObjectType::Boolean));
+        // dst_begin may be an octet string or Null, so no declared type
+        assert_eq!(clock.attribute_type(5), None);
+
+        assert!(clock
+            .attribute_type(3)
+            .unwrap()
+            .matches(&DataObject::Integer16(60)));
+        assert!(!clock
+            .attribute_type(3)
+            .unwrap()
+            .matches(&DataObject::Unsigned16(60)));
+    }
 }
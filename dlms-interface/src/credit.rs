@@ -122,6 +122,13 @@ pub struct Credit {
 
     /// Credit threshold (warning level)
     credit_threshold: Arc<RwLock<i64>>,
+
+    /// Upper limit the available credit is clamped to
+    credit_limit: Arc<RwLock<i64>>,
+
+    /// Set when the available credit drops below the threshold; consumed
+    /// by the meter's event logic via `take_warning`
+    warning_pending: Arc<RwLock<bool>>,
 }
 
 impl Credit {
@@ -143,6 +150,10 @@ impl Credit {
     pub const ATTR_CURRENCY: u8 = 7;
     pub const ATTR_CREDIT_THRESHOLD: u8 = 8;
 
+    /// Method IDs
+    pub const METHOD_UPDATE_AMOUNT: u8 = 1;
+    pub const METHOD_SET_AMOUNT_TO_VALUE: u8 = 2;
+
     /// Create a new Credit object
     ///
     /// # Arguments
@@ -158,6 +169,8 @@ impl Credit {
             unit_of_measure: Arc::new(RwLock::new(String::new())),
             currency: Arc::new(RwLock::new(String::new())),
             credit_threshold: Arc::new(RwLock::new(100)),
+            credit_limit: Arc::new(RwLock::new(i64::MAX)),
+            warning_pending: Arc::new(RwLock::new(false)),
         }
     }
 
@@ -173,30 +186,64 @@ impl Credit {
 
     /// Set the credit available
     pub async fn set_credit_available(&self, credit: i64) {
-        *self.credit_available.write().await = credit;
-        self.update_status().await;
+        self.write_balance(credit).await;
     }
 
     /// Add credit
     pub async fn add_credit(&self, amount: i64) {
-        let mut credit = self.credit_available.write().await;
-        *credit += amount;
-        drop(credit);
-        self.update_status().await;
+        let current = *self.credit_available.read().await;
+        self.write_balance(current.saturating_add(amount)).await;
     }
 
     /// Consume/deduct credit
     pub async fn consume_credit(&self, amount: i64) -> DlmsResult<()> {
-        let mut credit = self.credit_available.write().await;
-        if *credit < amount {
+        let current = *self.credit_available.read().await;
+        if current < amount {
             return Err(DlmsError::InvalidData("Insufficient credit".to_string()));
         }
-        *credit -= amount;
-        drop(credit);
-        self.update_status().await;
+        self.write_balance(current - amount).await;
         Ok(())
     }
 
+    /// Add or subtract credit (method 1, `update_amount`)
+    ///
+    /// A positive amount is credit-in, a negative amount is credit-out.
+    /// The resulting balance is clamped at `credit_limit`. Returns the
+    /// new balance.
+    pub async fn update_amount(&self, amount: i64) -> i64 {
+        let current = *self.credit_available.read().await;
+        self.write_balance(current.saturating_add(amount)).await
+    }
+
+    /// Replace the balance outright (method 2, `set_amount_to_value`)
+    ///
+    /// The value is clamped at `credit_limit`. Returns the new balance.
+    pub async fn set_amount_to_value(&self, value: i64) -> i64 {
+        self.write_balance(value).await
+    }
+
+    /// Write a new balance, clamping at the limit, updating the status
+    /// and raising the warning flag when the threshold is crossed downward
+    async fn write_balance(&self, new_balance: i64) -> i64 {
+        let limit = *self.credit_limit.read().await;
+        let new_balance = new_balance.min(limit);
+        let threshold = *self.credit_threshold.read().await;
+
+        let old_balance = {
+            let mut balance = self.credit_available.write().await;
+            let old = *balance;
+            *balance = new_balance;
+            old
+        };
+
+        if old_balance >= threshold && new_balance < threshold {
+            *self.warning_pending.write().await = true;
+        }
+
+        self.update_status().await;
+        new_balance
+    }
+
     /// Update credit status based on available credit
     async fn update_status(&self) {
         let available = *self.credit_available.read().await;
@@ -267,6 +314,30 @@ impl Credit {
         *self.credit_threshold.write().await = threshold;
     }
 
+    /// Get the credit limit
+    pub async fn credit_limit(&self) -> i64 {
+        *self.credit_limit.read().await
+    }
+
+    /// Set the credit limit
+    pub async fn set_credit_limit(&self, limit: i64) {
+        *self.credit_limit.write().await = limit;
+    }
+
+    /// Check whether a threshold warning is pending
+    pub async fn warning_pending(&self) -> bool {
+        *self.warning_pending.read().await
+    }
+
+    /// Consume a pending threshold warning
+    ///
+    /// Returns `true` if a warning was pending and clears it, so each
+    /// threshold crossing produces exactly one event.
+    pub async fn take_warning(&self) -> bool {
+        let mut pending = self.warning_pending.write().await;
+        std::mem::take(&mut *pending)
+    }
+
     /// Check if credit is available
     pub async fn is_available(&self) -> bool {
         self.credit_status().await.is_available()
@@ -425,15 +496,39 @@ impl CosemObject for Credit {
     async fn invoke_method(
         &self,
         method_id: u8,
-        _parameters: Option<DataObject>,
+        parameters: Option<DataObject>,
         _selective_access: Option<&SelectiveAccessDescriptor>,
         ctx: Option<&crate::association_access::CosemInvocationContext>,
     ) -> DlmsResult<Option<DataObject>> {
         crate::enforce_method_execute(ctx, self.class_id(), self.obis_code(), method_id).await?;
-        Err(DlmsError::InvalidData(format!(
-            "Credit has no method {}",
-            method_id
-        )))
+        match method_id {
+            Self::METHOD_UPDATE_AMOUNT => {
+                match parameters {
+                    Some(DataObject::Integer64(amount)) => {
+                        let balance = self.update_amount(amount).await;
+                        Ok(Some(DataObject::Integer64(balance)))
+                    }
+                    _ => Err(DlmsError::InvalidData(
+                        "Expected Integer64 parameter for update_amount".to_string(),
+                    )),
+                }
+            }
+            Self::METHOD_SET_AMOUNT_TO_VALUE => {
+                match parameters {
+                    Some(DataObject::Integer64(value)) => {
+                        let balance = self.set_amount_to_value(value).await;
+                        Ok(Some(DataObject::Integer64(balance)))
+                    }
+                    _ => Err(DlmsError::InvalidData(
+                        "Expected Integer64 parameter for set_amount_to_value".to_string(),
+                    )),
+                }
+            }
+            _ => Err(DlmsError::InvalidData(format!(
+                "Credit has no method {}",
+                method_id
+            ))),
+        }
     }
 }
 
@@ -627,7 +722,76 @@ mod tests {
     #[tokio::test]
     async fn test_credit_invalid_method() {
         let c = Credit::with_default_obis();
-        let result = c.invoke_method(1, None, None, None).await;
+        let result = c.invoke_method(99, None, None, None).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_credit_update_amount_crosses_warning_threshold() {
+        let c = Credit::with_default_obis();
+        c.set_credit_threshold(100).await;
+        c.set_credit_available(200).await;
+        assert!(!c.warning_pending().await);
+
+        let balance = c.update_amount(-150).await;
+        assert_eq!(balance, 50);
+        assert_eq!(c.credit_status().await, CreditStatusType::Low);
+        assert!(c.take_warning().await);
+        // Each crossing produces exactly one warning
+        assert!(!c.take_warning().await);
+
+        // Topping up and dropping below again raises a new warning
+        c.update_amount(100).await;
+        c.update_amount(-100).await;
+        assert!(c.take_warning().await);
+    }
+
+    #[tokio::test]
+    async fn test_credit_update_amount_exhaustion() {
+        let c = Credit::with_default_obis();
+        c.set_credit_available(50).await;
+
+        let balance = c.update_amount(-80).await;
+        assert_eq!(balance, -30);
+        assert_eq!(c.credit_status().await, CreditStatusType::Exhausted);
+        assert!(!c.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_credit_update_amount_clamps_at_limit() {
+        let c = Credit::with_default_obis();
+        c.set_credit_limit(500).await;
+        c.set_credit_available(400).await;
+
+        let balance = c.update_amount(1000).await;
+        assert_eq!(balance, 500);
+        assert_eq!(c.set_amount_to_value(600).await, 500);
+    }
+
+    #[tokio::test]
+    async fn test_credit_methods_via_invoke() {
+        let c = Credit::with_default_obis();
+
+        let result = c
+            .invoke_method(Credit::METHOD_UPDATE_AMOUNT, Some(DataObject::Integer64(150)), None, None)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DataObject::Integer64(150)));
+
+        let result = c
+            .invoke_method(
+                Credit::METHOD_SET_AMOUNT_TO_VALUE,
+                Some(DataObject::Integer64(75)),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result, Some(DataObject::Integer64(75)));
+        assert_eq!(c.credit_available().await, 75);
+
+        // Missing or mistyped parameters are rejected
+        let result = c.invoke_method(Credit::METHOD_UPDATE_AMOUNT, None, None, None).await;
         assert!(result.is_err());
     }
 }
@@ -29,6 +29,7 @@
 
 use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
 use dlms_application::pdu::SelectiveAccessDescriptor;
+use crate::method::DataObjectType;
 use crate::CosemObject;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -144,6 +145,14 @@ impl CosemObject for Data {
         }
     }
 
+    fn attribute_type(&self, attribute_id: u8) -> Option<DataObjectType> {
+        match attribute_id {
+            // Attribute 2: value is a CHOICE of any data type
+            2 => Some(DataObjectType::Any),
+            _ => None,
+        }
+    }
+
     async fn invoke_method(
         &self,
         method_id: u8,
@@ -3,7 +3,7 @@
 //! This module provides types for describing COSEM objects, attributes, and methods
 //! used in Association LN/SN interface classes.
 
-use dlms_core::{DlmsError, DlmsResult, ObisCode};
+use dlms_core::{DataObject, DlmsError, DlmsResult, ObisCode};
 use dlms_asn1::ber::decoder::BerDecoder;
 use dlms_asn1::ber::encoder::BerEncoder;
 use std::fmt;
@@ -167,6 +167,140 @@ impl AccessMode {
     }
 }
 
+/// Per-attribute access right entry
+///
+/// One element of the `attribute_access_descriptor` in an association
+/// object_list entry. The optional access selectors list which selective
+/// access selectors the attribute supports (e.g. 1 and 2 for a Profile
+/// Generic buffer).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeAccessItem {
+    /// Attribute ID
+    pub attribute_id: i8,
+    /// Access mode for the attribute
+    pub access_mode: AccessMode,
+    /// Supported access selectors (None encodes as null-data)
+    pub access_selectors: Option<Vec<u8>>,
+}
+
+impl AttributeAccessItem {
+    /// Create a new attribute access item without access selectors
+    pub fn new(attribute_id: i8, access_mode: AccessMode) -> Self {
+        Self {
+            attribute_id,
+            access_mode,
+            access_selectors: None,
+        }
+    }
+
+    /// Set the supported access selectors
+    pub fn with_access_selectors(mut self, selectors: Vec<u8>) -> Self {
+        self.access_selectors = Some(selectors);
+        self
+    }
+}
+
+/// Per-method access right entry
+///
+/// One element of the `method_access_descriptor` in an association
+/// object_list entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodAccessItem {
+    /// Method ID
+    pub method_id: i8,
+    /// Access mode for the method
+    pub access_mode: AccessMode,
+}
+
+impl MethodAccessItem {
+    /// Create a new method access item
+    pub fn new(method_id: i8, access_mode: AccessMode) -> Self {
+        Self {
+            method_id,
+            access_mode,
+        }
+    }
+}
+
+/// Access rights of one object_list entry
+///
+/// Combines the `attribute_access_descriptor` and
+/// `method_access_descriptor` of an association object_list entry
+/// (Blue Book `access_right`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AccessRight {
+    /// Access rights per attribute
+    pub attribute_access: Vec<AttributeAccessItem>,
+    /// Access rights per method
+    pub method_access: Vec<MethodAccessItem>,
+}
+
+impl AccessRight {
+    /// Create an empty access right
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an attribute access item
+    pub fn with_attribute(mut self, item: AttributeAccessItem) -> Self {
+        self.attribute_access.push(item);
+        self
+    }
+
+    /// Add a method access item
+    pub fn with_method(mut self, item: MethodAccessItem) -> Self {
+        self.method_access.push(item);
+        self
+    }
+
+    /// Encode as the Blue Book `access_right` structure
+    ///
+    /// ```text
+    /// access_right ::= structure {
+    ///     attribute_access_descriptor: array of attribute_access_item,
+    ///     method_access_descriptor:    array of method_access_item
+    /// }
+    /// attribute_access_item ::= structure {
+    ///     attribute_id:     integer,
+    ///     access_mode:      enum,
+    ///     access_selectors: array of unsigned / null-data
+    /// }
+    /// method_access_item ::= structure {
+    ///     method_id:   integer,
+    ///     access_mode: enum
+    /// }
+    /// ```
+    pub fn encode(&self) -> DlmsResult<DataObject> {
+        let mut attribute_items = Vec::with_capacity(self.attribute_access.len());
+        for item in &self.attribute_access {
+            let selectors = match &item.access_selectors {
+                Some(selectors) => DataObject::Array(
+                    selectors.iter().map(|s| DataObject::Unsigned8(*s)).collect(),
+                ),
+                None => DataObject::Null,
+            };
+            attribute_items.push(DataObject::Structure(vec![
+                DataObject::Integer8(item.attribute_id),
+                DataObject::Enumerate(item.access_mode.value()),
+                selectors,
+            ]));
+        }
+
+        let mut method_items = Vec::with_capacity(self.method_access.len());
+        for item in &self.method_access {
+            method_items.push(DataObject::Structure(vec![
+                DataObject::Integer8(item.method_id),
+                DataObject::Enumerate(item.access_mode.value()),
+            ]));
+        }
+
+        Ok(DataObject::Structure(vec![
+            DataObject::Array(attribute_items),
+            DataObject::Array(method_items),
+        ]))
+    }
+}
+
 /// Attribute descriptor
 ///
 /// Describes a single attribute of a COSEM object.
@@ -500,6 +634,45 @@ mod tests {
         assert!(mode.requires_auth());
     }
 
+    #[test]
+    fn test_access_right_encode_matches_object_list_entry() {
+        // Register-like entry: logical_name read-only, value read/write with
+        // selectors 1 and 2, reset method requiring authentication
+        let access_right = AccessRight::new()
+            .with_attribute(AttributeAccessItem::new(1, AccessMode::ReadOnly))
+            .with_attribute(
+                AttributeAccessItem::new(2, AccessMode::ReadWrite)
+                    .with_access_selectors(vec![1, 2]),
+            )
+            .with_method(MethodAccessItem::new(1, AccessMode::AuthReadWrite));
+
+        let encoded = access_right.encode().unwrap();
+
+        // Known-good structure as it appears in an association object_list
+        let expected = DataObject::Structure(vec![
+            DataObject::Array(vec![
+                DataObject::Structure(vec![
+                    DataObject::Integer8(1),
+                    DataObject::Enumerate(1),
+                    DataObject::Null,
+                ]),
+                DataObject::Structure(vec![
+                    DataObject::Integer8(2),
+                    DataObject::Enumerate(3),
+                    DataObject::Array(vec![
+                        DataObject::Unsigned8(1),
+                        DataObject::Unsigned8(2),
+                    ]),
+                ]),
+            ]),
+            DataObject::Array(vec![DataObject::Structure(vec![
+                DataObject::Integer8(1),
+                DataObject::Enumerate(6),
+            ])]),
+        ]);
+        assert_eq!(encoded, expected);
+    }
+
     #[test]
     fn test_attribute_descriptor_encode() {
         let obis = ObisCode::new(1, 1, 1, 8, 0, 255);
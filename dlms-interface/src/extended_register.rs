@@ -149,6 +149,15 @@ impl ExtendedRegister {
             self.set_status(Some(status)).await;
         }
     }
+
+    /// Capture a new value at the given time
+    ///
+    /// Records the value and timestamp; the current status is kept and
+    /// served alongside via attributes 4 and 5.
+    pub async fn capture(&self, value: i64, now: CosemDateTime) {
+        *self.value.write().await = value;
+        *self.capture_time.write().await = Some(now);
+    }
 }
 
 #[async_trait]
@@ -405,6 +414,42 @@ mod tests {
         assert!(reg.capture_time().await.is_some());
     }
 
+    #[tokio::test]
+    async fn test_extended_register_capture_updates_capture_time() {
+        let reg = ExtendedRegister::with_default_obis(0);
+        assert!(reg.capture_time().await.is_none());
+
+        let ts1 = CosemDateTime::new(2024, 6, 15, 12, 0, 0, 0, &[]).unwrap();
+        reg.capture(100, ts1.clone()).await;
+        assert_eq!(reg.value().await, 100);
+        assert_eq!(reg.capture_time().await, Some(ts1.clone()));
+
+        let ts2 = CosemDateTime::new(2024, 6, 15, 12, 15, 0, 0, &[]).unwrap();
+        reg.capture(200, ts2.clone()).await;
+        assert_eq!(reg.capture_time().await, Some(ts2.clone()));
+
+        // Attribute 5 serves the capture timestamp
+        let result = reg.get_attribute(5, None, None).await.unwrap();
+        assert_eq!(result, DataObject::OctetString(ts2.encode()));
+    }
+
+    #[tokio::test]
+    async fn test_extended_register_capture_keeps_status() {
+        let reg = ExtendedRegister::new(
+            ExtendedRegister::default_obis(),
+            0,
+            None,
+            Some(vec![0x01]),
+        );
+
+        let ts = CosemDateTime::new(2024, 6, 15, 12, 0, 0, 0, &[]).unwrap();
+        reg.capture(42, ts).await;
+
+        // Attribute 4 still serves the status
+        let result = reg.get_attribute(4, None, None).await.unwrap();
+        assert_eq!(result, DataObject::OctetString(vec![0x01]));
+    }
+
     #[tokio::test]
     async fn test_extended_register_negative_value() {
         let reg = ExtendedRegister::with_default_obis(100);
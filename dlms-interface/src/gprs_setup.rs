@@ -70,9 +70,12 @@ pub struct GprsSetup {
     /// Maximum allowed connections
     allowed_connections: Arc<RwLock<u8>>,
 
-    /// Quality of Service settings
+    /// Requested Quality of Service settings
     quality_of_service: Arc<RwLock<QualityOfService>>,
 
+    /// Minimum acceptable Quality of Service
+    minimum_quality_of_service: Arc<RwLock<QualityOfService>>,
+
     /// Whether GPRS is enabled
     enabled: Arc<RwLock<bool>>,
 
@@ -114,6 +117,7 @@ impl GprsSetup {
             pin: Arc::new(RwLock::new(None)),
             allowed_connections: Arc::new(RwLock::new(1)),
             quality_of_service: Arc::new(RwLock::new(QualityOfService::Normal)),
+            minimum_quality_of_service: Arc::new(RwLock::new(QualityOfService::BestEffort)),
             enabled: Arc::new(RwLock::new(false)),
             connection_count: Arc::new(RwLock::new(0)),
             username: Arc::new(RwLock::new(None)),
@@ -138,6 +142,7 @@ impl GprsSetup {
             pin: Arc::new(RwLock::new(None)),
             allowed_connections: Arc::new(RwLock::new(1)),
             quality_of_service: Arc::new(RwLock::new(QualityOfService::Normal)),
+            minimum_quality_of_service: Arc::new(RwLock::new(QualityOfService::BestEffort)),
             enabled: Arc::new(RwLock::new(false)),
             connection_count: Arc::new(RwLock::new(0)),
             username: Arc::new(RwLock::new(None)),
@@ -190,6 +195,41 @@ impl GprsSetup {
         *self.quality_of_service.write().await = qos;
     }
 
+    /// Get the minimum acceptable quality of service
+    pub async fn minimum_quality_of_service(&self) -> QualityOfService {
+        *self.minimum_quality_of_service.read().await
+    }
+
+    /// Set the minimum acceptable quality of service
+    pub async fn set_minimum_quality_of_service(&self, qos: QualityOfService) {
+        *self.minimum_quality_of_service.write().await = qos;
+    }
+
+    /// Negotiate quality of service against what the network offers
+    ///
+    /// Picks the offered QoS capped at the requested level. Fails if the
+    /// offer is below the configured minimum.
+    pub async fn negotiate(
+        &self,
+        network_offered: &QualityOfService,
+    ) -> DlmsResult<QualityOfService> {
+        let requested = self.quality_of_service().await;
+        let minimum = self.minimum_quality_of_service().await;
+
+        if network_offered.to_u8() < minimum.to_u8() {
+            return Err(DlmsError::InvalidData(format!(
+                "Network offered QoS {:?} is below minimum {:?}",
+                network_offered, minimum
+            )));
+        }
+
+        if network_offered.to_u8() < requested.to_u8() {
+            Ok(*network_offered)
+        } else {
+            Ok(requested)
+        }
+    }
+
     /// Get the enabled status
     pub async fn enabled(&self) -> bool {
         *self.enabled.read().await
@@ -726,4 +766,32 @@ mod tests {
         gprs.set_allowed_connections(10).await;
         assert_eq!(gprs.max_connections().await, 10);
     }
+
+    #[tokio::test]
+    async fn test_gprs_setup_negotiate_acceptable_offer() {
+        let gprs = GprsSetup::with_default_obis();
+        gprs.set_quality_of_service(QualityOfService::High).await;
+        gprs.set_minimum_quality_of_service(QualityOfService::Low)
+            .await;
+
+        // Offer below requested but above minimum: take the offer
+        let result = gprs.negotiate(&QualityOfService::Normal).await.unwrap();
+        assert_eq!(result, QualityOfService::Normal);
+
+        // Offer above requested: cap at requested
+        gprs.set_quality_of_service(QualityOfService::Normal).await;
+        let result = gprs.negotiate(&QualityOfService::High).await.unwrap();
+        assert_eq!(result, QualityOfService::Normal);
+    }
+
+    #[tokio::test]
+    async fn test_gprs_setup_negotiate_below_minimum() {
+        let gprs = GprsSetup::with_default_obis();
+        gprs.set_quality_of_service(QualityOfService::High).await;
+        gprs.set_minimum_quality_of_service(QualityOfService::Normal)
+            .await;
+
+        let result = gprs.negotiate(&QualityOfService::Low).await;
+        assert!(result.is_err());
+    }
 }
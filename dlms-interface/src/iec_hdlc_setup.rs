@@ -20,6 +20,7 @@
 use async_trait::async_trait;
 use dlms_application::pdu::SelectiveAccessDescriptor;
 use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
+use dlms_session::hdlc::HdlcParameters;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -217,6 +218,65 @@ impl IecHdlcSetup {
         )
     }
 
+    /// Convert the configured attributes into HDLC connection parameters
+    ///
+    /// The single maximum_information_length attribute is applied to both
+    /// the transmit and receive directions.
+    pub async fn to_hdlc_parameters(&self) -> HdlcParameters {
+        let info_length = self.maximum_information_length().await.to_u16();
+        HdlcParameters {
+            max_information_field_length_tx: info_length,
+            max_information_field_length_rx: info_length,
+            window_size_tx: self.window_size_transmission().await,
+            window_size_rx: self.window_size_reception().await,
+        }
+    }
+
+    /// Create a setup object from negotiated HDLC connection parameters
+    ///
+    /// This is the reverse of [`to_hdlc_parameters`](Self::to_hdlc_parameters),
+    /// allowing a server to expose (and later reconfigure) its HDLC layer
+    /// through this COSEM object. Window sizes must be in 1-7 and the
+    /// information field length must be one of the supported values
+    /// (128/256/512/1024); the smaller of the two directions is used.
+    pub fn from_hdlc_parameters(
+        logical_name: ObisCode,
+        communication_speed: u32,
+        params: &HdlcParameters,
+    ) -> DlmsResult<Self> {
+        if params.window_size_tx < 1 || params.window_size_tx > 7 {
+            return Err(DlmsError::InvalidData(format!(
+                "Window size tx {} out of range [1, 7]",
+                params.window_size_tx
+            )));
+        }
+        if params.window_size_rx < 1 || params.window_size_rx > 7 {
+            return Err(DlmsError::InvalidData(format!(
+                "Window size rx {} out of range [1, 7]",
+                params.window_size_rx
+            )));
+        }
+
+        let info_length = params
+            .max_information_field_length_tx
+            .min(params.max_information_field_length_rx);
+        let info_length = InformationLength::from_u16(info_length).ok_or_else(|| {
+            DlmsError::InvalidData(format!(
+                "Invalid maximum information field length: {}",
+                info_length
+            ))
+        })?;
+
+        Ok(Self::new(
+            logical_name,
+            communication_speed,
+            params.window_size_tx,
+            params.window_size_rx,
+            info_length,
+            vec![300, 600, 1200, 2400, 4800, 9600, 19200, 38400, 57600, 115200],
+        ))
+    }
+
     /// Set default HDLC settings (9600 baud, window size 1, 128-byte info length)
     pub async fn set_defaults(&self) -> DlmsResult<()> {
         self.set_communication_speed(9600).await?;
@@ -564,6 +624,80 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_iec_hdlc_setup_to_hdlc_parameters() {
+        let setup = IecHdlcSetup::new(
+            IecHdlcSetup::default_obis(),
+            19200,
+            3,
+            2,
+            InformationLength::L512,
+            vec![9600, 19200],
+        );
+
+        let params = setup.to_hdlc_parameters().await;
+        assert_eq!(params.window_size_tx, 3);
+        assert_eq!(params.window_size_rx, 2);
+        assert_eq!(params.max_information_field_length_tx, 512);
+        assert_eq!(params.max_information_field_length_rx, 512);
+    }
+
+    #[tokio::test]
+    async fn test_iec_hdlc_setup_from_hdlc_parameters_round_trip() {
+        let params = HdlcParameters {
+            max_information_field_length_tx: 256,
+            max_information_field_length_rx: 256,
+            window_size_tx: 4,
+            window_size_rx: 5,
+        };
+
+        let setup =
+            IecHdlcSetup::from_hdlc_parameters(IecHdlcSetup::default_obis(), 9600, &params)
+                .unwrap();
+        assert_eq!(setup.window_size_transmission().await, 4);
+        assert_eq!(setup.window_size_reception().await, 5);
+        assert_eq!(
+            setup.maximum_information_length().await,
+            InformationLength::L256
+        );
+
+        let back = setup.to_hdlc_parameters().await;
+        assert_eq!(back.window_size_tx, params.window_size_tx);
+        assert_eq!(back.window_size_rx, params.window_size_rx);
+        assert_eq!(
+            back.max_information_field_length_tx,
+            params.max_information_field_length_tx
+        );
+    }
+
+    #[tokio::test]
+    async fn test_iec_hdlc_setup_from_hdlc_parameters_invalid_window() {
+        let params = HdlcParameters {
+            max_information_field_length_tx: 128,
+            max_information_field_length_rx: 128,
+            window_size_tx: 8,
+            window_size_rx: 1,
+        };
+
+        let result =
+            IecHdlcSetup::from_hdlc_parameters(IecHdlcSetup::default_obis(), 9600, &params);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_iec_hdlc_setup_from_hdlc_parameters_invalid_info_length() {
+        let params = HdlcParameters {
+            max_information_field_length_tx: 100,
+            max_information_field_length_rx: 128,
+            window_size_tx: 1,
+            window_size_rx: 1,
+        };
+
+        let result =
+            IecHdlcSetup::from_hdlc_parameters(IecHdlcSetup::default_obis(), 9600, &params);
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_information_length_from_u16() {
         assert_eq!(
@@ -20,6 +20,7 @@
 use async_trait::async_trait;
 use dlms_application::pdu::SelectiveAccessDescriptor;
 use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
+use dlms_transport::SerialSettings;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -279,6 +280,36 @@ impl IecLocalPortSetup {
         )
     }
 
+    /// Translate the configured attributes into serial port settings
+    ///
+    /// This lets a server apply a SET on this object to reconfigure the
+    /// optical port. `Mark`/`Space` parity are not supported by the serial
+    /// layer and fall back to no parity.
+    pub async fn to_serial_settings(&self, port_name: &str) -> SerialSettings {
+        let mut settings =
+            SerialSettings::new(port_name.to_string(), self.default_baud().await.to_u32());
+
+        settings.parity = match self.default_parity().await {
+            Parity::Odd => tokio_serial::Parity::Odd,
+            Parity::Even => tokio_serial::Parity::Even,
+            Parity::None | Parity::Mark | Parity::Space => tokio_serial::Parity::None,
+        };
+
+        settings.data_bits = match self.data_bits().await {
+            5 => tokio_serial::DataBits::Five,
+            6 => tokio_serial::DataBits::Six,
+            7 => tokio_serial::DataBits::Seven,
+            _ => tokio_serial::DataBits::Eight,
+        };
+
+        settings.stop_bits = match self.default_stop_bits().await {
+            2 => tokio_serial::StopBits::Two,
+            _ => tokio_serial::StopBits::One,
+        };
+
+        settings
+    }
+
     /// Set common configuration 8N1 (8 data bits, no parity, 1 stop bit)
     pub async fn set_8n1(&self, baud: BaudRate) {
         self.set_default_baud(baud).await;
@@ -642,4 +673,28 @@ mod tests {
         assert_eq!(PortMode::from_u8(2), PortMode::ModeC);
         assert_eq!(PortMode::from_u8(3), PortMode::ModeE); // Invalid defaults to ModeE
     }
+
+    #[tokio::test]
+    async fn test_to_serial_settings_9600_even() {
+        let setup = IecLocalPortSetup::with_default_obis();
+        setup.set_8e1(BaudRate::B9600).await;
+
+        let settings = setup.to_serial_settings("/dev/ttyUSB0").await;
+        assert_eq!(settings.port_name, "/dev/ttyUSB0");
+        assert_eq!(settings.baud_rate, 9600);
+        assert_eq!(settings.parity, tokio_serial::Parity::Even);
+        assert_eq!(settings.data_bits, tokio_serial::DataBits::Eight);
+        assert_eq!(settings.stop_bits, tokio_serial::StopBits::One);
+    }
+
+    #[tokio::test]
+    async fn test_to_serial_settings_300_none() {
+        let setup = IecLocalPortSetup::with_default_obis();
+        setup.set_8n1(BaudRate::B300).await;
+
+        let settings = setup.to_serial_settings("/dev/ttyS0").await;
+        assert_eq!(settings.baud_rate, 300);
+        assert_eq!(settings.parity, tokio_serial::Parity::None);
+        assert_eq!(settings.stop_bits, tokio_serial::StopBits::One);
+    }
 }
@@ -40,6 +40,22 @@ pub enum CommunicationMode {
 }
 
 impl CommunicationMode {
+    /// Check whether a protocol selection is valid in this mode
+    ///
+    /// Direct connections (Mode 1) carry either HDLC or M-Bus; remote
+    /// connections (Mode 2) are M-Bus only; the reserved modes accept only
+    /// the reserved protocol value.
+    pub fn supports_protocol(self, protocol: ProtocolSelect) -> bool {
+        matches!(
+            (self, protocol),
+            (Self::Mode1, ProtocolSelect::Hdlc)
+                | (Self::Mode1, ProtocolSelect::MBus)
+                | (Self::Mode2, ProtocolSelect::MBus)
+                | (Self::Mode3, ProtocolSelect::Reserved)
+                | (Self::Mode4, ProtocolSelect::Reserved)
+        )
+    }
+
     /// Create from u8
     pub fn from_u8(value: u8) -> Option<Self> {
         match value {
@@ -106,6 +122,12 @@ pub struct IecTwistedPairSetup {
 
     /// Supported communication speeds
     supported_communication_speeds: Arc<RwLock<Vec<u32>>>,
+
+    /// Inter-octet timeout in milliseconds
+    inter_octet_timeout: Arc<RwLock<u16>>,
+
+    /// Reaction time in milliseconds
+    reaction_time: Arc<RwLock<u16>>,
 }
 
 impl IecTwistedPairSetup {
@@ -123,6 +145,16 @@ impl IecTwistedPairSetup {
     pub const ATTR_MODE: u8 = 3;
     pub const ATTR_PROTOCOL_SELECT: u8 = 4;
     pub const ATTR_SUPPORTED_COMMUNICATION_SPEEDS: u8 = 5;
+    pub const ATTR_INTER_OCTET_TIMEOUT: u8 = 6;
+    pub const ATTR_REACTION_TIME: u8 = 7;
+
+    /// Inter-octet timeout bounds in milliseconds
+    pub const INTER_OCTET_TIMEOUT_MIN: u16 = 20;
+    pub const INTER_OCTET_TIMEOUT_MAX: u16 = 6000;
+
+    /// Reaction time bounds in milliseconds
+    pub const REACTION_TIME_MIN: u16 = 50;
+    pub const REACTION_TIME_MAX: u16 = 3000;
 
     /// Create a new IEC Twisted Pair Setup object
     ///
@@ -147,6 +179,8 @@ impl IecTwistedPairSetup {
             supported_communication_speeds: Arc::new(RwLock::new(
                 supported_communication_speeds,
             )),
+            inter_octet_timeout: Arc::new(RwLock::new(100)),
+            reaction_time: Arc::new(RwLock::new(200)),
         }
     }
 
@@ -186,8 +220,20 @@ impl IecTwistedPairSetup {
     }
 
     /// Set the communication mode
-    pub async fn set_mode(&self, mode: CommunicationMode) {
+    ///
+    /// # Errors
+    /// Returns error if the currently selected protocol is not valid in
+    /// the new mode (TYPE_UNMATCHED)
+    pub async fn set_mode(&self, mode: CommunicationMode) -> DlmsResult<()> {
+        let protocol = self.protocol_select().await;
+        if !mode.supports_protocol(protocol) {
+            return Err(DlmsError::InvalidData(format!(
+                "Protocol {:?} is not valid in mode {:?} (TYPE_UNMATCHED)",
+                protocol, mode
+            )));
+        }
         *self.mode.write().await = mode;
+        Ok(())
     }
 
     /// Get the protocol selection
@@ -196,8 +242,66 @@ impl IecTwistedPairSetup {
     }
 
     /// Set the protocol selection
-    pub async fn set_protocol_select(&self, protocol: ProtocolSelect) {
+    ///
+    /// # Errors
+    /// Returns error if the protocol is not valid in the current mode
+    /// (TYPE_UNMATCHED)
+    pub async fn set_protocol_select(&self, protocol: ProtocolSelect) -> DlmsResult<()> {
+        let mode = self.mode().await;
+        if !mode.supports_protocol(protocol) {
+            return Err(DlmsError::InvalidData(format!(
+                "Protocol {:?} is not valid in mode {:?} (TYPE_UNMATCHED)",
+                protocol, mode
+            )));
+        }
         *self.protocol_select.write().await = protocol;
+        Ok(())
+    }
+
+    /// Get the inter-octet timeout in milliseconds
+    pub async fn inter_octet_timeout(&self) -> u16 {
+        *self.inter_octet_timeout.read().await
+    }
+
+    /// Set the inter-octet timeout in milliseconds
+    ///
+    /// # Errors
+    /// Returns error if the timeout is outside
+    /// [`Self::INTER_OCTET_TIMEOUT_MIN`]..=[`Self::INTER_OCTET_TIMEOUT_MAX`]
+    pub async fn set_inter_octet_timeout(&self, timeout_ms: u16) -> DlmsResult<()> {
+        if !(Self::INTER_OCTET_TIMEOUT_MIN..=Self::INTER_OCTET_TIMEOUT_MAX).contains(&timeout_ms) {
+            return Err(DlmsError::InvalidData(format!(
+                "Inter-octet timeout {} ms out of range {}..={} ms",
+                timeout_ms,
+                Self::INTER_OCTET_TIMEOUT_MIN,
+                Self::INTER_OCTET_TIMEOUT_MAX
+            )));
+        }
+        *self.inter_octet_timeout.write().await = timeout_ms;
+        Ok(())
+    }
+
+    /// Get the reaction time in milliseconds
+    pub async fn reaction_time(&self) -> u16 {
+        *self.reaction_time.read().await
+    }
+
+    /// Set the reaction time in milliseconds
+    ///
+    /// # Errors
+    /// Returns error if the time is outside
+    /// [`Self::REACTION_TIME_MIN`]..=[`Self::REACTION_TIME_MAX`]
+    pub async fn set_reaction_time(&self, time_ms: u16) -> DlmsResult<()> {
+        if !(Self::REACTION_TIME_MIN..=Self::REACTION_TIME_MAX).contains(&time_ms) {
+            return Err(DlmsError::InvalidData(format!(
+                "Reaction time {} ms out of range {}..={} ms",
+                time_ms,
+                Self::REACTION_TIME_MIN,
+                Self::REACTION_TIME_MAX
+            )));
+        }
+        *self.reaction_time.write().await = time_ms;
+        Ok(())
     }
 
     /// Get the supported communication speeds
@@ -223,8 +327,8 @@ impl IecTwistedPairSetup {
     /// Set default settings (2400 baud, Mode1, M-Bus)
     pub async fn set_defaults(&self) -> DlmsResult<()> {
         self.set_communication_speed(2400).await?;
-        self.set_mode(CommunicationMode::Mode1).await;
-        self.set_protocol_select(ProtocolSelect::MBus).await;
+        self.set_mode(CommunicationMode::Mode1).await?;
+        self.set_protocol_select(ProtocolSelect::MBus).await?;
         Ok(())
     }
 }
@@ -265,6 +369,12 @@ impl CosemObject for IecTwistedPairSetup {
                     speeds.into_iter().map(DataObject::Unsigned32).collect();
                 Ok(DataObject::Array(speed_objs))
             }
+            Self::ATTR_INTER_OCTET_TIMEOUT => {
+                Ok(DataObject::Unsigned16(self.inter_octet_timeout().await))
+            }
+            Self::ATTR_REACTION_TIME => {
+                Ok(DataObject::Unsigned16(self.reaction_time().await))
+            }
             _ => Err(DlmsError::InvalidData(format!(
                 "IEC Twisted Pair Setup has no attribute {}",
                 attribute_id
@@ -306,8 +416,7 @@ impl CosemObject for IecTwistedPairSetup {
                     }
                 };
                 if let Some(m) = mode {
-                    self.set_mode(m).await;
-                    Ok(())
+                    self.set_mode(m).await
                 } else {
                     Err(DlmsError::InvalidData("Invalid mode value".to_string()))
                 }
@@ -323,8 +432,8 @@ impl CosemObject for IecTwistedPairSetup {
                     }
                 };
                 // from_u8 always returns Some (with Reserved as fallback), so unwrap is safe
-                self.set_protocol_select(protocol.unwrap_or(ProtocolSelect::Reserved)).await;
-                Ok(())
+                self.set_protocol_select(protocol.unwrap_or(ProtocolSelect::Reserved))
+                    .await
             }
             Self::ATTR_SUPPORTED_COMMUNICATION_SPEEDS => {
                 // Read-only attribute
@@ -332,6 +441,24 @@ impl CosemObject for IecTwistedPairSetup {
                     "Attribute 5 (supported_communication_speeds) is read-only".to_string(),
                 ))
             }
+            Self::ATTR_INTER_OCTET_TIMEOUT => {
+                if let DataObject::Unsigned16(timeout_ms) = value {
+                    self.set_inter_octet_timeout(timeout_ms).await
+                } else {
+                    Err(DlmsError::InvalidData(
+                        "Expected Unsigned16 for inter_octet_timeout".to_string(),
+                    ))
+                }
+            }
+            Self::ATTR_REACTION_TIME => {
+                if let DataObject::Unsigned16(time_ms) = value {
+                    self.set_reaction_time(time_ms).await
+                } else {
+                    Err(DlmsError::InvalidData(
+                        "Expected Unsigned16 for reaction_time".to_string(),
+                    ))
+                }
+            }
             _ => Err(DlmsError::InvalidData(format!(
                 "IEC Twisted Pair Setup has no attribute {}",
                 attribute_id
@@ -396,14 +523,14 @@ mod tests {
     #[tokio::test]
     async fn test_iec_twisted_pair_setup_set_mode() {
         let setup = IecTwistedPairSetup::with_default_obis();
-        setup.set_mode(CommunicationMode::Mode2).await;
+        setup.set_mode(CommunicationMode::Mode2).await.unwrap();
         assert_eq!(setup.mode().await, CommunicationMode::Mode2);
     }
 
     #[tokio::test]
     async fn test_iec_twisted_pair_setup_set_protocol_select() {
         let setup = IecTwistedPairSetup::with_default_obis();
-        setup.set_protocol_select(ProtocolSelect::Hdlc).await;
+        setup.set_protocol_select(ProtocolSelect::Hdlc).await.unwrap();
         assert_eq!(setup.protocol_select().await, ProtocolSelect::Hdlc);
     }
 
@@ -557,4 +684,69 @@ mod tests {
         assert_eq!(ProtocolSelect::from_u8(1), Some(ProtocolSelect::MBus));
         assert_eq!(ProtocolSelect::from_u8(2), Some(ProtocolSelect::Reserved));
     }
+
+    #[tokio::test]
+    async fn test_iec_twisted_pair_setup_valid_mode_protocol_pairing() {
+        let setup = IecTwistedPairSetup::with_default_obis();
+
+        // Mode 1 carries HDLC as well as M-Bus
+        setup
+            .set_attribute(4, DataObject::Enumerate(ProtocolSelect::Hdlc.to_u8()), None, None)
+            .await
+            .unwrap();
+        assert_eq!(setup.protocol_select().await, ProtocolSelect::Hdlc);
+    }
+
+    #[tokio::test]
+    async fn test_iec_twisted_pair_setup_invalid_mode_protocol_pairing() {
+        let setup = IecTwistedPairSetup::with_default_obis();
+        setup.set_mode(CommunicationMode::Mode2).await.unwrap();
+
+        // HDLC is not valid on a remote (Mode 2) connection
+        let result = setup
+            .set_attribute(4, DataObject::Enumerate(ProtocolSelect::Hdlc.to_u8()), None, None)
+            .await;
+        match result {
+            Err(DlmsError::InvalidData(message)) => {
+                assert!(message.contains("TYPE_UNMATCHED"));
+            }
+            other => panic!("Expected InvalidData error, got {:?}", other),
+        }
+        assert_eq!(setup.protocol_select().await, ProtocolSelect::MBus);
+
+        // Switching to a mode the current protocol does not support is
+        // rejected the same way
+        setup.set_protocol_select(ProtocolSelect::MBus).await.unwrap();
+        assert!(setup.set_mode(CommunicationMode::Mode3).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_iec_twisted_pair_setup_timing_attributes() {
+        let setup = IecTwistedPairSetup::with_default_obis();
+
+        setup
+            .set_attribute(6, DataObject::Unsigned16(250), None, None)
+            .await
+            .unwrap();
+        assert_eq!(setup.inter_octet_timeout().await, 250);
+        assert_eq!(
+            setup.get_attribute(6, None, None).await.unwrap(),
+            DataObject::Unsigned16(250)
+        );
+
+        setup.set_reaction_time(500).await.unwrap();
+        assert_eq!(
+            setup.get_attribute(7, None, None).await.unwrap(),
+            DataObject::Unsigned16(500)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_iec_twisted_pair_setup_timing_bounds() {
+        let setup = IecTwistedPairSetup::with_default_obis();
+        assert!(setup.set_inter_octet_timeout(10).await.is_err());
+        assert!(setup.set_inter_octet_timeout(7000).await.is_err());
+        assert!(setup.set_reaction_time(10).await.is_err());
+        assert!(setup.set_reaction_time(5000).await.is_err());
+    }
 }
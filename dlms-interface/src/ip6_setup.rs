@@ -21,6 +21,7 @@ use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::mac_address_setup::MacAddr;
 use crate::CosemObject;
 
 /// IP Address Assignment Method
@@ -110,6 +111,36 @@ impl Ipv6Addr {
     pub fn is_loopback(&self) -> bool {
         self.octets[..15] == [0u8; 15] && self.octets[15] == 1
     }
+
+    /// Check if address is multicast (ff00::/8)
+    pub fn is_multicast(&self) -> bool {
+        self.octets[0] == 0xFF
+    }
+
+    /// Check if address is link-local (fe80::/10)
+    pub fn is_link_local(&self) -> bool {
+        self.octets[0] == 0xFE && self.octets[1] & 0xC0 == 0x80
+    }
+
+    /// Derive a link-local address from a MAC address using modified EUI-64
+    ///
+    /// The interface identifier is built by splitting the MAC in the
+    /// middle, inserting 0xFFFE, and flipping the universal/local bit of
+    /// the first octet (RFC 4291, appendix A).
+    pub fn link_local_from_mac(mac: &MacAddr) -> Self {
+        let mut octets = [0u8; 16];
+        octets[0] = 0xFE;
+        octets[1] = 0x80;
+        octets[8] = mac.octets[0] ^ 0x02;
+        octets[9] = mac.octets[1];
+        octets[10] = mac.octets[2];
+        octets[11] = 0xFF;
+        octets[12] = 0xFE;
+        octets[13] = mac.octets[3];
+        octets[14] = mac.octets[4];
+        octets[15] = mac.octets[5];
+        Self { octets }
+    }
 }
 
 impl Default for Ipv6Addr {
@@ -298,6 +329,30 @@ impl Ip6Setup {
         self.address_method().await.is_automatic()
     }
 
+    /// Validate the configured addresses
+    ///
+    /// The unicast address and gateway fields must not hold a multicast
+    /// address (ff00::/8); those belong in attribute 9.
+    pub async fn validate(&self) -> DlmsResult<()> {
+        let ip = self.ip_address().await;
+        if ip.is_multicast() {
+            return Err(DlmsError::InvalidData(format!(
+                "Multicast address {} not allowed in unicast ip_address field",
+                ip
+            )));
+        }
+
+        let gateway = self.gateway_address().await;
+        if gateway.is_multicast() {
+            return Err(DlmsError::InvalidData(format!(
+                "Multicast address {} not allowed in gateway_address field",
+                gateway
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Check if has a valid IP configured
     pub async fn has_valid_ip(&self) -> bool {
         !self.ip_address().await.is_unspecified()
@@ -744,4 +799,43 @@ mod tests {
         let result = setup.set_attribute(2, DataObject::Boolean(true), None, None).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_ipv6_addr_link_local_from_mac() {
+        // Example from RFC 4291 appendix A: 34-56-78-9A-BC-DE
+        let mac = MacAddr::new([0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE]);
+        let addr = Ipv6Addr::link_local_from_mac(&mac);
+
+        assert!(addr.is_link_local());
+        assert_eq!(
+            addr.octets,
+            [
+                0xFE, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+                0x36, 0x56, 0x78, 0xFF, 0xFE, 0x9A, 0xBC, 0xDE,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ipv6_addr_is_multicast() {
+        let mut octets = [0u8; 16];
+        octets[0] = 0xFF;
+        octets[1] = 0x02;
+        assert!(Ipv6Addr::new(octets).is_multicast());
+        assert!(!Ipv6Addr::localhost().is_multicast());
+    }
+
+    #[tokio::test]
+    async fn test_ip6_setup_validate_rejects_multicast_unicast() {
+        let setup = Ip6Setup::with_default_obis();
+        assert!(setup.validate().await.is_ok());
+
+        let mut octets = [0u8; 16];
+        octets[0] = 0xFF;
+        octets[1] = 0x02;
+        octets[15] = 0x01;
+        setup.set_ip_address(Ipv6Addr::new(octets)).await;
+
+        assert!(setup.validate().await.is_err());
+    }
 }
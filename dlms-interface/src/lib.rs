@@ -426,6 +426,9 @@ pub mod gprs_setup;
 pub mod value_display;
 pub mod key_table;
 pub mod sensor;
+pub mod obis_class;
+pub mod factory;
+pub mod registry;
 
 pub use data::Data;
 pub use scaler_unit::{ScalerUnit, units};
@@ -462,6 +465,7 @@ pub use image_transfer::{
 };
 pub use descriptor::{
     CosemObjectDescriptor, AccessMode,
+    AccessRight, AttributeAccessItem, MethodAccessItem,
     AttributeDescriptor, MethodDescriptor, UserInfo,
     CaptureObjectDefinition, ProfileEntry, SortMethod,
     ObisCodeExt,
@@ -515,6 +519,9 @@ pub use gprs_setup::{GprsSetup, QualityOfService};
 pub use value_display::ValueDisplay;
 pub use key_table::{KeyTable, KeyType};
 pub use sensor::{Sensor, SensorStatus};
+pub use obis_class::validate_class_for_obis;
+pub use factory::CosemObjectFactory;
+pub use registry::ObjectRegistry;
 
 // Attribute and method handling exports
 pub use attribute::{
@@ -577,6 +584,18 @@ pub trait CosemObject: Send + Sync {
         ctx: Option<&crate::association_access::CosemInvocationContext>,
     ) -> DlmsResult<()>;
 
+    /// Expected value type of an attribute, if the class declares one
+    ///
+    /// The server checks a SET value against this before calling
+    /// `set_attribute`, rejecting mismatches with TYPE_UNMATCHED so no
+    /// state is mutated. The default of `None` means "no declared type"
+    /// and skips the check; attributes whose type depends on runtime
+    /// state (e.g. a value that may be Null or Unsigned8) should also
+    /// return `None` and validate inside `set_attribute`.
+    fn attribute_type(&self, _attribute_id: u8) -> Option<DataObjectType> {
+        None
+    }
+
     /// Invoke a method
     ///
     /// # Arguments
@@ -108,6 +108,9 @@ pub struct MacAddressSetup {
 
     /// Whether MAC address is enabled
     mac_enabled: Arc<RwLock<bool>>,
+
+    /// Filter table of peer MAC addresses allowed to communicate
+    allowed_peers: Arc<RwLock<Vec<MacAddr>>>,
 }
 
 impl MacAddressSetup {
@@ -133,6 +136,7 @@ impl MacAddressSetup {
             logical_name,
             mac_address: Arc::new(RwLock::new(MacAddr::default())),
             mac_enabled: Arc::new(RwLock::new(true)),
+            allowed_peers: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -161,6 +165,40 @@ impl MacAddressSetup {
         }
     }
 
+    /// Add a peer MAC address to the filter table
+    ///
+    /// Duplicates are ignored.
+    pub async fn add_allowed(&self, mac: MacAddr) {
+        let mut peers = self.allowed_peers.write().await;
+        if !peers.contains(&mac) {
+            peers.push(mac);
+        }
+    }
+
+    /// Remove a peer MAC address from the filter table
+    pub async fn remove_allowed(&self, mac: &MacAddr) {
+        self.allowed_peers.write().await.retain(|m| m != mac);
+    }
+
+    /// Check whether a peer MAC address is allowed
+    ///
+    /// An empty filter table allows everyone; once the table has entries,
+    /// only listed peers pass.
+    pub async fn is_allowed(&self, mac: &MacAddr) -> bool {
+        let peers = self.allowed_peers.read().await;
+        peers.is_empty() || peers.contains(mac)
+    }
+
+    /// Get the filter table entries
+    pub async fn allowed_peers(&self) -> Vec<MacAddr> {
+        self.allowed_peers.read().await.clone()
+    }
+
+    /// Clear the filter table, allowing all peers again
+    pub async fn clear_allowed(&self) {
+        self.allowed_peers.write().await.clear();
+    }
+
     /// Get whether MAC address is enabled
     pub async fn mac_enabled(&self) -> bool {
         *self.mac_enabled.read().await
@@ -485,4 +523,48 @@ mod tests {
         let setup = MacAddressSetup::new(obis);
         assert_eq!(setup.obis_code(), obis);
     }
+
+    #[tokio::test]
+    async fn test_mac_address_setup_add_allowed() {
+        let setup = MacAddressSetup::with_default_obis();
+        let peer = MacAddr::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        setup.add_allowed(peer.clone()).await;
+        assert_eq!(setup.allowed_peers().await.len(), 1);
+
+        // Duplicates are ignored
+        setup.add_allowed(peer).await;
+        assert_eq!(setup.allowed_peers().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mac_address_setup_is_allowed() {
+        let setup = MacAddressSetup::with_default_obis();
+        let peer = MacAddr::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let other = MacAddr::new([0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF]);
+
+        // Empty table allows everyone
+        assert!(setup.is_allowed(&peer).await);
+
+        setup.add_allowed(peer.clone()).await;
+        assert!(setup.is_allowed(&peer).await);
+        assert!(!setup.is_allowed(&other).await);
+
+        setup.remove_allowed(&peer).await;
+        assert!(setup.allowed_peers().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mac_address_setup_clear_allowed() {
+        let setup = MacAddressSetup::with_default_obis();
+        let peer = MacAddr::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let other = MacAddr::new([0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF]);
+
+        setup.add_allowed(peer).await;
+        assert!(!setup.is_allowed(&other).await);
+
+        setup.clear_allowed().await;
+        assert!(setup.allowed_peers().await.is_empty());
+        assert!(setup.is_allowed(&other).await);
+    }
 }
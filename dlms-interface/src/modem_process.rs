@@ -125,6 +125,25 @@ impl ModemConnectionStatus {
     }
 }
 
+/// Events driving the modem state machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModemEvent {
+    /// Start dialing the configured number
+    Dial,
+    /// Carrier detected while dialing
+    CarrierDetected,
+    /// Dial attempt timed out
+    DialTimeout,
+    /// Hang up an established connection
+    Hangup,
+    /// Hangup finished, line released
+    HangupComplete,
+    /// Unrecoverable line error
+    LineError,
+    /// Reset the modem back to idle after a failure
+    Reset,
+}
+
 /// Modem Process interface class (Class ID: 30)
 ///
 /// Default OBIS: 0-0:30.0.0.255
@@ -343,6 +362,70 @@ impl ModemProcess {
         self.increment_connection_count().await;
     }
 
+    /// Advance the dial/connect/hangup state machine by one event
+    ///
+    /// Transitions:
+    /// - `Idle` + `Dial` -> `Dialing`
+    /// - `Dialing` + `CarrierDetected` -> `Connected`
+    /// - `Dialing` + `DialTimeout` -> `ConnectionFailed`
+    /// - `Connected` + `Hangup` -> still `Connected`, connection status `Disconnecting`
+    /// - then `HangupComplete` -> `Idle`
+    /// - `LineError` -> `Error` from any state
+    /// - `Reset` -> `Idle` from a failed/error state
+    ///
+    /// Events that do not apply to the current state are ignored. The
+    /// status after the transition is returned.
+    pub async fn step(&self, event: ModemEvent) -> ModemStatus {
+        let status = self.modem_status().await;
+
+        match (status, event) {
+            (ModemStatus::Idle, ModemEvent::Dial) => {
+                self.set_modem_status(ModemStatus::Dialing).await;
+                self.set_connection_status(ModemConnectionStatus::Connecting)
+                    .await;
+                self.increment_connection_count().await;
+            }
+            (ModemStatus::Dialing, ModemEvent::CarrierDetected) => {
+                self.set_modem_status(ModemStatus::Connected).await;
+                self.set_connection_status(ModemConnectionStatus::Connected)
+                    .await;
+            }
+            (ModemStatus::Dialing, ModemEvent::DialTimeout) => {
+                self.set_modem_status(ModemStatus::ConnectionFailed).await;
+                self.set_connection_status(ModemConnectionStatus::Failed)
+                    .await;
+            }
+            (ModemStatus::Dialing, ModemEvent::Hangup) => {
+                self.set_modem_status(ModemStatus::Idle).await;
+                self.set_connection_status(ModemConnectionStatus::NotConnected)
+                    .await;
+            }
+            (ModemStatus::Connected, ModemEvent::Hangup) => {
+                self.set_connection_status(ModemConnectionStatus::Disconnecting)
+                    .await;
+            }
+            (ModemStatus::Connected, ModemEvent::HangupComplete) => {
+                self.set_modem_status(ModemStatus::Idle).await;
+                self.set_connection_status(ModemConnectionStatus::NotConnected)
+                    .await;
+            }
+            (_, ModemEvent::LineError) => {
+                self.set_modem_status(ModemStatus::Error).await;
+                self.set_connection_status(ModemConnectionStatus::Failed)
+                    .await;
+            }
+            (ModemStatus::ConnectionFailed | ModemStatus::Error, ModemEvent::Reset) => {
+                self.set_modem_status(ModemStatus::Idle).await;
+                self.set_connection_status(ModemConnectionStatus::NotConnected)
+                    .await;
+            }
+            // Event does not apply to the current state
+            _ => {}
+        }
+
+        self.modem_status().await
+    }
+
     /// Disconnect
     pub async fn disconnect(&self) {
         self.set_connection_status(ModemConnectionStatus::Disconnecting).await;
@@ -840,4 +923,67 @@ mod tests {
         assert_eq!(modem.connection_status().await, ModemConnectionStatus::Connecting);
         assert!(modem.connection_status().await.is_transitioning());
     }
+
+    #[tokio::test]
+    async fn test_modem_process_step_normal_connect_sequence() {
+        let modem = ModemProcess::with_default_obis();
+        modem.enable().await;
+
+        assert_eq!(modem.step(ModemEvent::Dial).await, ModemStatus::Dialing);
+        assert_eq!(
+            modem.connection_status().await,
+            ModemConnectionStatus::Connecting
+        );
+
+        assert_eq!(
+            modem.step(ModemEvent::CarrierDetected).await,
+            ModemStatus::Connected
+        );
+        assert!(modem.is_connected().await);
+
+        assert_eq!(modem.step(ModemEvent::Hangup).await, ModemStatus::Connected);
+        assert_eq!(
+            modem.connection_status().await,
+            ModemConnectionStatus::Disconnecting
+        );
+
+        assert_eq!(
+            modem.step(ModemEvent::HangupComplete).await,
+            ModemStatus::Idle
+        );
+        assert_eq!(
+            modem.connection_status().await,
+            ModemConnectionStatus::NotConnected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_modem_process_step_dial_timeout() {
+        let modem = ModemProcess::with_default_obis();
+        modem.enable().await;
+
+        modem.step(ModemEvent::Dial).await;
+        assert_eq!(
+            modem.step(ModemEvent::DialTimeout).await,
+            ModemStatus::ConnectionFailed
+        );
+        assert_eq!(
+            modem.connection_status().await,
+            ModemConnectionStatus::Failed
+        );
+
+        // Reset recovers the modem to idle
+        assert_eq!(modem.step(ModemEvent::Reset).await, ModemStatus::Idle);
+    }
+
+    #[tokio::test]
+    async fn test_modem_process_step_ignores_inapplicable_event() {
+        let modem = ModemProcess::with_default_obis();
+        modem.enable().await;
+        // CarrierDetected while idle does nothing
+        assert_eq!(
+            modem.step(ModemEvent::CarrierDetected).await,
+            ModemStatus::Idle
+        );
+    }
 }
@@ -0,0 +1,86 @@
+//! OBIS code / class-id consistency validation
+//!
+//! The Blue Book reserves a number of well-known OBIS codes for specific
+//! interface classes (e.g. the clock object 0.0.1.0.0.255 is always class 8).
+//! Registering such an OBIS under a different class is almost certainly a
+//! configuration mistake, so this module provides a validator backed by a
+//! small table of standard OBIS→class associations.
+
+use dlms_core::{DlmsError, DlmsResult, ObisCode};
+
+/// Standard OBIS→class associations from the Blue Book
+///
+/// Only unambiguous, well-known logical names are listed; anything not in
+/// the table passes validation unchanged.
+const STANDARD_OBIS_CLASSES: &[([u8; 6], u16)] = &[
+    ([0, 0, 1, 0, 0, 255], 8),       // Clock
+    ([0, 0, 10, 0, 0, 255], 9),      // Script table (global meter reset)
+    ([0, 0, 11, 0, 0, 255], 11),     // Special days table
+    ([0, 0, 13, 0, 0, 255], 20),     // Activity calendar
+    ([0, 0, 15, 0, 0, 255], 22),     // Single action schedule (end of billing)
+    ([0, 0, 22, 0, 0, 255], 19),     // IEC local port setup
+    ([0, 0, 23, 2, 0, 255], 23),     // IEC HDLC setup
+    ([0, 0, 25, 0, 0, 255], 69),     // TCP-UDP setup
+    ([0, 0, 40, 0, 0, 255], 15),     // Current association (LN)
+    ([0, 0, 42, 0, 0, 255], 1),      // COSEM logical device name (Data)
+    ([0, 0, 43, 0, 0, 255], 64),     // Security setup
+    ([0, 0, 44, 0, 0, 255], 18),     // Image transfer
+    ([0, 0, 96, 1, 0, 255], 1),      // Device ID 1 (Data)
+    ([0, 0, 96, 3, 10, 255], 70),    // Disconnect control
+    ([1, 0, 1, 8, 0, 255], 3),       // Active energy import total (Register)
+    ([1, 0, 2, 8, 0, 255], 3),       // Active energy export total (Register)
+    ([1, 0, 99, 1, 0, 255], 7),      // Load profile 1 (Profile generic)
+];
+
+/// Validate that a class id is consistent with a well-known OBIS code
+///
+/// Checks the OBIS against the built-in table of standard associations.
+/// Returns an error when the OBIS is reserved for a different interface
+/// class; OBIS codes not in the table always pass.
+///
+/// # Arguments
+/// * `class_id` - Class id the object is being registered under
+/// * `obis` - Logical name of the object
+///
+/// # Errors
+/// Returns `DlmsError::InvalidData` naming the expected class on mismatch.
+pub fn validate_class_for_obis(class_id: u16, obis: &ObisCode) -> DlmsResult<()> {
+    for (standard_obis, standard_class) in STANDARD_OBIS_CLASSES {
+        if obis.as_bytes() == standard_obis {
+            if class_id != *standard_class {
+                return Err(DlmsError::InvalidData(format!(
+                    "OBIS {} is reserved for class {}, but was registered under class {}",
+                    obis, standard_class, class_id
+                )));
+            }
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_class_for_obis_correct_pairing() {
+        let clock_obis = ObisCode::new(0, 0, 1, 0, 0, 255);
+        assert!(validate_class_for_obis(8, &clock_obis).is_ok());
+    }
+
+    #[test]
+    fn test_validate_class_for_obis_flags_mismatch() {
+        let clock_obis = ObisCode::new(0, 0, 1, 0, 0, 255);
+        let result = validate_class_for_obis(3, &clock_obis);
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_validate_class_for_obis_unknown_obis_passes() {
+        // Manufacturer-specific OBIS: not in the table, any class is accepted
+        let obis = ObisCode::new(0, 0, 199, 1, 0, 255);
+        assert!(validate_class_for_obis(3, &obis).is_ok());
+        assert!(validate_class_for_obis(8, &obis).is_ok());
+    }
+}
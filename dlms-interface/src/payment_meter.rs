@@ -69,6 +69,36 @@ impl PaymentMethod {
     }
 }
 
+/// Operating mode of the payment meter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PaymentMode {
+    /// Post-paid: energy is consumed first and billed later
+    Credit = 0,
+    /// Prepaid: energy must be paid for in advance
+    Prepayment = 1,
+}
+
+impl PaymentMode {
+    /// Create from u8
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Prepayment,
+            _ => Self::Credit,
+        }
+    }
+
+    /// Convert to u8
+    pub fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Check if prepayment mode
+    pub fn is_prepayment(self) -> bool {
+        matches!(self, Self::Prepayment)
+    }
+}
+
 /// Payment Status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -133,6 +163,12 @@ pub struct PaymentMeter {
     /// Payment status
     payment_status: Arc<RwLock<PaymentStatus>>,
 
+    /// Operating mode (credit or prepayment)
+    payment_mode: Arc<RwLock<PaymentMode>>,
+
+    /// Account balance in the smallest currency unit (may go negative in credit mode)
+    balance: Arc<RwLock<i64>>,
+
     /// Total number of payments
     total_payments: Arc<RwLock<u32>>,
 
@@ -177,6 +213,8 @@ impl PaymentMeter {
             logical_name,
             payment_method: Arc::new(RwLock::new(PaymentMethod::Cash)),
             payment_status: Arc::new(RwLock::new(PaymentStatus::NoPaymentDue)),
+            payment_mode: Arc::new(RwLock::new(PaymentMode::Credit)),
+            balance: Arc::new(RwLock::new(0)),
             total_payments: Arc::new(RwLock::new(0)),
             total_amount_paid: Arc::new(RwLock::new(0)),
             last_payment_amount: Arc::new(RwLock::new(0)),
@@ -258,6 +296,55 @@ impl PaymentMeter {
         *self.payment_status.write().await = PaymentStatus::PaymentCompleted;
     }
 
+    /// Get the operating mode
+    pub async fn payment_mode(&self) -> PaymentMode {
+        *self.payment_mode.read().await
+    }
+
+    /// Get the account balance
+    pub async fn balance(&self) -> i64 {
+        *self.balance.read().await
+    }
+
+    /// Set the account balance
+    pub async fn set_balance(&self, balance: i64) {
+        *self.balance.write().await = balance;
+    }
+
+    /// Adjust the account balance by a (possibly negative) amount
+    pub async fn adjust_balance(&self, amount: i64) {
+        *self.balance.write().await += amount;
+    }
+
+    /// Switch the operating mode, enforcing valid transitions
+    ///
+    /// Switching to prepayment is rejected while the balance is negative:
+    /// the outstanding debt must be settled first. The payment status is
+    /// updated to reflect the new mode.
+    pub async fn set_mode(&self, mode: PaymentMode) -> DlmsResult<()> {
+        let current = self.payment_mode().await;
+        if current == mode {
+            return Ok(());
+        }
+
+        if mode.is_prepayment() && self.balance().await < 0 {
+            return Err(DlmsError::InvalidData(format!(
+                "Cannot switch to prepayment with negative balance {}",
+                self.balance().await
+            )));
+        }
+
+        *self.payment_mode.write().await = mode;
+
+        let status = if self.balance().await < 0 {
+            PaymentStatus::PaymentOverdue
+        } else {
+            PaymentStatus::NoPaymentDue
+        };
+        *self.payment_status.write().await = status;
+        Ok(())
+    }
+
     /// Check if payment is required
     pub async fn is_payment_required(&self) -> bool {
         self.payment_status().await.is_payment_required()
@@ -727,4 +814,37 @@ mod tests {
         let result = pm.get_attribute(7, None, None).await.unwrap();
         assert!(matches!(result, DataObject::Null));
     }
+
+    #[tokio::test]
+    async fn test_payment_meter_set_mode_valid_switch() {
+        let pm = PaymentMeter::with_default_obis();
+        assert_eq!(pm.payment_mode().await, PaymentMode::Credit);
+
+        pm.set_balance(500).await;
+        pm.set_mode(PaymentMode::Prepayment).await.unwrap();
+        assert_eq!(pm.payment_mode().await, PaymentMode::Prepayment);
+        assert_eq!(pm.payment_status().await, PaymentStatus::NoPaymentDue);
+
+        // Switching back to credit is always allowed
+        pm.set_mode(PaymentMode::Credit).await.unwrap();
+        assert_eq!(pm.payment_mode().await, PaymentMode::Credit);
+    }
+
+    #[tokio::test]
+    async fn test_payment_meter_set_mode_rejects_negative_balance() {
+        let pm = PaymentMeter::with_default_obis();
+        pm.set_balance(-100).await;
+
+        let result = pm.set_mode(PaymentMode::Prepayment).await;
+        assert!(result.is_err());
+        assert_eq!(pm.payment_mode().await, PaymentMode::Credit);
+    }
+
+    #[tokio::test]
+    async fn test_payment_meter_adjust_balance() {
+        let pm = PaymentMeter::with_default_obis();
+        pm.adjust_balance(200).await;
+        pm.adjust_balance(-50).await;
+        assert_eq!(pm.balance().await, 150);
+    }
 }
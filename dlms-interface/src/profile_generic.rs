@@ -11,7 +11,7 @@
 //! - Attribute 3: buffer_timestamp - Timestamp of the last buffer update
 //! - Attribute 4: capture_objects - List of objects to capture
 //! - Attribute 5: capture_period - Period in seconds between captures (0 = on demand)
-//! - Attribute 6: sort_method - Sort method for the buffer (FIFO/LIFO)
+//! - Attribute 6: sort_method - Sort method for the buffer (FIFO/LIFO/value-sorted)
 //! - Attribute 7: sort_object - Object used for sorting (optional)
 //! - Attribute 8: sort_attribute - Attribute of sort object (optional)
 //! - Attribute 9: entries_in_use - Number of entries currently in use
@@ -23,15 +23,15 @@
 //! - Method 2: capture() - Perform an immediate capture
 
 use async_trait::async_trait;
-use dlms_application::pdu::SelectiveAccessDescriptor;
+use dlms_application::pdu::{data_access_result, SelectiveAccessDescriptor};
 use dlms_core::{
-    datatypes::{CosemDateTime, CosemDateFormat},
+    datatypes::{CosemDateTime, CosemDateFormat, Field},
     DlmsError, DlmsResult, ObisCode, DataObject,
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::{CosemObject, CosemObjectDescriptor, ObisCodeExt};
+use crate::{CosemObject, CosemObjectDescriptor, ObisCodeExt, ObjectRegistry};
 
 /// Sort method for the profile buffer (specific to Profile Generic)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -40,6 +40,10 @@ pub enum ProfileSortMethod {
     Fifo = 0,
     /// Last In First Out
     Lifo = 1,
+    /// Ordered by sort column, largest value first
+    LargestFirst = 2,
+    /// Ordered by sort column, smallest value first
+    SmallestFirst = 3,
 }
 
 impl ProfileSortMethod {
@@ -47,6 +51,8 @@ impl ProfileSortMethod {
         match value {
             0 => Some(ProfileSortMethod::Fifo),
             1 => Some(ProfileSortMethod::Lifo),
+            2 => Some(ProfileSortMethod::LargestFirst),
+            3 => Some(ProfileSortMethod::SmallestFirst),
             _ => None,
         }
     }
@@ -114,6 +120,27 @@ impl GenericProfileEntry {
         DataObject::Structure(fields)
     }
 
+    /// Numeric sort key of this entry for the value-sorted methods
+    ///
+    /// The sort column is the first captured value. Non-numeric or
+    /// missing sort columns sort as 0.
+    fn sort_key(&self) -> f64 {
+        match self.values.first() {
+            Some(DataObject::Integer8(v)) => f64::from(*v),
+            Some(DataObject::Integer16(v)) => f64::from(*v),
+            Some(DataObject::Integer32(v)) => f64::from(*v),
+            Some(DataObject::Integer64(v)) => *v as f64,
+            Some(DataObject::Unsigned8(v)) => f64::from(*v),
+            Some(DataObject::Unsigned16(v)) => f64::from(*v),
+            Some(DataObject::Unsigned32(v)) => f64::from(*v),
+            Some(DataObject::Unsigned64(v)) => *v as f64,
+            Some(DataObject::Float32(v)) => f64::from(*v),
+            Some(DataObject::Float64(v)) => *v,
+            Some(DataObject::Enumerate(v)) => f64::from(*v),
+            _ => 0.0,
+        }
+    }
+
     /// Decode from a DataObject structure
     pub fn decode(data: &DataObject) -> DlmsResult<Self> {
         match data {
@@ -206,7 +233,7 @@ impl ProfileGeneric {
     /// * `logical_name` - OBIS code identifying this object
     /// * `max_buffer_size` - Maximum number of entries in the buffer
     /// * `capture_period` - Period in seconds between captures (0 = on demand)
-    /// * `sort_method` - Sort method (FIFO or LIFO)
+    /// * `sort_method` - Sort method maintained by the buffer
     pub fn new(
         logical_name: ObisCode,
         max_buffer_size: usize,
@@ -322,24 +349,40 @@ impl ProfileGeneric {
         let mut buffer = self.buffer.write().await;
         let sort_method = *self.sort_method.read().await;
 
-        // Check if buffer is full
-        if buffer.len() >= self.max_buffer_size {
-            if sort_method == ProfileSortMethod::Fifo {
-                // Remove oldest entry
-                buffer.remove(0);
-            } else {
-                // LIFO: buffer is full, can't add more without overwriting
-                // For simplicity, remove the first entry
-                buffer.remove(0);
+        // Insert the new entry at the position dictated by the sort method
+        match sort_method {
+            ProfileSortMethod::Fifo => buffer.push(entry),
+            // LIFO: add at beginning
+            ProfileSortMethod::Lifo => buffer.insert(0, entry),
+            ProfileSortMethod::LargestFirst => {
+                let key = entry.sort_key();
+                let pos = buffer
+                    .iter()
+                    .position(|e| e.sort_key() < key)
+                    .unwrap_or(buffer.len());
+                buffer.insert(pos, entry);
+            }
+            ProfileSortMethod::SmallestFirst => {
+                let key = entry.sort_key();
+                let pos = buffer
+                    .iter()
+                    .position(|e| e.sort_key() > key)
+                    .unwrap_or(buffer.len());
+                buffer.insert(pos, entry);
             }
         }
 
-        // Add new entry
-        if sort_method == ProfileSortMethod::Fifo {
-            buffer.push(entry);
-        } else {
-            // LIFO: add at beginning
-            buffer.insert(0, entry);
+        // Evict according to the sort method when over capacity:
+        // - FIFO drops the oldest entry (front)
+        // - LIFO drops the previous newest (right after the new entry)
+        // - SmallestFirst drops the smallest, keeping the largest N
+        //   by the sort column; LargestFirst is the mirror image
+        if buffer.len() > self.max_buffer_size {
+            let evict_index = match sort_method {
+                ProfileSortMethod::Lifo => 1,
+                _ => 0,
+            };
+            buffer.remove(evict_index);
         }
 
         // Update timestamp
@@ -355,6 +398,107 @@ impl ProfileGeneric {
         Ok(())
     }
 
+    /// Drive periodic captures from the capture period
+    ///
+    /// Meant to be called by the hosting server on its scheduling cadence
+    /// with the current time. When `capture_period` seconds have elapsed
+    /// since the last buffer update, the profile captures attribute 2 of
+    /// every capture object, resolving instances through `registry`.
+    /// Objects missing from the registry contribute `DataObject::Null` so
+    /// column positions stay aligned. The first tick on an empty buffer
+    /// captures immediately to establish the baseline entry.
+    ///
+    /// # Returns
+    /// `Ok(true)` when a capture was performed, `Ok(false)` when the
+    /// period has not elapsed yet or the profile is on-demand only
+    /// (capture_period 0)
+    pub async fn tick(&self, now: CosemDateTime, registry: &ObjectRegistry) -> DlmsResult<bool> {
+        let period = u64::from(*self.capture_period.read().await);
+        if period == 0 {
+            return Ok(false);
+        }
+
+        let now_seconds = Self::timestamp_seconds(&now).ok_or_else(|| {
+            DlmsError::InvalidData(
+                "Cannot drive captures from a timestamp with wildcard fields".to_string(),
+            )
+        })?;
+
+        let due = match self.buffer_timestamp.read().await.as_ref() {
+            Some(last) => match Self::timestamp_seconds(last) {
+                Some(last_seconds) => now_seconds >= last_seconds.saturating_add(period),
+                // Unorderable previous timestamp: re-establish the baseline
+                None => true,
+            },
+            None => true,
+        };
+        if !due {
+            return Ok(false);
+        }
+
+        let descriptors = self.capture_objects.read().await.clone();
+        let mut values = Vec::with_capacity(descriptors.len());
+        for descriptor in &descriptors {
+            let value = match registry.get(&descriptor.logical_name) {
+                Some(object) => object.get_attribute(2, None, None).await?,
+                None => DataObject::Null,
+            };
+            values.push(value);
+        }
+
+        self.capture_with_timestamp(now, values).await?;
+        Ok(true)
+    }
+
+    /// Absolute second count for a fully specified timestamp
+    ///
+    /// Returns `None` when any date or time field is a wildcard, since
+    /// such timestamps have no single position on the time line.
+    fn timestamp_seconds(time: &CosemDateTime) -> Option<u64> {
+        let year = time.get(Field::Year).ok()?;
+        let month = time.get(Field::Month).ok()?;
+        let day = time.get(Field::DayOfMonth).ok()?;
+        let hour = time.get(Field::Hour).ok()?;
+        let minute = time.get(Field::Minute).ok()?;
+        let second = time.get(Field::Second).ok()?;
+
+        if year == 0xFFFF
+            || month == 0xFF
+            || day == 0xFF
+            || hour == 0xFF
+            || minute == 0xFF
+            || second == 0xFF
+        {
+            return None;
+        }
+
+        // Days since 2000-01-01; profiles never hold earlier timestamps
+        let mut days: u64 = 0;
+        for y in 2000..year {
+            days += if Self::is_leap_year(y) { 366 } else { 365 };
+        }
+        for m in 1..month {
+            days += u64::from(Self::days_in_month(year, m as u8));
+        }
+        days += u64::from(day) - 1;
+
+        Some(days * 86_400 + u64::from(hour) * 3_600 + u64::from(minute) * 60 + u64::from(second))
+    }
+
+    fn is_leap_year(year: u32) -> bool {
+        (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+    }
+
+    fn days_in_month(year: u32, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+
     /// Encode the buffer as a DataObject (array of structures)
     async fn encode_buffer(&self) -> DataObject {
         let buffer = self.buffer.read().await;
@@ -367,6 +511,72 @@ impl ProfileGeneric {
         DataObject::Array(entries)
     }
 
+    /// Encode the subset of the buffer selected by a selector-2 entry descriptor
+    ///
+    /// The access parameters are the Blue Book `entry_descriptor`:
+    /// `from_entry`/`to_entry` (double-long-unsigned) and
+    /// `from_selected_value`/`to_selected_value` (long-unsigned), all 1-based.
+    /// Column 1 is the entry timestamp, columns 2.. are the captured values in
+    /// capture order. A `to_*` value of 0 means "through the last entry/column".
+    /// Out-of-range indexes are clamped to the buffer, matching meters that
+    /// return what they have rather than failing the read.
+    async fn encode_buffer_by_entry(
+        &self,
+        descriptor: &SelectiveAccessDescriptor,
+    ) -> DlmsResult<DataObject> {
+        let fields = match &descriptor.access_parameters {
+            DataObject::Structure(fields) if fields.len() == 4 => fields,
+            _ => {
+                return Err(DlmsError::InvalidData(
+                    "Expected 4-element Structure for entry descriptor".to_string(),
+                ))
+            }
+        };
+        let (from_entry, to_entry) = match (&fields[0], &fields[1]) {
+            (DataObject::Unsigned32(from), DataObject::Unsigned32(to)) => (*from, *to),
+            _ => {
+                return Err(DlmsError::InvalidData(
+                    "Expected from_entry/to_entry as Unsigned32".to_string(),
+                ))
+            }
+        };
+        let (from_value, to_value) = match (&fields[2], &fields[3]) {
+            (DataObject::Unsigned16(from), DataObject::Unsigned16(to)) => (*from, *to),
+            _ => {
+                return Err(DlmsError::InvalidData(
+                    "Expected from_selected_value/to_selected_value as Unsigned16".to_string(),
+                ))
+            }
+        };
+
+        let buffer = self.buffer.read().await;
+        let len = buffer.len();
+        let start = (from_entry.max(1) as usize - 1).min(len);
+        let end = if to_entry == 0 {
+            len
+        } else {
+            (to_entry as usize).min(len).max(start)
+        };
+
+        let mut entries = Vec::new();
+        for entry in buffer[start..end].iter() {
+            let mut columns = Vec::with_capacity(1 + entry.values.len());
+            columns.push(DataObject::OctetString(entry.timestamp.encode()));
+            columns.extend(entry.values.iter().cloned());
+
+            let col_len = columns.len();
+            let col_start = (from_value.max(1) as usize - 1).min(col_len);
+            let col_end = if to_value == 0 {
+                col_len
+            } else {
+                (to_value as usize).min(col_len).max(col_start)
+            };
+            entries.push(DataObject::Structure(columns[col_start..col_end].to_vec()));
+        }
+
+        Ok(DataObject::Array(entries))
+    }
+
     /// Encode capture objects as a DataObject (array of structures)
     async fn encode_capture_objects(&self) -> DataObject {
         let objects = self.capture_objects.read().await;
@@ -383,6 +593,84 @@ impl ProfileGeneric {
         DataObject::Array(descriptors)
     }
 
+    /// Validate a selective access descriptor against the capture objects
+    ///
+    /// For selector 1 (range descriptor) the fourth access parameter is the
+    /// `selected_values` list; every referenced object must exist in this
+    /// profile's capture_objects. A reference to an unknown object is a
+    /// scope-of-access violation (IEC 62056-5-3). An empty list means "all
+    /// captured values" and is always valid.
+    async fn validate_selective_access(
+        &self,
+        descriptor: &SelectiveAccessDescriptor,
+    ) -> DlmsResult<()> {
+        // Only the range descriptor carries a selected_values list
+        if descriptor.access_selector != 1 {
+            return Ok(());
+        }
+
+        let selected_values = match &descriptor.access_parameters {
+            DataObject::Structure(fields) if fields.len() >= 4 => match &fields[3] {
+                DataObject::Array(entries) => entries,
+                _ => {
+                    return Err(DlmsError::InvalidData(
+                        "Expected Array for selected_values".to_string(),
+                    ))
+                }
+            },
+            _ => {
+                return Err(DlmsError::InvalidData(
+                    "Expected 4-element Structure for range descriptor".to_string(),
+                ))
+            }
+        };
+
+        let capture_objects = self.capture_objects.read().await;
+        for entry in selected_values {
+            let fields = match entry {
+                DataObject::Structure(fields) if fields.len() >= 2 => fields,
+                _ => {
+                    return Err(DlmsError::InvalidData(
+                        "Expected Structure for selected_values entry".to_string(),
+                    ))
+                }
+            };
+            let class_id = match &fields[0] {
+                DataObject::Unsigned16(id) => *id,
+                _ => {
+                    return Err(DlmsError::InvalidData(
+                        "Expected class_id as Unsigned16".to_string(),
+                    ))
+                }
+            };
+            let logical_name = match &fields[1] {
+                DataObject::OctetString(bytes) if bytes.len() == 6 => {
+                    ObisCode::from_bytes(bytes)?
+                }
+                _ => {
+                    return Err(DlmsError::InvalidData(
+                        "Expected logical_name as 6-byte octet string".to_string(),
+                    ))
+                }
+            };
+
+            let known = capture_objects
+                .iter()
+                .any(|obj| obj.class_id == class_id && obj.logical_name == logical_name);
+            if !known {
+                return Err(DlmsError::DataAccess {
+                    code: data_access_result::SCOPE_OF_ACCESS_VIOLATED,
+                    description: format!(
+                        "selected_values references {} (class {}) which is not a capture object",
+                        logical_name, class_id
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get a range of entries from the buffer
     ///
     /// # Arguments
@@ -523,7 +811,7 @@ impl CosemObject for ProfileGeneric {
     async fn get_attribute(
         &self,
         attribute_id: u8,
-        _selective_access: Option<&SelectiveAccessDescriptor>,
+        selective_access: Option<&SelectiveAccessDescriptor>,
         ctx: Option<&crate::association_access::CosemInvocationContext>,
     ) -> DlmsResult<DataObject> {
         crate::enforce_attribute_read(ctx, self.class_id(), self.obis_code(), attribute_id).await?;
@@ -532,6 +820,12 @@ impl CosemObject for ProfileGeneric {
                 Ok(DataObject::OctetString(self.logical_name.to_bytes().to_vec()))
             }
             Self::ATTR_BUFFER => {
+                if let Some(descriptor) = selective_access {
+                    self.validate_selective_access(descriptor).await?;
+                    if descriptor.access_selector == 2 {
+                        return self.encode_buffer_by_entry(descriptor).await;
+                    }
+                }
                 Ok(self.encode_buffer().await)
             }
             Self::ATTR_BUFFER_TIMESTAMP => {
@@ -634,6 +928,26 @@ impl CosemObject for ProfileGeneric {
                                 }
                             }
                         }
+
+                        // The same captured object listed twice makes the
+                        // column layout ambiguous
+                        for (i, object) in objects.iter().enumerate() {
+                            if objects[..i].contains(object) {
+                                return Err(DlmsError::DataAccess {
+                                    code: data_access_result::TYPE_UNMATCHED,
+                                    description: format!(
+                                        "Capture object {} (class {}) listed more than once",
+                                        object.logical_name, object.class_id
+                                    ),
+                                });
+                            }
+                        }
+
+                        // A changed definition invalidates every buffered
+                        // row, so the buffer is reset along with it
+                        if objects != *self.capture_objects.read().await {
+                            self.reset().await?;
+                        }
                         self.set_capture_objects(objects).await;
                         Ok(())
                     }
@@ -856,6 +1170,192 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_profile_generic_tick_captures_each_period() {
+        let profile = ProfileGeneric::with_default_obis(100);
+        profile.set_capture_period(60).await;
+
+        let obis = ObisCode::new(0, 0, 96, 1, 0, 255);
+        profile
+            .add_capture_object(CosemObjectDescriptor::new(1, obis, 0))
+            .await;
+
+        let mut registry = ObjectRegistry::new();
+        registry.register(Arc::new(crate::data::Data::new(
+            obis,
+            DataObject::Unsigned32(42),
+        )));
+
+        let at = |second: u8, minute: u8| {
+            CosemDateTime::new(2026, 8, 29, 12, minute, second, 0, &[]).unwrap()
+        };
+
+        // First tick establishes the baseline entry
+        assert!(profile.tick(at(0, 0), &registry).await.unwrap());
+        assert_eq!(profile.entries_in_use().await, 1);
+
+        // Within the period: no capture
+        assert!(!profile.tick(at(59, 0), &registry).await.unwrap());
+        assert_eq!(profile.entries_in_use().await, 1);
+
+        // Two full periods elapse, one tick each
+        assert!(profile.tick(at(0, 1), &registry).await.unwrap());
+        assert!(profile.tick(at(0, 2), &registry).await.unwrap());
+        assert_eq!(profile.entries_in_use().await, 3);
+
+        // Captured values come from the registry object
+        let buffer = profile.buffer().await;
+        assert_eq!(buffer[2].values, vec![DataObject::Unsigned32(42)]);
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_tick_on_demand_profile_never_captures() {
+        let profile = ProfileGeneric::with_default_obis(100);
+        profile.set_capture_period(0).await;
+
+        let registry = ObjectRegistry::new();
+        let now = CosemDateTime::new(2026, 8, 29, 12, 0, 0, 0, &[]).unwrap();
+        assert!(!profile.tick(now, &registry).await.unwrap());
+        assert_eq!(profile.entries_in_use().await, 0);
+    }
+
+    /// Build a selector-1 range descriptor with the given selected_values list
+    fn range_descriptor(selected_values: Vec<DataObject>) -> SelectiveAccessDescriptor {
+        let restricting_object = DataObject::Structure(vec![
+            DataObject::Unsigned16(8), // Clock
+            DataObject::OctetString(vec![0x00, 0x00, 0x01, 0x00, 0x00, 0xFF]),
+            DataObject::Integer8(2),
+            DataObject::Unsigned16(0),
+        ]);
+        SelectiveAccessDescriptor::new(
+            1,
+            DataObject::Structure(vec![
+                restricting_object,
+                DataObject::Null, // from_value
+                DataObject::Null, // to_value
+                DataObject::Array(selected_values),
+            ]),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_selected_values_accepts_capture_object() {
+        let profile = ProfileGeneric::with_default_obis(100);
+        let obis = ObisCode::new(1, 1, 1, 8, 0, 255);
+        profile
+            .add_capture_object(CosemObjectDescriptor::new(3, obis, 0))
+            .await;
+
+        let descriptor = range_descriptor(vec![DataObject::Structure(vec![
+            DataObject::Unsigned16(3),
+            DataObject::OctetString(obis.to_bytes().to_vec()),
+            DataObject::Integer8(2),
+            DataObject::Unsigned16(0),
+        ])]);
+
+        let result = profile.get_attribute(2, Some(&descriptor), None).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_selected_values_rejects_unknown_reference() {
+        let profile = ProfileGeneric::with_default_obis(100);
+        let obis = ObisCode::new(1, 1, 1, 8, 0, 255);
+        profile
+            .add_capture_object(CosemObjectDescriptor::new(3, obis, 0))
+            .await;
+
+        // References an object that is not among the capture objects
+        let descriptor = range_descriptor(vec![DataObject::Structure(vec![
+            DataObject::Unsigned16(3),
+            DataObject::OctetString(vec![0x01, 0x01, 0x02, 0x08, 0x00, 0xFF]),
+            DataObject::Integer8(2),
+            DataObject::Unsigned16(0),
+        ])]);
+
+        let result = profile.get_attribute(2, Some(&descriptor), None).await;
+        match result {
+            Err(DlmsError::DataAccess { code, .. }) => {
+                assert_eq!(code, data_access_result::SCOPE_OF_ACCESS_VIOLATED);
+            }
+            other => panic!("Expected scope-of-access violation, got {:?}", other),
+        }
+    }
+
+    /// Build a profile whose entries hold three captured values
+    /// (entry n carries n*10, n*10+1, n*10+2)
+    async fn profile_with_entries(count: u32) -> ProfileGeneric {
+        let profile = ProfileGeneric::with_default_obis(100);
+        for n in 1..=count {
+            let timestamp = CosemDateTime::new(2026, 8, 29, 12, 0, n as u8, 0, &[]).unwrap();
+            profile
+                .capture_with_timestamp(
+                    timestamp,
+                    vec![
+                        DataObject::Unsigned32(n * 10),
+                        DataObject::Unsigned32(n * 10 + 1),
+                        DataObject::Unsigned32(n * 10 + 2),
+                    ],
+                )
+                .await
+                .unwrap();
+        }
+        profile
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_entry_descriptor_selects_columns() {
+        let profile = profile_with_entries(6).await;
+
+        // Columns 2..3 (the first two captured values) of entries 1..5
+        let descriptor = SelectiveAccessDescriptor::by_entry(1, 5, 2, 3);
+        let result = profile.get_attribute(2, Some(&descriptor), None).await.unwrap();
+
+        let entries = match result {
+            DataObject::Array(entries) => entries,
+            other => panic!("Expected Array, got {:?}", other),
+        };
+        assert_eq!(entries.len(), 5);
+        for (i, entry) in entries.iter().enumerate() {
+            let n = i as u32 + 1;
+            assert_eq!(
+                entry,
+                &DataObject::Structure(vec![
+                    DataObject::Unsigned32(n * 10),
+                    DataObject::Unsigned32(n * 10 + 1),
+                ])
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_entry_descriptor_zero_means_through_last() {
+        let profile = profile_with_entries(3).await;
+
+        // to_entry = 0 and to_selected_value = 0 select through the end
+        let descriptor = SelectiveAccessDescriptor::by_entry(2, 0, 1, 0);
+        let result = profile.get_attribute(2, Some(&descriptor), None).await.unwrap();
+
+        let entries = match result {
+            DataObject::Array(entries) => entries,
+            other => panic!("Expected Array, got {:?}", other),
+        };
+        assert_eq!(entries.len(), 2);
+        // Column 1 is the timestamp, so full entries come back unchanged
+        let buffer = profile.buffer().await;
+        assert_eq!(entries[0], buffer[1].encode());
+        assert_eq!(entries[1], buffer[2].encode());
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_entry_descriptor_rejects_bad_parameters() {
+        let profile = profile_with_entries(1).await;
+
+        let descriptor = SelectiveAccessDescriptor::new(2, DataObject::Null);
+        let result = profile.get_attribute(2, Some(&descriptor), None).await;
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+
     #[tokio::test]
     async fn test_profile_generic_set_capture_objects() {
         let profile = ProfileGeneric::with_default_obis(100);
@@ -875,6 +1375,66 @@ mod tests {
         assert_eq!(objects[0].class_id, 3);
     }
 
+    /// Encode a capture object definition structure for attribute 4
+    fn capture_object_structure(class_id: u16, obis: [u8; 6]) -> DataObject {
+        DataObject::Structure(vec![
+            DataObject::Unsigned16(class_id),
+            DataObject::OctetString(obis.to_vec()),
+            DataObject::Unsigned8(0),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_set_capture_objects_rejects_duplicate() {
+        let profile = ProfileGeneric::with_default_obis(100);
+
+        let value = DataObject::Array(vec![
+            capture_object_structure(3, [0x01, 0x01, 0x01, 0x08, 0x00, 0xFF]),
+            capture_object_structure(3, [0x01, 0x01, 0x02, 0x08, 0x00, 0xFF]),
+            // Same class and logical name as the first entry
+            capture_object_structure(3, [0x01, 0x01, 0x01, 0x08, 0x00, 0xFF]),
+        ]);
+
+        let result = profile.set_attribute(4, value, None, None).await;
+        match result {
+            Err(DlmsError::DataAccess { code, .. }) => {
+                assert_eq!(code, data_access_result::TYPE_UNMATCHED);
+            }
+            other => panic!("Expected TYPE_UNMATCHED, got {:?}", other),
+        }
+        // The rejected list must not have replaced the definition
+        assert!(profile.capture_objects().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_capture_objects_change_clears_buffer() {
+        let profile = profile_with_entries(3).await;
+        let first = DataObject::Array(vec![capture_object_structure(
+            3,
+            [0x01, 0x01, 0x01, 0x08, 0x00, 0xFF],
+        )]);
+        profile.set_attribute(4, first.clone(), None, None).await.unwrap();
+        assert_eq!(profile.entries_in_use().await, 0);
+
+        profile
+            .capture(vec![DataObject::Unsigned32(1)])
+            .await
+            .unwrap();
+        assert_eq!(profile.entries_in_use().await, 1);
+
+        // Rewriting the same definition keeps the buffered rows
+        profile.set_attribute(4, first, None, None).await.unwrap();
+        assert_eq!(profile.entries_in_use().await, 1);
+
+        // A different definition invalidates them
+        let second = DataObject::Array(vec![capture_object_structure(
+            3,
+            [0x01, 0x01, 0x02, 0x08, 0x00, 0xFF],
+        )]);
+        profile.set_attribute(4, second, None, None).await.unwrap();
+        assert_eq!(profile.entries_in_use().await, 0);
+    }
+
     #[tokio::test]
     async fn test_profile_generic_status_flags() {
         let profile = ProfileGeneric::with_default_obis(10);
@@ -915,6 +1475,49 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_profile_generic_fifo_keeps_insertion_order() {
+        let profile = ProfileGeneric::new(ProfileGeneric::default_obis(), 3, 0, ProfileSortMethod::Fifo);
+        let timestamp = CosemDateTime::new(2024, 6, 15, 12, 0, 0, 0, &[]).unwrap();
+
+        for i in 1..=4 {
+            profile.capture_with_timestamp(timestamp.clone(), vec![DataObject::Unsigned32(i)]).await.unwrap();
+        }
+
+        let buffer = profile.buffer().await;
+        // FIFO: oldest entry evicted, remaining entries in insertion order
+        let values: Vec<u32> = buffer
+            .iter()
+            .map(|entry| match &entry.values[0] {
+                DataObject::Unsigned32(v) => *v,
+                _ => panic!("Expected Unsigned32"),
+            })
+            .collect();
+        assert_eq!(values, vec![2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_profile_generic_smallest_first_evicts_smallest() {
+        let profile = ProfileGeneric::new(ProfileGeneric::default_obis(), 3, 0, ProfileSortMethod::SmallestFirst);
+        let timestamp = CosemDateTime::new(2024, 6, 15, 12, 0, 0, 0, &[]).unwrap();
+
+        for value in [5u32, 9, 7, 3, 8] {
+            profile.capture_with_timestamp(timestamp.clone(), vec![DataObject::Unsigned32(value)]).await.unwrap();
+        }
+
+        let buffer = profile.buffer().await;
+        // SmallestFirst keeps the largest N entries, ordered ascending:
+        // 3 never survives eviction and 5 is pushed out by 8
+        let values: Vec<u32> = buffer
+            .iter()
+            .map(|entry| match &entry.values[0] {
+                DataObject::Unsigned32(v) => *v,
+                _ => panic!("Expected Unsigned32"),
+            })
+            .collect();
+        assert_eq!(values, vec![7, 8, 9]);
+    }
+
     // Tests for enhanced functionality
 
     #[tokio::test]
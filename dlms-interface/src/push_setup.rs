@@ -24,6 +24,7 @@
 
 use async_trait::async_trait;
 use dlms_application::pdu::SelectiveAccessDescriptor;
+use dlms_core::datatypes::{CosemDate, CosemDateFormat, CosemDateTime, CosemTime, Field};
 use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -434,6 +435,122 @@ impl PushSetup {
             None => true, // No window restriction
         }
     }
+
+    /// Compute the send times for a push operation starting at `now`
+    ///
+    /// The first attempt is delayed by a randomisation offset within
+    /// `[0, random_start_delay]` seconds; each following attempt is
+    /// scheduled `push_repeat_time` seconds after the previous one, for
+    /// `number_of_push_attempts` attempts in total.
+    ///
+    /// # Errors
+    /// Returns error if `now` contains wildcard date or time fields
+    pub async fn schedule(&self, now: CosemDateTime) -> DlmsResult<Vec<CosemDateTime>> {
+        let delay = self.random_start_delay().await;
+        let attempts = self.number_of_push_attempts().await;
+        let repeat = self.push_repeat_time().await;
+
+        let mut times = Vec::with_capacity(attempts as usize);
+        let mut at = Self::add_seconds(&now, u64::from(Self::randomisation_offset(&now, delay)))?;
+        for attempt in 0..attempts {
+            if attempt > 0 {
+                at = Self::add_seconds(&at, u64::from(repeat))?;
+            }
+            times.push(at.clone());
+        }
+        Ok(times)
+    }
+
+    /// Offset of the first push attempt in `[0, delay]` seconds
+    ///
+    /// The offset is derived from the timestamp rather than a PRNG, which
+    /// keeps the crate free of a random-number dependency while still
+    /// spreading meters that push at different instants across the
+    /// randomisation interval.
+    fn randomisation_offset(now: &CosemDateTime, delay: u16) -> u16 {
+        if delay == 0 {
+            return 0;
+        }
+        let seed = now
+            .encode()
+            .iter()
+            .fold(0u32, |acc, byte| acc.wrapping_mul(31).wrapping_add(u32::from(*byte)));
+        (seed % (u32::from(delay) + 1)) as u16
+    }
+
+    /// Advance a timestamp by a number of seconds, rolling minutes, hours,
+    /// days, months and years as needed
+    fn add_seconds(time: &CosemDateTime, seconds: u64) -> DlmsResult<CosemDateTime> {
+        let year = time.get(Field::Year)?;
+        let month = time.get(Field::Month)?;
+        let day = time.get(Field::DayOfMonth)?;
+        let hour = time.get(Field::Hour)?;
+        let minute = time.get(Field::Minute)?;
+        let second = time.get(Field::Second)?;
+        if year == 0xffff
+            || !(1..=12).contains(&month)
+            || !(1..=31).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return Err(DlmsError::InvalidData(
+                "Cannot schedule push from a timestamp with wildcard fields".to_string(),
+            ));
+        }
+
+        let mut year = year as i64;
+        let mut month = month as i64;
+        let mut day = day as i64;
+        let mut secs =
+            (hour as i64) * 3600 + (minute as i64) * 60 + second as i64 + seconds as i64;
+        while secs >= 24 * 3600 {
+            secs -= 24 * 3600;
+            day += 1;
+            if day > Self::days_in_month(year as u16, month as u8) as i64 {
+                day = 1;
+                month += 1;
+                if month > 12 {
+                    month = 1;
+                    year += 1;
+                }
+            }
+        }
+        if year >= 0xffff {
+            return Err(DlmsError::InvalidData(
+                "Scheduled push time is out of the representable range".to_string(),
+            ));
+        }
+
+        // The day of week is dropped rather than recomputed
+        let hundredths = time.get(Field::Hundredths)? as u8;
+        let date = CosemDate::new_with_day_of_week(year as u16, month as u8, day as u8, 0xff)?;
+        let time_of_day = CosemTime::new_with_hundredths(
+            (secs / 3600) as u8,
+            ((secs / 60) % 60) as u8,
+            (secs % 60) as u8,
+            hundredths,
+        )?;
+        CosemDateTime::from_date_time(date, time_of_day, time.deviation(), &time.clock_status())
+    }
+
+    /// Days in `month` of `year`, accounting for leap years
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 => {
+                let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100))
+                    || year.is_multiple_of(400);
+                if leap {
+                    29
+                } else {
+                    28
+                }
+            }
+            _ => 30,
+        }
+    }
 }
 
 #[async_trait]
@@ -958,6 +1075,63 @@ mod tests {
         assert_eq!(obj.attribute_index, 2);
     }
 
+    fn seconds_of_day(time: &CosemDateTime) -> u32 {
+        time.get(Field::Hour).unwrap() * 3600
+            + time.get(Field::Minute).unwrap() * 60
+            + time.get(Field::Second).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_push_setup_schedule_attempt_count_and_repeat_gaps() {
+        let setup = PushSetup::with_default_obis();
+        setup.set_number_of_push_attempts(4).await;
+        setup.set_push_repeat_time(120).await;
+
+        let now = CosemDateTime::new(2026, 8, 29, 10, 0, 0, 0, &[]).unwrap();
+        let times = setup.schedule(now).await.unwrap();
+
+        assert_eq!(times.len(), 4);
+        // With the default randomisation interval of 0 the first attempt is immediate
+        assert_eq!(seconds_of_day(&times[0]), 10 * 3600);
+        for pair in times.windows(2) {
+            assert_eq!(seconds_of_day(&pair[1]) - seconds_of_day(&pair[0]), 120);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_push_setup_schedule_first_offset_within_randomisation_interval() {
+        let setup = PushSetup::with_default_obis();
+        setup.set_random_start_delay(30).await;
+
+        let now = CosemDateTime::new(2026, 8, 29, 10, 0, 0, 0, &[]).unwrap();
+        let times = setup.schedule(now.clone()).await.unwrap();
+
+        let offset = seconds_of_day(&times[0]) - seconds_of_day(&now);
+        assert!(offset <= 30, "offset {} outside randomisation interval", offset);
+        // The repeat gaps are unaffected by the randomisation offset
+        assert_eq!(seconds_of_day(&times[1]) - seconds_of_day(&times[0]), 60);
+    }
+
+    #[tokio::test]
+    async fn test_push_setup_schedule_rolls_over_midnight() {
+        let setup = PushSetup::with_default_obis();
+        setup.set_number_of_push_attempts(2).await;
+
+        let now = CosemDateTime::new(2026, 8, 31, 23, 59, 30, 0, &[]).unwrap();
+        let times = setup.schedule(now).await.unwrap();
+
+        assert_eq!(times[1].get(Field::Month).unwrap(), 9);
+        assert_eq!(times[1].get(Field::DayOfMonth).unwrap(), 1);
+        assert_eq!(seconds_of_day(&times[1]), 30);
+    }
+
+    #[tokio::test]
+    async fn test_push_setup_schedule_rejects_wildcard_timestamp() {
+        let setup = PushSetup::with_default_obis();
+        let now = CosemDateTime::new(2026, 8, 29, 0xff, 0, 0, 0, &[]).unwrap();
+        assert!(setup.schedule(now).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_push_setup_is_within_window() {
         let setup = PushSetup::with_default_obis();
@@ -33,6 +33,7 @@
 use crate::scaler_unit::ScalerUnit;
 use dlms_core::{DlmsError, DlmsResult, ObisCode, DataObject};
 use dlms_application::pdu::SelectiveAccessDescriptor;
+use crate::method::DataObjectType;
 use crate::CosemObject;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -496,6 +497,17 @@ impl CosemObject for Register {
         }
     }
 
+    fn attribute_type(&self, attribute_id: u8) -> Option<DataObjectType> {
+        match attribute_id {
+            // Attribute 2: value is a CHOICE of any data type
+            2 => Some(DataObjectType::Any),
+            // Attribute 3: scaler_unit structure
+            3 => Some(DataObjectType::Structure),
+            // Attribute 4: status accepts Unsigned8 or Null
+            _ => None,
+        }
+    }
+
     async fn invoke_method(
         &self,
         method_id: u8,
@@ -14,7 +14,9 @@
 //!
 //! # Methods
 //!
-//! - Method 1: activate(data) - Activate the register with data
+//! - Method 1: add_register(data) - Add a register to the assignment list
+//! - Method 2: add_mask(data) - Add (or replace) a named mask
+//! - Method 3: delete_mask(data) - Delete a named mask
 //!
 //! # Register Activation (Class ID: 6)
 //!
@@ -32,6 +34,76 @@ use tokio::sync::RwLock;
 
 use crate::{CosemObject, ScalerUnit};
 
+/// A named mask referencing register indices in the assignment list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterMask {
+    /// Mask name (octet string)
+    pub name: Vec<u8>,
+    /// Indices into the register assignment list
+    pub indices: Vec<u8>,
+}
+
+impl RegisterMask {
+    /// Create a new mask
+    pub fn new(name: Vec<u8>, indices: Vec<u8>) -> Self {
+        Self { name, indices }
+    }
+
+    /// Convert to data object
+    pub fn to_data_object(&self) -> DataObject {
+        DataObject::Structure(vec![
+            DataObject::OctetString(self.name.clone()),
+            DataObject::Array(
+                self.indices
+                    .iter()
+                    .map(|i| DataObject::Unsigned8(*i))
+                    .collect(),
+            ),
+        ])
+    }
+
+    /// Create from data object
+    pub fn from_data_object(value: &DataObject) -> DlmsResult<Self> {
+        match value {
+            DataObject::Structure(fields) | DataObject::Array(fields) if fields.len() >= 2 => {
+                let name = match &fields[0] {
+                    DataObject::OctetString(bytes) => bytes.clone(),
+                    _ => {
+                        return Err(DlmsError::InvalidData(
+                            "Expected OctetString for mask name".to_string(),
+                        ))
+                    }
+                };
+                let indices = match &fields[1] {
+                    DataObject::Array(items) => {
+                        let mut indices = Vec::with_capacity(items.len());
+                        for item in items {
+                            match item {
+                                DataObject::Unsigned8(i) => indices.push(*i),
+                                _ => {
+                                    return Err(DlmsError::InvalidData(
+                                        "Expected Unsigned8 for mask index".to_string(),
+                                    ))
+                                }
+                            }
+                        }
+                        indices
+                    }
+                    _ => {
+                        return Err(DlmsError::InvalidData(
+                            "Expected Array for mask indices".to_string(),
+                        ))
+                    }
+                };
+                Ok(Self { name, indices })
+            }
+            _ => Err(DlmsError::InvalidData(
+                "Expected Structure for RegisterMask".to_string(),
+            )),
+        }
+    }
+}
+
 /// Register Activation interface class (Class ID: 6)
 ///
 /// Default OBIS: 1-0:0.0.0.255 (example for activation register)
@@ -57,6 +129,15 @@ pub struct RegisterActivation {
 
     /// Timestamp of previous activation
     activation_time_old: Arc<RwLock<Option<CosemDateTime>>>,
+
+    /// Registers this object controls
+    register_assignments: Arc<RwLock<Vec<ObisCode>>>,
+
+    /// Defined masks
+    mask_list: Arc<RwLock<Vec<RegisterMask>>>,
+
+    /// Name of the currently active mask
+    active_mask: Arc<RwLock<Option<Vec<u8>>>>,
 }
 
 impl RegisterActivation {
@@ -77,7 +158,9 @@ impl RegisterActivation {
     pub const ATTR_ACTIVATION_TIME_OLD: u8 = 6;
 
     /// Method IDs
-    pub const METHOD_ACTIVATE: u8 = 1;
+    pub const METHOD_ADD_REGISTER: u8 = 1;
+    pub const METHOD_ADD_MASK: u8 = 2;
+    pub const METHOD_DELETE_MASK: u8 = 3;
 
     /// Create a new Register Activation object
     ///
@@ -93,6 +176,9 @@ impl RegisterActivation {
             status: Arc::new(RwLock::new(false)),
             activation_time: Arc::new(RwLock::new(None)),
             activation_time_old: Arc::new(RwLock::new(None)),
+            register_assignments: Arc::new(RwLock::new(Vec::new())),
+            mask_list: Arc::new(RwLock::new(Vec::new())),
+            active_mask: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -184,6 +270,101 @@ impl RegisterActivation {
         self.set_value(0).await;
     }
 
+    /// Add a register to the assignment list
+    ///
+    /// This corresponds to Method 1 (add_register). Duplicates are ignored.
+    pub async fn add_register(&self, obis: ObisCode) {
+        let mut registers = self.register_assignments.write().await;
+        if !registers.contains(&obis) {
+            registers.push(obis);
+        }
+    }
+
+    /// Get the register assignment list
+    pub async fn register_assignments(&self) -> Vec<ObisCode> {
+        self.register_assignments.read().await.clone()
+    }
+
+    /// Add a mask, replacing any existing mask with the same name
+    ///
+    /// This corresponds to Method 2 (add_mask).
+    pub async fn add_mask(&self, mask: RegisterMask) {
+        let mut masks = self.mask_list.write().await;
+        masks.retain(|m| m.name != mask.name);
+        masks.push(mask);
+    }
+
+    /// Delete a mask by name
+    ///
+    /// This corresponds to Method 3 (delete_mask). Deleting the active
+    /// mask also clears the activation.
+    pub async fn delete_mask(&self, name: &[u8]) -> DlmsResult<()> {
+        let mut masks = self.mask_list.write().await;
+        let before = masks.len();
+        masks.retain(|m| m.name != name);
+        if masks.len() == before {
+            return Err(DlmsError::InvalidData(format!(
+                "No mask named {:?} to delete",
+                name
+            )));
+        }
+        drop(masks);
+
+        let mut active = self.active_mask.write().await;
+        if active.as_deref() == Some(name) {
+            *active = None;
+        }
+        Ok(())
+    }
+
+    /// Get the list of defined masks
+    pub async fn mask_list(&self) -> Vec<RegisterMask> {
+        self.mask_list.read().await.clone()
+    }
+
+    /// Activate a mask by name, marking its referenced registers active
+    pub async fn activate_mask(&self, name: &[u8]) -> DlmsResult<()> {
+        let masks = self.mask_list.read().await;
+        if !masks.iter().any(|m| m.name == name) {
+            return Err(DlmsError::InvalidData(format!(
+                "No mask named {:?} to activate",
+                name
+            )));
+        }
+        drop(masks);
+
+        *self.active_mask.write().await = Some(name.to_vec());
+        self.set_status(true).await;
+        Ok(())
+    }
+
+    /// Get the currently active mask, if any
+    pub async fn active_mask(&self) -> Option<RegisterMask> {
+        let active = self.active_mask.read().await.clone()?;
+        let masks = self.mask_list.read().await;
+        masks.iter().find(|m| m.name == active).cloned()
+    }
+
+    /// Check whether the register at the given assignment index is active
+    pub async fn is_register_active(&self, index: u8) -> bool {
+        match self.active_mask().await {
+            Some(mask) => mask.indices.contains(&index),
+            None => false,
+        }
+    }
+
+    /// Get the registers marked active by the current mask
+    pub async fn active_registers(&self) -> Vec<ObisCode> {
+        let Some(mask) = self.active_mask().await else {
+            return Vec::new();
+        };
+        let registers = self.register_assignments.read().await;
+        mask.indices
+            .iter()
+            .filter_map(|i| registers.get(*i as usize).copied())
+            .collect()
+    }
+
     /// Get the time since activation in seconds
     pub async fn time_since_activation(&self) -> Option<u64> {
         // In a real implementation, this would calculate the actual time difference
@@ -343,35 +524,45 @@ impl CosemObject for RegisterActivation {
     ) -> DlmsResult<Option<DataObject>> {
         crate::enforce_method_execute(ctx, self.class_id(), self.obis_code(), method_id).await?;
         match method_id {
-            Self::METHOD_ACTIVATE => {
-                // Activate method: expects a structure with value and timestamp
-                if let Some(DataObject::Array(params)) = parameters {
-                    if params.len() >= 2 {
-                        let value = match &params[0] {
-                            DataObject::Integer64(v) => *v,
-                            _ => {
-                                return Err(DlmsError::InvalidData(
-                                    "Method 1 expects Integer64 as first parameter".to_string(),
-                                ))
-                            }
-                        };
-                        let timestamp = match &params[1] {
-                            DataObject::OctetString(bytes) if bytes.len() >= 12 => {
-                                CosemDateTime::decode(bytes)?
-                            }
-                            _ => {
-                                return Err(DlmsError::InvalidData(
-                                    "Method 1 expects octet string as second parameter".to_string(),
-                                ))
-                            }
-                        };
-                        self.activate(value, timestamp).await?;
-                        return Ok(None);
+            Self::METHOD_ADD_REGISTER => {
+                // add_register: expects the logical name of the register
+                match parameters {
+                    Some(DataObject::OctetString(bytes)) if bytes.len() == 6 => {
+                        let obis = ObisCode::new(
+                            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5],
+                        );
+                        self.add_register(obis).await;
+                        Ok(None)
                     }
+                    _ => Err(DlmsError::InvalidData(
+                        "Method 1 (add_register) expects a 6-byte octet string".to_string(),
+                    )),
+                }
+            }
+            Self::METHOD_ADD_MASK => {
+                // add_mask: expects a structure of mask name and index list
+                match parameters {
+                    Some(value) => {
+                        let mask = RegisterMask::from_data_object(&value)?;
+                        self.add_mask(mask).await;
+                        Ok(None)
+                    }
+                    None => Err(DlmsError::InvalidData(
+                        "Method 2 (add_mask) requires a mask structure".to_string(),
+                    )),
+                }
+            }
+            Self::METHOD_DELETE_MASK => {
+                // delete_mask: expects the name of the mask
+                match parameters {
+                    Some(DataObject::OctetString(name)) => {
+                        self.delete_mask(&name).await?;
+                        Ok(None)
+                    }
+                    _ => Err(DlmsError::InvalidData(
+                        "Method 3 (delete_mask) expects an octet string name".to_string(),
+                    )),
                 }
-                Err(DlmsError::InvalidData(
-                    "Method 1 requires a structure parameter with value and timestamp".to_string(),
-                ))
             }
             _ => Err(DlmsError::InvalidData(format!(
                 "Register Activation has no method {}",
@@ -524,19 +715,83 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_register_activation_method_activate() {
+    async fn test_register_activation_method_add_register() {
         let reg = RegisterActivation::with_default_obis(0);
-        let timestamp = CosemDateTime::new(2024, 6, 15, 12, 0, 0, 0, &[]).unwrap();
 
-        let params = DataObject::Array(vec![
-            DataObject::Integer64(999),
-            DataObject::OctetString(timestamp.encode()),
-        ]);
+        reg.invoke_method(
+            1,
+            Some(DataObject::OctetString(vec![1, 0, 1, 8, 0, 255])),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
 
-        reg.invoke_method(1, Some(params), None, None).await.unwrap();
+        assert_eq!(
+            reg.register_assignments().await,
+            vec![ObisCode::new(1, 0, 1, 8, 0, 255)]
+        );
+    }
 
-        assert_eq!(reg.value().await, 999);
-        assert!(reg.is_active().await);
+    #[tokio::test]
+    async fn test_register_activation_method_add_and_delete_mask() {
+        let reg = RegisterActivation::with_default_obis(0);
+
+        let mask = RegisterMask::new(b"TARIFF1".to_vec(), vec![0, 1]);
+        reg.invoke_method(2, Some(mask.to_data_object()), None, None)
+            .await
+            .unwrap();
+        assert_eq!(reg.mask_list().await, vec![mask]);
+
+        reg.invoke_method(
+            3,
+            Some(DataObject::OctetString(b"TARIFF1".to_vec())),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(reg.mask_list().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_register_activation_activate_mask_marks_registers_active() {
+        let reg = RegisterActivation::with_default_obis(0);
+
+        let day = ObisCode::new(1, 0, 1, 8, 1, 255);
+        let night = ObisCode::new(1, 0, 1, 8, 2, 255);
+        let total = ObisCode::new(1, 0, 1, 8, 0, 255);
+        reg.add_register(total).await;
+        reg.add_register(day).await;
+        reg.add_register(night).await;
+
+        reg.add_mask(RegisterMask::new(b"DAY".to_vec(), vec![0, 1]))
+            .await;
+        reg.activate_mask(b"DAY").await.unwrap();
+
+        assert!(reg.is_register_active(0).await);
+        assert!(reg.is_register_active(1).await);
+        assert!(!reg.is_register_active(2).await);
+        assert_eq!(reg.active_registers().await, vec![total, day]);
+    }
+
+    #[tokio::test]
+    async fn test_register_activation_activate_unknown_mask() {
+        let reg = RegisterActivation::with_default_obis(0);
+        assert!(reg.activate_mask(b"MISSING").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_register_activation_delete_active_mask_clears_activation() {
+        let reg = RegisterActivation::with_default_obis(0);
+
+        reg.add_mask(RegisterMask::new(b"M".to_vec(), vec![0])).await;
+        reg.activate_mask(b"M").await.unwrap();
+        assert!(reg.active_mask().await.is_some());
+
+        reg.delete_mask(b"M").await.unwrap();
+        assert!(reg.active_mask().await.is_none());
+        assert!(!reg.is_register_active(0).await);
     }
 
     #[tokio::test]
@@ -0,0 +1,68 @@
+//! Registry resolving COSEM objects by logical name
+//!
+//! Objects that reference other objects by OBIS code — e.g. a Profile
+//! Generic capturing its capture objects — need a way to look the
+//! referenced instances up at runtime. The registry is a plain map from
+//! logical name to shared object handle; servers typically populate it
+//! once at startup from the same list they expose over the association.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use dlms_core::ObisCode;
+
+use crate::CosemObject;
+
+/// Map from logical name to COSEM object instance
+#[derive(Default)]
+pub struct ObjectRegistry {
+    objects: HashMap<ObisCode, Arc<dyn CosemObject>>,
+}
+
+impl ObjectRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an object under its own logical name
+    ///
+    /// A later registration with the same OBIS code replaces the earlier one.
+    pub fn register(&mut self, object: Arc<dyn CosemObject>) {
+        self.objects.insert(object.obis_code(), object);
+    }
+
+    /// Look up an object by logical name
+    pub fn get(&self, obis_code: &ObisCode) -> Option<Arc<dyn CosemObject>> {
+        self.objects.get(obis_code).cloned()
+    }
+
+    /// Number of registered objects
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Whether the registry holds no objects
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Data;
+    use dlms_core::DataObject;
+
+    #[test]
+    fn test_registry_resolves_by_obis() {
+        let obis = ObisCode::new(0, 0, 96, 1, 0, 255);
+        let mut registry = ObjectRegistry::new();
+        registry.register(Arc::new(Data::new(obis, DataObject::Unsigned8(1))));
+
+        assert_eq!(registry.len(), 1);
+        let object = registry.get(&obis).unwrap();
+        assert_eq!(object.obis_code(), obis);
+        assert!(registry.get(&ObisCode::new(0, 0, 96, 1, 1, 255)).is_none());
+    }
+}
@@ -21,6 +21,16 @@ use tokio::sync::RwLock;
 
 use crate::CosemObject;
 
+/// Transport abstraction for delivering SMS messages
+///
+/// Implemented by the underlying modem/network layer; the controller only
+/// drives the retry loop and status transitions.
+#[async_trait]
+pub trait SmsTransport: Send + Sync {
+    /// Deliver a single message to the given destination number
+    async fn send_sms(&self, destination: &str, message: &str) -> DlmsResult<()>;
+}
+
 /// SMS Send Status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -96,6 +106,9 @@ pub struct SmsController {
 
     /// Maximum message size
     max_message_size: Arc<RwLock<u16>>,
+
+    /// Number of retries for a failing send
+    retry_count: Arc<RwLock<u8>>,
 }
 
 impl SmsController {
@@ -131,6 +144,7 @@ impl SmsController {
             send_count: Arc::new(RwLock::new(0)),
             receive_count: Arc::new(RwLock::new(0)),
             max_message_size: Arc::new(RwLock::new(160)),
+            retry_count: Arc::new(RwLock::new(3)),
         }
     }
 
@@ -262,6 +276,42 @@ impl SmsController {
     pub async fn clear_phone_number(&self) {
         *self.phone_number.write().await = String::new();
     }
+
+    /// Get the retry count
+    pub async fn retry_count(&self) -> u8 {
+        *self.retry_count.read().await
+    }
+
+    /// Set the retry count
+    pub async fn set_retry_count(&self, count: u8) {
+        *self.retry_count.write().await = count;
+    }
+
+    /// Send a message through the given transport with retries
+    ///
+    /// The send status transitions Sending -> Success on delivery, or
+    /// Sending -> Failed once the initial attempt and all retries are
+    /// exhausted. The final status is returned.
+    pub async fn send(&self, transport: &dyn SmsTransport, message: &str) -> SmsSendStatus {
+        if !self.is_enabled().await {
+            self.mark_send_failed().await;
+            return self.send_status().await;
+        }
+
+        let destination = self.phone_number().await;
+        let attempts = self.retry_count().await as u32 + 1;
+
+        self.prepare_send().await;
+        for _ in 0..attempts {
+            if transport.send_sms(&destination, message).await.is_ok() {
+                self.mark_send_success().await;
+                return self.send_status().await;
+            }
+        }
+
+        self.mark_send_failed().await;
+        self.send_status().await
+    }
 }
 
 #[async_trait]
@@ -675,4 +725,85 @@ mod tests {
         let result = sms.invoke_method(1, None, None, None).await;
         assert!(result.is_err());
     }
+
+    /// Transport that fails a fixed number of times before succeeding
+    struct FlakyTransport {
+        failures_left: std::sync::atomic::AtomicU32,
+        attempts: std::sync::atomic::AtomicU32,
+    }
+
+    impl FlakyTransport {
+        fn new(failures: u32) -> Self {
+            Self {
+                failures_left: std::sync::atomic::AtomicU32::new(failures),
+                attempts: std::sync::atomic::AtomicU32::new(0),
+            }
+        }
+
+        fn attempts(&self) -> u32 {
+            self.attempts.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl SmsTransport for FlakyTransport {
+        async fn send_sms(&self, _destination: &str, _message: &str) -> DlmsResult<()> {
+            self.attempts
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self
+                .failures_left
+                .fetch_update(
+                    std::sync::atomic::Ordering::SeqCst,
+                    std::sync::atomic::Ordering::SeqCst,
+                    |v| v.checked_sub(1),
+                )
+                .is_ok()
+            {
+                Err(DlmsError::InvalidData("SMS network unavailable".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sms_controller_send_succeeds_on_second_attempt() {
+        let sms = SmsController::with_default_obis();
+        sms.set_sms_enabled(true).await;
+        sms.set_phone_number("+1234567890".to_string()).await;
+        sms.set_retry_count(3).await;
+
+        let transport = FlakyTransport::new(1);
+        let status = sms.send(&transport, "hello").await;
+
+        assert_eq!(status, SmsSendStatus::Success);
+        assert_eq!(transport.attempts(), 2);
+        assert_eq!(sms.send_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_sms_controller_send_exhausts_retries() {
+        let sms = SmsController::with_default_obis();
+        sms.set_sms_enabled(true).await;
+        sms.set_phone_number("+1234567890".to_string()).await;
+        sms.set_retry_count(2).await;
+
+        let transport = FlakyTransport::new(10);
+        let status = sms.send(&transport, "hello").await;
+
+        assert_eq!(status, SmsSendStatus::Failed);
+        // Initial attempt plus two retries
+        assert_eq!(transport.attempts(), 3);
+        assert_eq!(sms.send_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_sms_controller_send_disabled() {
+        let sms = SmsController::with_default_obis();
+        let transport = FlakyTransport::new(0);
+        let status = sms.send(&transport, "hello").await;
+
+        assert_eq!(status, SmsSendStatus::Failed);
+        assert_eq!(transport.attempts(), 0);
+    }
 }
@@ -1,44 +1,107 @@
 //! Authentication functionality for DLMS/COSEM
 
+use crate::encryption::AesGcmEncryption;
 use crate::error::{DlmsError, DlmsResult};
-use hmac::{Hmac, Mac};
-use sha2::Sha256;
-
-type HmacSha256 = Hmac<Sha256>;
+use crate::suite::{EncryptionMechanism, SecuritySuite};
 
 /// GMAC authentication
+///
+/// GMAC is AES-128-GCM with an empty plaintext: the data to authenticate
+/// is fed in as additional authenticated data and the output is just the
+/// authentication tag.
 pub struct GmacAuth {
     key: Vec<u8>,
+    /// Authentication tag length in bytes (12 or 16)
+    tag_len: usize,
 }
 
 impl GmacAuth {
+    /// DLMS-standard truncated tag length in bytes
+    pub const DEFAULT_TAG_LEN: usize = 12;
+    /// Full (untruncated) tag length in bytes
+    pub const FULL_TAG_LEN: usize = 16;
+
     /// Create a new GMAC authentication context
+    ///
+    /// Uses the DLMS-standard 12-byte truncated tag.
     pub fn new(key: &[u8]) -> Self {
         Self {
             key: key.to_vec(),
+            tag_len: Self::DEFAULT_TAG_LEN,
+        }
+    }
+
+    /// Create a new GMAC authentication context with an explicit tag length
+    ///
+    /// Supports the DLMS-standard truncated 12-byte tag and the full
+    /// 16-byte tag.
+    ///
+    /// # Errors
+    /// Returns `DlmsError::Security` for any other tag length
+    pub fn new_with_tag_len(key: &[u8], tag_len: usize) -> DlmsResult<Self> {
+        if tag_len != Self::DEFAULT_TAG_LEN && tag_len != Self::FULL_TAG_LEN {
+            return Err(DlmsError::Security(format!(
+                "Unsupported GMAC tag length {} (supported: {} or {} bytes)",
+                tag_len,
+                Self::DEFAULT_TAG_LEN,
+                Self::FULL_TAG_LEN
+            )));
+        }
+        Ok(Self {
+            key: key.to_vec(),
+            tag_len,
+        })
+    }
+
+    /// Create a new GMAC authentication context validated against a suite
+    ///
+    /// In addition to the checks of [`GmacAuth::new_with_tag_len`], the
+    /// requested length is validated against the suite's encryption
+    /// mechanism: a suite without an encryption mechanism cannot carry a
+    /// GMAC at all.
+    pub fn new_for_suite(key: &[u8], tag_len: usize, suite: &SecuritySuite) -> DlmsResult<Self> {
+        match suite.encryption_mechanism() {
+            EncryptionMechanism::AesGcm128 => Self::new_with_tag_len(key, tag_len),
+            EncryptionMechanism::None => Err(DlmsError::Security(
+                "Security suite without an encryption mechanism does not support GMAC".to_string(),
+            )),
         }
     }
 
+    /// Get the configured tag length in bytes
+    pub fn tag_len(&self) -> usize {
+        self.tag_len
+    }
+
     /// Generate GMAC for data
-    pub fn generate_gmac(&self, data: &[u8], aad: &[u8]) -> DlmsResult<Vec<u8>> {
-        // GMAC is essentially AES-GCM authentication tag
-        // For DLMS, we use HMAC-SHA256 as a simplified implementation
-        // In production, this should use actual AES-GCM GMAC
-        
-        let mut mac = HmacSha256::new_from_slice(&self.key)
-            .map_err(|e| DlmsError::Security(format!("Failed to create HMAC: {}", e)))?;
-        
-        mac.update(aad);
-        mac.update(data);
-        
-        let result = mac.finalize();
-        Ok(result.into_bytes().to_vec())
+    ///
+    /// The IV is the 12-byte initialization vector (system title followed
+    /// by the frame counter in DLMS). The returned tag is truncated to the
+    /// configured tag length.
+    pub fn generate_gmac(&self, data: &[u8], iv: &[u8]) -> DlmsResult<Vec<u8>> {
+        let cipher = AesGcmEncryption::new(&self.key)?;
+
+        // AES-GCM with an empty plaintext emits only the 16-byte tag
+        let mut tag = cipher.encrypt_with_nonce(&[], iv, data)?;
+        tag.truncate(self.tag_len);
+        Ok(tag)
     }
 
     /// Verify GMAC
-    pub fn verify_gmac(&self, data: &[u8], aad: &[u8], gmac: &[u8]) -> DlmsResult<bool> {
-        let computed = self.generate_gmac(data, aad)?;
-        Ok(computed == gmac)
+    ///
+    /// The received tag must match the configured tag length exactly; a
+    /// tag of a different length never verifies. The comparison runs in
+    /// constant time to avoid leaking how many tag bytes matched.
+    pub fn verify_gmac(&self, data: &[u8], iv: &[u8], gmac: &[u8]) -> DlmsResult<bool> {
+        if gmac.len() != self.tag_len {
+            return Ok(false);
+        }
+        let computed = self.generate_gmac(data, iv)?;
+        let mut difference = 0u8;
+        for (a, b) in computed.iter().zip(gmac.iter()) {
+            difference |= a ^ b;
+        }
+        Ok(difference == 0)
     }
 }
 
@@ -99,14 +162,14 @@ impl Hls5GmacAuth {
     /// Generate authentication tag
     pub fn generate_auth_tag(&self, data: &[u8], system_title: &[u8], frame_counter: u32) -> DlmsResult<Vec<u8>> {
         // HLS5-GMAC uses AES-GCM authentication
-        // Build AAD: system_title + frame_counter
-        let mut aad = Vec::new();
-        aad.extend_from_slice(system_title);
-        aad.extend_from_slice(&frame_counter.to_be_bytes());
+        // Build IV: system_title + frame_counter
+        let mut iv = Vec::with_capacity(12);
+        iv.extend_from_slice(system_title);
+        iv.extend_from_slice(&frame_counter.to_be_bytes());
 
         // Use authentication key for GMAC
         let gmac = GmacAuth::new(&self.authentication_key);
-        gmac.generate_gmac(data, &aad)
+        gmac.generate_gmac(data, &iv)
     }
 
     /// Verify authentication tag
@@ -126,15 +189,85 @@ impl Hls5GmacAuth {
 mod tests {
     use super::*;
 
+    /// System title plus frame counter, as built by the ciphering layer
+    const TEST_IV: [u8; 12] = [0x4D, 0x4D, 0x4D, 0x00, 0x00, 0xBC, 0x61, 0x4E, 0x00, 0x00, 0x00, 0x01];
+
     #[test]
     fn test_gmac_auth() {
         let key = [0u8; 16];
         let auth = GmacAuth::new(&key);
         let data = b"test data";
-        let aad = b"additional data";
 
-        let gmac = auth.generate_gmac(data, aad).unwrap();
-        assert!(auth.verify_gmac(data, aad, &gmac).unwrap());
+        let gmac = auth.generate_gmac(data, &TEST_IV).unwrap();
+        assert_eq!(gmac.len(), GmacAuth::DEFAULT_TAG_LEN);
+        assert!(auth.verify_gmac(data, &TEST_IV, &gmac).unwrap());
+    }
+
+    #[test]
+    fn test_gmac_full_tag_roundtrip() {
+        let key = [0u8; 16];
+        let auth = GmacAuth::new_with_tag_len(&key, 16).unwrap();
+        let data = b"test data";
+
+        let gmac = auth.generate_gmac(data, &TEST_IV).unwrap();
+        assert_eq!(gmac.len(), GmacAuth::FULL_TAG_LEN);
+        assert!(auth.verify_gmac(data, &TEST_IV, &gmac).unwrap());
+    }
+
+    #[test]
+    fn test_gmac_matches_aes_gcm_tag() {
+        let key = [0x42u8; 16];
+        let data = b"\x10\x00\x00\x00\x01challenge";
+
+        // The full-length GMAC is the AES-GCM tag over empty plaintext
+        let cipher = AesGcmEncryption::new(&key).unwrap();
+        let tag = cipher.encrypt_with_nonce(&[], &TEST_IV, data).unwrap();
+
+        let auth = GmacAuth::new_with_tag_len(&key, 16).unwrap();
+        assert_eq!(auth.generate_gmac(data, &TEST_IV).unwrap(), tag);
+    }
+
+    #[test]
+    fn test_gmac_rejects_invalid_iv_length() {
+        let auth = GmacAuth::new(&[0u8; 16]);
+        assert!(auth.generate_gmac(b"test data", b"short iv").is_err());
+    }
+
+    #[test]
+    fn test_gmac_rejects_unsupported_tag_length() {
+        let key = [0u8; 16];
+        assert!(GmacAuth::new_with_tag_len(&key, 8).is_err());
+        assert!(GmacAuth::new_with_tag_len(&key, 32).is_err());
+    }
+
+    #[test]
+    fn test_gmac_mismatched_tag_length_fails_verify() {
+        let key = [0u8; 16];
+        let data = b"test data";
+
+        // A full-length tag never verifies against a truncated-tag context,
+        // even though the first 12 bytes agree
+        let full = GmacAuth::new_with_tag_len(&key, 16).unwrap();
+        let tag = full.generate_gmac(data, &TEST_IV).unwrap();
+        let truncated = GmacAuth::new(&key);
+        assert!(!truncated.verify_gmac(data, &TEST_IV, &tag).unwrap());
+        assert!(!full.verify_gmac(data, &TEST_IV, &tag[..12]).unwrap());
+    }
+
+    #[test]
+    fn test_gmac_tag_length_validated_against_suite() {
+        let key = [0u8; 16];
+        let gcm_suite = SecuritySuite::builder()
+            .set_encryption_mechanism(EncryptionMechanism::AesGcm128)
+            .build()
+            .unwrap();
+        assert!(GmacAuth::new_for_suite(&key, 12, &gcm_suite).is_ok());
+        assert!(GmacAuth::new_for_suite(&key, 16, &gcm_suite).is_ok());
+        assert!(GmacAuth::new_for_suite(&key, 8, &gcm_suite).is_err());
+
+        // A suite without encryption cannot carry a GMAC
+        let plain_suite = SecuritySuite::default();
+        assert!(GmacAuth::new_for_suite(&key, 12, &plain_suite).is_err());
     }
 
     #[test]
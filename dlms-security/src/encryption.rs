@@ -1,14 +1,20 @@
 //! Encryption functionality for DLMS/COSEM
 
 use crate::error::{DlmsError, DlmsResult};
+use aes::Aes128;
 use aes_gcm::{
     aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
     Aes128Gcm, Key, Nonce,
 };
+use cipher::BlockEncrypt;
+use ghash::{universal_hash::UniversalHash, GHash};
 
 /// AES-GCM encryption context
 pub struct AesGcmEncryption {
     cipher: Aes128Gcm,
+    /// Raw key, kept for the streaming API which drives AES-CTR and GHASH
+    /// itself (the one-shot AEAD interface does not expose it)
+    key: [u8; 16],
 }
 
 impl AesGcmEncryption {
@@ -21,10 +27,13 @@ impl AesGcmEncryption {
             )));
         }
 
+        let mut raw_key = [0u8; 16];
+        raw_key.copy_from_slice(key);
+
         let key = Key::<Aes128Gcm>::from_slice(key);
         let cipher = Aes128Gcm::new(key);
 
-        Ok(Self { cipher })
+        Ok(Self { cipher, key: raw_key })
     }
 
     /// Encrypt data with AES-GCM using a random nonce
@@ -116,6 +125,212 @@ impl AesGcmEncryption {
 
         Ok(plaintext)
     }
+
+    /// Start a streaming encryption producing the same output as
+    /// [`encrypt_with_nonce`](Self::encrypt_with_nonce)
+    ///
+    /// Large ciphered block transfers would otherwise need the whole
+    /// plaintext in memory at once. Feed chunks of any size through
+    /// [`AesGcmStreamEncryptor::update`] and collect the tag from
+    /// [`AesGcmStreamEncryptor::finalize`]; the concatenated ciphertext
+    /// followed by the tag is byte-identical to the one-shot result.
+    pub fn encrypt_stream(&self, nonce: &[u8], aad: &[u8]) -> DlmsResult<AesGcmStreamEncryptor> {
+        Ok(AesGcmStreamEncryptor {
+            core: GcmStreamCore::new(&self.key, nonce, aad)?,
+        })
+    }
+
+    /// Start a streaming decryption, the counterpart of
+    /// [`encrypt_stream`](Self::encrypt_stream)
+    ///
+    /// Feed ciphertext chunks (without the tag) through
+    /// [`AesGcmStreamDecryptor::update`], then verify authenticity with
+    /// [`AesGcmStreamDecryptor::finalize`]. Note that plaintext is
+    /// released before the tag is checked — callers must discard it if
+    /// `finalize` fails.
+    pub fn decrypt_stream(&self, nonce: &[u8], aad: &[u8]) -> DlmsResult<AesGcmStreamDecryptor> {
+        Ok(AesGcmStreamDecryptor {
+            core: GcmStreamCore::new(&self.key, nonce, aad)?,
+        })
+    }
+}
+
+/// Shared AES-CTR keystream and GHASH state for the streaming API
+///
+/// Implements GCM per NIST SP 800-38D: the keystream starts at inc32(J0),
+/// GHASH runs over the zero-padded AAD, the zero-padded ciphertext and a
+/// final length block, and the tag is E(K, J0) XOR GHASH. Encryption and
+/// decryption differ only in which side of the XOR feeds GHASH.
+struct GcmStreamCore {
+    cipher: Aes128,
+    ghash: GHash,
+    /// Pre-counter block J0, consumed when computing the tag
+    j0: [u8; 16],
+    /// Current CTR counter block
+    counter: [u8; 16],
+    /// Unconsumed keystream bytes from the current counter block
+    keystream: [u8; 16],
+    keystream_used: usize,
+    /// Ciphertext bytes not yet forming a full GHASH block
+    ghash_partial: Vec<u8>,
+    aad_len: u64,
+    msg_len: u64,
+}
+
+impl GcmStreamCore {
+    fn new(key: &[u8; 16], nonce: &[u8], aad: &[u8]) -> DlmsResult<Self> {
+        if nonce.len() != 12 {
+            return Err(DlmsError::Security(format!(
+                "Invalid nonce length: expected 12 bytes, got {}",
+                nonce.len()
+            )));
+        }
+
+        let cipher = Aes128::new(key.into());
+
+        // Hash subkey H = E(K, 0^128)
+        let mut h = [0u8; 16];
+        cipher.encrypt_block((&mut h).into());
+        let mut ghash = GHash::new(&h.into());
+        ghash.update_padded(aad);
+
+        // 96-bit nonce: J0 = nonce || 0x00000001
+        let mut j0 = [0u8; 16];
+        j0[..12].copy_from_slice(nonce);
+        j0[15] = 1;
+
+        Ok(Self {
+            cipher,
+            ghash,
+            j0,
+            counter: j0,
+            keystream: [0u8; 16],
+            keystream_used: 16, // Force a fresh block on first use
+            ghash_partial: Vec::with_capacity(16),
+            aad_len: aad.len() as u64,
+            msg_len: 0,
+        })
+    }
+
+    /// Increment the rightmost 32 bits of the counter block (inc32)
+    fn increment_counter(&mut self) {
+        let mut value = u32::from_be_bytes([
+            self.counter[12],
+            self.counter[13],
+            self.counter[14],
+            self.counter[15],
+        ]);
+        value = value.wrapping_add(1);
+        self.counter[12..].copy_from_slice(&value.to_be_bytes());
+    }
+
+    /// XOR `data` with the keystream, continuing across chunk boundaries
+    fn apply_keystream(&mut self, data: &[u8]) -> Vec<u8> {
+        let mut output = Vec::with_capacity(data.len());
+        for &byte in data {
+            if self.keystream_used == 16 {
+                self.increment_counter();
+                self.keystream = self.counter;
+                self.cipher.encrypt_block((&mut self.keystream).into());
+                self.keystream_used = 0;
+            }
+            output.push(byte ^ self.keystream[self.keystream_used]);
+            self.keystream_used += 1;
+        }
+        self.msg_len += data.len() as u64;
+        output
+    }
+
+    /// Feed ciphertext bytes into GHASH, buffering partial blocks
+    fn absorb_ciphertext(&mut self, mut data: &[u8]) {
+        if !self.ghash_partial.is_empty() {
+            let needed = 16 - self.ghash_partial.len();
+            let take = needed.min(data.len());
+            self.ghash_partial.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            if self.ghash_partial.len() == 16 {
+                let block = ghash::Block::clone_from_slice(&self.ghash_partial);
+                self.ghash.update(&[block]);
+                self.ghash_partial.clear();
+            }
+        }
+
+        let full_blocks = data.len() / 16 * 16;
+        for chunk in data[..full_blocks].chunks_exact(16) {
+            self.ghash.update(&[ghash::Block::clone_from_slice(chunk)]);
+        }
+        self.ghash_partial.extend_from_slice(&data[full_blocks..]);
+    }
+
+    /// Close GHASH with the length block and produce the tag
+    fn compute_tag(mut self) -> [u8; 16] {
+        let partial = std::mem::take(&mut self.ghash_partial);
+        self.ghash.update_padded(&partial);
+
+        let mut length_block = [0u8; 16];
+        length_block[..8].copy_from_slice(&(self.aad_len * 8).to_be_bytes());
+        length_block[8..].copy_from_slice(&(self.msg_len * 8).to_be_bytes());
+        self.ghash.update(&[length_block.into()]);
+
+        let hash = self.ghash.finalize();
+
+        let mut tag = self.j0;
+        self.cipher.encrypt_block((&mut tag).into());
+        for (tag_byte, hash_byte) in tag.iter_mut().zip(hash.iter()) {
+            *tag_byte ^= hash_byte;
+        }
+        tag
+    }
+}
+
+/// Streaming AES-GCM encryption state, created by
+/// [`AesGcmEncryption::encrypt_stream`]
+pub struct AesGcmStreamEncryptor {
+    core: GcmStreamCore,
+}
+
+impl AesGcmStreamEncryptor {
+    /// Encrypt the next plaintext chunk
+    pub fn update(&mut self, plaintext: &[u8]) -> Vec<u8> {
+        let ciphertext = self.core.apply_keystream(plaintext);
+        self.core.absorb_ciphertext(&ciphertext);
+        ciphertext
+    }
+
+    /// Finish the stream and return the 16-byte authentication tag
+    pub fn finalize(self) -> Vec<u8> {
+        self.core.compute_tag().to_vec()
+    }
+}
+
+/// Streaming AES-GCM decryption state, created by
+/// [`AesGcmEncryption::decrypt_stream`]
+pub struct AesGcmStreamDecryptor {
+    core: GcmStreamCore,
+}
+
+impl AesGcmStreamDecryptor {
+    /// Decrypt the next ciphertext chunk (tag excluded)
+    pub fn update(&mut self, ciphertext: &[u8]) -> Vec<u8> {
+        self.core.absorb_ciphertext(ciphertext);
+        self.core.apply_keystream(ciphertext)
+    }
+
+    /// Verify the authentication tag in constant time
+    pub fn finalize(self, tag: &[u8]) -> DlmsResult<()> {
+        let computed = self.core.compute_tag();
+        if tag.len() != computed.len() {
+            return Err(DlmsError::Security("Decryption failed: aead::Error".to_string()));
+        }
+        let mut difference = 0u8;
+        for (a, b) in computed.iter().zip(tag.iter()) {
+            difference |= a ^ b;
+        }
+        if difference != 0 {
+            return Err(DlmsError::Security("Decryption failed: aead::Error".to_string()));
+        }
+        Ok(())
+    }
 }
 
 /// Security control byte for DLMS APDU
@@ -174,6 +389,21 @@ impl SecurityControl {
     pub fn is_key_set(&self) -> bool {
         (self.byte & 0x40) != 0
     }
+
+    /// Set or clear the compression bit (bit 7)
+    pub fn with_compression(mut self, compressed: bool) -> Self {
+        if compressed {
+            self.byte |= 0x80;
+        } else {
+            self.byte &= 0x7F;
+        }
+        self
+    }
+
+    /// Check if the payload is compressed
+    pub fn is_compressed(&self) -> bool {
+        (self.byte & 0x80) != 0
+    }
 }
 
 #[cfg(test)]
@@ -193,6 +423,55 @@ mod tests {
         assert_eq!(plaintext, decrypted.as_slice());
     }
 
+    #[test]
+    fn test_stream_matches_one_shot_for_large_payload() {
+        let key = [0x42u8; 16];
+        let nonce = [0x01u8; 12];
+        let aad = b"system-title+frame-counter";
+        let plaintext: Vec<u8> = (0..65536u32).map(|i| (i % 251) as u8).collect();
+
+        let enc = AesGcmEncryption::new(&key).unwrap();
+        let one_shot = enc.encrypt_with_nonce(&plaintext, &nonce, aad).unwrap();
+
+        // Stream in deliberately awkward chunk sizes
+        let mut streamed = Vec::new();
+        let mut encryptor = enc.encrypt_stream(&nonce, aad).unwrap();
+        for chunk in plaintext.chunks(1000) {
+            streamed.extend_from_slice(&encryptor.update(chunk));
+        }
+        streamed.extend_from_slice(&encryptor.finalize());
+
+        assert_eq!(streamed, one_shot);
+    }
+
+    #[test]
+    fn test_stream_decrypt_roundtrip_and_tag_check() {
+        let key = [0x42u8; 16];
+        let nonce = [0x02u8; 12];
+        let aad = b"aad";
+        let plaintext = b"streaming roundtrip payload".to_vec();
+
+        let enc = AesGcmEncryption::new(&key).unwrap();
+        let mut encryptor = enc.encrypt_stream(&nonce, aad).unwrap();
+        let ciphertext = encryptor.update(&plaintext);
+        let tag = encryptor.finalize();
+
+        let mut decryptor = enc.decrypt_stream(&nonce, aad).unwrap();
+        let mut recovered = Vec::new();
+        for chunk in ciphertext.chunks(7) {
+            recovered.extend_from_slice(&decryptor.update(chunk));
+        }
+        assert_eq!(recovered, plaintext);
+        decryptor.finalize(&tag).unwrap();
+
+        // A corrupted tag must be rejected
+        let mut bad_tag = tag.clone();
+        bad_tag[0] ^= 0x01;
+        let mut decryptor = enc.decrypt_stream(&nonce, aad).unwrap();
+        decryptor.update(&ciphertext);
+        assert!(decryptor.finalize(&bad_tag).is_err());
+    }
+
     #[test]
     fn test_security_control() {
         let ctrl = SecurityControl::new(0, true, true, false);
@@ -152,6 +152,7 @@
 //! - [`validation`] - Security parameter validation
 //! - [`key_management`] - Key management and lifecycle
 //! - [`key_agreement`] - Key agreement protocols
+//! - [`signing`] - ECDSA digital signatures (Suites 1 and 2)
 //!
 //! # Implementation Status
 //!
@@ -182,6 +183,7 @@
 //! - [x] Security Suite 1: Low-Level authentication
 //! - [x] Security Suite 2: HLS5-GMAC
 //! - [x] Security Suite 12: AES-GCM encryption
+//! - [x] ECDSA digital signatures for Suites 1 and 2
 //!
 //! # References
 //!
@@ -203,6 +205,7 @@ pub mod suite_negotiation;
 pub mod validation;
 pub mod key_management;
 pub mod key_agreement;
+pub mod signing;
 
 pub use error::{DlmsError, DlmsResult};
 pub use suite::{
@@ -213,8 +216,14 @@ pub use authentication::{GmacAuth, LowAuth, Hls5GmacAuth};
 pub use auth_flow::{AuthenticationFlow, AuthenticationMechanism, AuthenticationState};
 pub use utils::{KeyId, generate_aes128_key, wrap_aes_rfc3394_key, unwrap_aes_rfc3394_key};
 pub use constants::*;
-pub use xdlms::{SystemTitle, FrameCounter, KeyDerivationFunction, XdlmsContext};
-pub use xdlms_frame::{EncryptedFrameBuilder, EncryptedFrameParser};
+pub use xdlms::{
+    SystemTitle, FrameCounter, FrameCounterStore, MemoryFrameCounterStore,
+    KeyDerivationFunction, XdlmsContext,
+};
+pub use xdlms_frame::{
+    EncryptedFrameBuilder, EncryptedFrameParser, GloCiphering, GENERAL_GLO_CIPHERING_TAG,
+    GLO_INITIATE_REQUEST_TAG, GLO_INITIATE_RESPONSE_TAG,
+};
 pub use suite_negotiation::{
     SecuritySuiteNegotiator, SuiteId, SuiteProposal, NegotiationState,
     NegotiationTimeout, NegotiationError, NegotiationParameters,
@@ -232,3 +241,4 @@ pub use key_agreement::{
     KeyAgreement, KeyAgreementProtocol, KeyAgreementRole, KeyAgreementState,
     KeyAgreementMessage, SharedSecret, PskConfig, PskKeyAgreement, KeyAgreementResult,
 };
+pub use signing::DigitalSignature;
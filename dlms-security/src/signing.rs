@@ -0,0 +1,276 @@
+//! ECDSA Digital Signatures
+//!
+//! This module implements the digital signature mechanism of Security
+//! Suites 1 and 2 for protecting APDUs carried in `general-signing` APDUs.
+//!
+//! # Overview
+//!
+//! The Green Book assigns one signature algorithm to each suite:
+//!
+//! - **Suite 1**: ECDSA over P-256 with SHA-256 (64-byte signatures)
+//! - **Suite 2**: ECDSA over P-384 with SHA-384 (96-byte signatures)
+//!
+//! Signatures are exchanged in the plain `r || s` form used on the wire,
+//! not ASN.1 DER. Suite 0 defines no signature mechanism.
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use dlms_security::signing::DigitalSignature;
+//!
+//! # fn main() -> dlms_security::DlmsResult<()> {
+//! let apdu = vec![0xC0, 0x01, 0xC1];
+//!
+//! // Meter side: generate a signing key and sign an outgoing APDU
+//! let mut signer = DigitalSignature::new(2)?;
+//! let public_key = signer.generate_signing_key()?;
+//! let signature = signer.sign(&apdu)?;
+//!
+//! // Utility side: verify against the meter's public key
+//! let mut verifier = DigitalSignature::new(2)?;
+//! verifier.set_public_key(public_key)?;
+//! assert!(verifier.verify(&apdu, &signature)?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{DlmsError, DlmsResult};
+use ring::rand::SystemRandom;
+use ring::signature::{
+    EcdsaKeyPair, EcdsaSigningAlgorithm, EcdsaVerificationAlgorithm, KeyPair, UnparsedPublicKey,
+    ECDSA_P256_SHA256_FIXED, ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P384_SHA384_FIXED,
+    ECDSA_P384_SHA384_FIXED_SIGNING,
+};
+
+/// ECDSA signer/verifier tied to a security suite id
+///
+/// Holds up to two independent keys: a private signing key for outgoing
+/// APDUs and the peer's public key for verifying incoming ones. Either
+/// side may be left unconfigured when only one direction is used.
+pub struct DigitalSignature {
+    /// Security suite id (1 or 2) selecting curve and hash
+    suite_id: u8,
+    /// Private key for signing outgoing APDUs
+    signing_key: Option<EcdsaKeyPair>,
+    /// Peer public key as an uncompressed point (0x04 || X || Y)
+    public_key: Option<Vec<u8>>,
+    rng: SystemRandom,
+}
+
+impl DigitalSignature {
+    /// Create a digital signature context for the given security suite
+    ///
+    /// # Errors
+    /// Returns `DlmsError::Security` for suite ids other than 1 and 2,
+    /// since no other suite defines a digital signature mechanism.
+    pub fn new(suite_id: u8) -> DlmsResult<Self> {
+        match suite_id {
+            1 | 2 => Ok(Self {
+                suite_id,
+                signing_key: None,
+                public_key: None,
+                rng: SystemRandom::new(),
+            }),
+            _ => Err(DlmsError::Security(format!(
+                "Security suite {} does not define a digital signature mechanism",
+                suite_id
+            ))),
+        }
+    }
+
+    /// Get the security suite id
+    pub fn suite_id(&self) -> u8 {
+        self.suite_id
+    }
+
+    /// Length in bytes of an `r || s` signature for this suite
+    pub fn signature_length(&self) -> usize {
+        match self.suite_id {
+            1 => 64, // P-256: two 32-byte scalars
+            _ => 96, // P-384: two 48-byte scalars
+        }
+    }
+
+    /// Length in bytes of an uncompressed public key point for this suite
+    fn public_key_length(&self) -> usize {
+        // 0x04 marker plus the X and Y coordinates
+        1 + self.signature_length()
+    }
+
+    fn signing_algorithm(&self) -> &'static EcdsaSigningAlgorithm {
+        match self.suite_id {
+            1 => &ECDSA_P256_SHA256_FIXED_SIGNING,
+            _ => &ECDSA_P384_SHA384_FIXED_SIGNING,
+        }
+    }
+
+    fn verification_algorithm(&self) -> &'static EcdsaVerificationAlgorithm {
+        match self.suite_id {
+            1 => &ECDSA_P256_SHA256_FIXED,
+            _ => &ECDSA_P384_SHA384_FIXED,
+        }
+    }
+
+    /// Generate a fresh signing key pair on the suite's curve
+    ///
+    /// The private key is installed for `sign()` and the matching public
+    /// key is returned as an uncompressed point (0x04 || X || Y) for the
+    /// peer to verify with.
+    pub fn generate_signing_key(&mut self) -> DlmsResult<Vec<u8>> {
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(self.signing_algorithm(), &self.rng)
+            .map_err(|_| DlmsError::Security("Failed to generate ECDSA key pair".to_string()))?;
+        self.set_signing_key(pkcs8.as_ref())?;
+        self.signing_public_key()
+    }
+
+    /// Install a private signing key from PKCS#8 bytes
+    ///
+    /// The key must match the suite's curve; a P-256 key is rejected for
+    /// suite 2 and vice versa.
+    pub fn set_signing_key(&mut self, pkcs8: &[u8]) -> DlmsResult<()> {
+        let key_pair = EcdsaKeyPair::from_pkcs8(self.signing_algorithm(), pkcs8, &self.rng)
+            .map_err(|e| {
+                DlmsError::Security(format!(
+                    "Invalid ECDSA signing key for security suite {}: {}",
+                    self.suite_id, e
+                ))
+            })?;
+        self.signing_key = Some(key_pair);
+        Ok(())
+    }
+
+    /// Install the peer public key for `verify()`
+    ///
+    /// Expects an uncompressed point (0x04 || X || Y) on the suite's curve.
+    pub fn set_public_key(&mut self, public_key: Vec<u8>) -> DlmsResult<()> {
+        if public_key.len() != self.public_key_length() || public_key[0] != 0x04 {
+            return Err(DlmsError::Security(format!(
+                "Public key for security suite {} must be an uncompressed point of {} bytes",
+                self.suite_id,
+                self.public_key_length()
+            )));
+        }
+        self.public_key = Some(public_key);
+        Ok(())
+    }
+
+    /// Public key matching the installed signing key
+    ///
+    /// Returned as an uncompressed point (0x04 || X || Y), suitable for
+    /// passing to the peer's `set_public_key()`.
+    pub fn signing_public_key(&self) -> DlmsResult<Vec<u8>> {
+        let key_pair = self.signing_key.as_ref().ok_or_else(|| {
+            DlmsError::Security("No signing key configured".to_string())
+        })?;
+        Ok(key_pair.public_key().as_ref().to_vec())
+    }
+
+    /// Sign an APDU with the configured private key
+    ///
+    /// Returns the signature as `r || s` (64 bytes for suite 1, 96 bytes
+    /// for suite 2), ready to embed in a `general-signing` APDU.
+    ///
+    /// # Errors
+    /// Returns `DlmsError::Security` when no signing key is configured.
+    pub fn sign(&self, apdu: &[u8]) -> DlmsResult<Vec<u8>> {
+        let key_pair = self.signing_key.as_ref().ok_or_else(|| {
+            DlmsError::Security("No signing key configured".to_string())
+        })?;
+        let signature = key_pair
+            .sign(&self.rng, apdu)
+            .map_err(|_| DlmsError::Security("ECDSA signing failed".to_string()))?;
+        Ok(signature.as_ref().to_vec())
+    }
+
+    /// Verify an APDU signature against the configured peer public key
+    ///
+    /// # Returns
+    /// `Ok(true)` when the signature is valid for the APDU, `Ok(false)`
+    /// when it is not (tampered APDU, wrong key, or malformed signature).
+    ///
+    /// # Errors
+    /// Returns `DlmsError::Security` when no public key is configured.
+    pub fn verify(&self, apdu: &[u8], signature: &[u8]) -> DlmsResult<bool> {
+        let public_key = self.public_key.as_deref().ok_or_else(|| {
+            DlmsError::Security("No public key configured".to_string())
+        })?;
+        let key = UnparsedPublicKey::new(self.verification_algorithm(), public_key);
+        Ok(key.verify(apdu, signature).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A signer with a generated key and a verifier holding its public key
+    fn signer_and_verifier(suite_id: u8) -> (DigitalSignature, DigitalSignature) {
+        let mut signer = DigitalSignature::new(suite_id).unwrap();
+        let public_key = signer.generate_signing_key().unwrap();
+        let mut verifier = DigitalSignature::new(suite_id).unwrap();
+        verifier.set_public_key(public_key).unwrap();
+        (signer, verifier)
+    }
+
+    #[test]
+    fn test_valid_signature_verifies() {
+        let apdu = b"\xC0\x01\xC1\x00\x03\x01\x00\x01\x08\x00\xFF\x02\x00";
+        for suite_id in [1u8, 2] {
+            let (signer, verifier) = signer_and_verifier(suite_id);
+
+            let signature = signer.sign(apdu).unwrap();
+            assert_eq!(signature.len(), signer.signature_length());
+            assert!(verifier.verify(apdu, &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_tampered_apdu_fails_verification() {
+        let apdu = b"\xC0\x01\xC1\x00\x03\x01\x00\x01\x08\x00\xFF\x02\x00".to_vec();
+        let (signer, verifier) = signer_and_verifier(2);
+        let signature = signer.sign(&apdu).unwrap();
+
+        // Flipping a single APDU byte invalidates the signature
+        let mut tampered = apdu.clone();
+        tampered[4] ^= 0x01;
+        assert!(!verifier.verify(&tampered, &signature).unwrap());
+
+        // A corrupted signature fails against the original APDU too
+        let mut corrupted = signature.clone();
+        corrupted[0] ^= 0x01;
+        assert!(!verifier.verify(&apdu, &corrupted).unwrap());
+    }
+
+    #[test]
+    fn test_signature_from_wrong_key_fails() {
+        let apdu = b"\xC4\x01\xC1\x00\x06\x00\x00\x30\x39";
+        let (other_signer, _) = signer_and_verifier(1);
+        let (_, verifier) = signer_and_verifier(1);
+
+        let signature = other_signer.sign(apdu).unwrap();
+        assert!(!verifier.verify(apdu, &signature).unwrap());
+    }
+
+    #[test]
+    fn test_suite_without_signature_mechanism_rejected() {
+        for suite_id in [0u8, 3, 12] {
+            assert!(DigitalSignature::new(suite_id).is_err());
+        }
+    }
+
+    #[test]
+    fn test_key_material_validation() {
+        let mut suite1 = DigitalSignature::new(1).unwrap();
+        let mut suite2 = DigitalSignature::new(2).unwrap();
+
+        // A P-256 public key is too short for suite 2
+        let p256_public = suite1.generate_signing_key().unwrap();
+        assert_eq!(p256_public.len(), 65);
+        assert!(suite2.set_public_key(p256_public).is_err());
+
+        // Signing and verifying without configured keys fail
+        let unconfigured = DigitalSignature::new(1).unwrap();
+        assert!(unconfigured.sign(b"\x01").is_err());
+        assert!(unconfigured.verify(b"\x01", &[0u8; 64]).is_err());
+    }
+}
@@ -42,6 +42,18 @@ impl SecurityPolicy {
     pub fn is_encrypted(&self) -> bool {
         matches!(self, SecurityPolicy::Encrypted | SecurityPolicy::AuthenticatedAndEncrypted)
     }
+
+    /// Check whether a message's protection satisfies this policy
+    ///
+    /// # Arguments
+    /// * `authenticated` - Authentication bit from the security control byte
+    /// * `encrypted` - Encryption bit from the security control byte
+    ///
+    /// # Returns
+    /// `true` if the protection meets or exceeds what the policy requires
+    pub fn is_satisfied_by(&self, authenticated: bool, encrypted: bool) -> bool {
+        (!self.is_authenticated() || authenticated) && (!self.is_encrypted() || encrypted)
+    }
 }
 
 /// Encryption mechanism
@@ -36,11 +36,15 @@
 //! - System Title
 //! - Key ID (GlobalUnicastEncryptionKey, GlobalBroadcastEncryptionKey, etc.)
 
+use crate::encryption::SecurityControl;
 use crate::error::{DlmsError, DlmsResult};
+use crate::suite::SecurityPolicy;
 use crate::utils::KeyId;
+use crate::xdlms_frame::GloCiphering;
 use aes::{Aes128, Aes192, Aes256};
 use aes::cipher::{BlockEncrypt, KeyInit};
 use aes::cipher::generic_array::{GenericArray, typenum::{U16, U24, U32}};
+use std::fmt;
 use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -118,6 +122,51 @@ impl SystemTitle {
         Ok(Self { value })
     }
 
+    /// Build a spec-compliant System Title from manufacturer and serial
+    ///
+    /// Per the Green Book, the system title starts with the 3-character
+    /// FLAG manufacturer ID followed by 5 bytes that uniquely identify
+    /// the device — here the serial number, packed big-endian.
+    ///
+    /// # Arguments
+    /// * `flag_id` - 3-character FLAG manufacturer ID (uppercase ASCII)
+    /// * `serial` - Device serial number (must fit in 5 bytes)
+    ///
+    /// # Errors
+    /// Returns error if the FLAG id is not exactly 3 uppercase ASCII
+    /// letters or the serial exceeds 5 bytes (2^40 - 1)
+    pub fn from_manufacturer(flag_id: &str, serial: u64) -> DlmsResult<Self> {
+        if flag_id.len() != 3 || !flag_id.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(DlmsError::InvalidData(format!(
+                "FLAG manufacturer ID must be 3 uppercase ASCII letters, got {:?}",
+                flag_id
+            )));
+        }
+        if serial > 0xFF_FFFF_FFFF {
+            return Err(DlmsError::InvalidData(format!(
+                "Serial number {} does not fit in 5 bytes",
+                serial
+            )));
+        }
+
+        let mut value = [0u8; 8];
+        value[0..3].copy_from_slice(flag_id.as_bytes());
+        value[3..8].copy_from_slice(&serial.to_be_bytes()[3..]);
+        Ok(Self { value })
+    }
+
+    /// Get the FLAG manufacturer ID (first 3 bytes as ASCII)
+    pub fn manufacturer_id(&self) -> String {
+        String::from_utf8_lossy(&self.value[0..3]).into_owned()
+    }
+
+    /// Get the serial number packed into the last 5 bytes
+    pub fn serial(&self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes[3..].copy_from_slice(&self.value[3..8]);
+        u64::from_be_bytes(bytes)
+    }
+
     /// Get the System Title as bytes
     pub fn as_bytes(&self) -> &[u8; 8] {
         &self.value
@@ -139,6 +188,49 @@ impl Default for SystemTitle {
     }
 }
 
+/// Persistence backend for a [`FrameCounter`]
+///
+/// A restart must not reset the frame counter: the peer tracks the last
+/// seen value for replay protection and rejects frames with a lower
+/// counter. Implementations persist the value to non-volatile storage
+/// (NVRAM, flash, a file) so a reloaded counter resumes where it left off.
+///
+/// Implementations are called from the I/O path and must not block for
+/// long; saves are debounced via the counter's save interval.
+pub trait FrameCounterStore: Send + Sync {
+    /// Load the last persisted counter value
+    fn load(&self) -> u32;
+
+    /// Persist the current counter value
+    fn save(&self, value: u32);
+}
+
+/// In-memory [`FrameCounterStore`]
+///
+/// Keeps the value in a mutex; useful in tests and for setups that
+/// deliberately accept volatile counters.
+#[derive(Debug, Default)]
+pub struct MemoryFrameCounterStore {
+    value: Mutex<u32>,
+}
+
+impl MemoryFrameCounterStore {
+    /// Create a store starting at 0
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FrameCounterStore for MemoryFrameCounterStore {
+    fn load(&self) -> u32 {
+        *self.value.lock().unwrap()
+    }
+
+    fn save(&self, value: u32) {
+        *self.value.lock().unwrap() = value;
+    }
+}
+
 /// Frame Counter
 ///
 /// A 32-bit counter that increments with each encrypted frame.
@@ -146,10 +238,16 @@ impl Default for SystemTitle {
 ///
 /// # Thread Safety
 /// Frame counter is wrapped in `Arc<Mutex<>>` to allow safe concurrent access.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct FrameCounter {
     /// The current frame counter value
     counter: Arc<Mutex<u32>>,
+    /// Optional persistence backend (no-op when unset)
+    store: Option<Arc<dyn FrameCounterStore>>,
+    /// Persist every this many increments (debounce)
+    save_interval: u32,
+    /// Value at the time of the last save
+    last_saved: Arc<Mutex<u32>>,
 }
 
 impl FrameCounter {
@@ -157,6 +255,9 @@ impl FrameCounter {
     pub fn new() -> Self {
         Self {
             counter: Arc::new(Mutex::new(0)),
+            store: None,
+            save_interval: 1,
+            last_saved: Arc::new(Mutex::new(0)),
         }
     }
 
@@ -167,6 +268,28 @@ impl FrameCounter {
     pub fn with_initial(initial: u32) -> Self {
         Self {
             counter: Arc::new(Mutex::new(initial)),
+            store: None,
+            save_interval: 1,
+            last_saved: Arc::new(Mutex::new(initial)),
+        }
+    }
+
+    /// Create a Frame Counter backed by a persistence store
+    ///
+    /// The counter resumes from the store's saved value. Increments are
+    /// persisted every `save_interval` steps to limit write wear; explicit
+    /// `set()`/`reset()` calls are persisted immediately.
+    ///
+    /// # Arguments
+    /// * `store` - Persistence backend
+    /// * `save_interval` - Persist every this many increments (0 is treated as 1)
+    pub fn with_store(store: Arc<dyn FrameCounterStore>, save_interval: u32) -> Self {
+        let initial = store.load();
+        Self {
+            counter: Arc::new(Mutex::new(initial)),
+            store: Some(store),
+            save_interval: save_interval.max(1),
+            last_saved: Arc::new(Mutex::new(initial)),
         }
     }
 
@@ -188,7 +311,18 @@ impl FrameCounter {
     pub fn increment(&self) -> u32 {
         let mut counter = self.counter.lock().unwrap();
         *counter = counter.wrapping_add(1);
-        *counter
+        let value = *counter;
+        drop(counter);
+
+        if let Some(store) = &self.store {
+            let mut last_saved = self.last_saved.lock().unwrap();
+            if value.wrapping_sub(*last_saved) >= self.save_interval {
+                store.save(value);
+                *last_saved = value;
+            }
+        }
+
+        value
     }
 
     /// Set the frame counter to a specific value
@@ -198,6 +332,13 @@ impl FrameCounter {
     pub fn set(&self, value: u32) {
         let mut counter = self.counter.lock().unwrap();
         *counter = value;
+        drop(counter);
+
+        // Jumps (e.g. adopting a peer's counter) are persisted immediately
+        if let Some(store) = &self.store {
+            store.save(value);
+            *self.last_saved.lock().unwrap() = value;
+        }
     }
 
     /// Reset the frame counter to 0
@@ -212,6 +353,16 @@ impl Default for FrameCounter {
     }
 }
 
+impl fmt::Debug for FrameCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameCounter")
+            .field("counter", &self.get())
+            .field("store", &if self.store.is_some() { "set" } else { "unset" })
+            .field("save_interval", &self.save_interval)
+            .finish()
+    }
+}
+
 /// Key Derivation Function (KDF)
 ///
 /// Derives encryption and authentication keys from a master key (KEK) according to
@@ -419,6 +570,11 @@ pub struct XdlmsContext {
     unicast_encryption_key: Option<Vec<u8>>,
     /// Derived broadcast encryption key (cached)
     broadcast_encryption_key: Option<Vec<u8>>,
+    /// Dedicated session key from the InitiateRequest (overrides the
+    /// derived unicast key for ciphered APDUs in this association)
+    dedicated_key: Option<Vec<u8>>,
+    /// Security policy enforced on outgoing and incoming APDUs
+    security_policy: SecurityPolicy,
 }
 
 impl XdlmsContext {
@@ -436,6 +592,8 @@ impl XdlmsContext {
             master_key: None,
             unicast_encryption_key: None,
             broadcast_encryption_key: None,
+            dedicated_key: None,
+            security_policy: SecurityPolicy::Nothing,
         }
     }
 
@@ -480,6 +638,62 @@ impl XdlmsContext {
         self.broadcast_encryption_key.as_ref()
     }
 
+    /// Install a dedicated session key
+    ///
+    /// The dedicated key is negotiated in the `InitiateRequest` and replaces
+    /// the derived unicast key for ciphered APDUs during this association.
+    ///
+    /// # Arguments
+    /// * `dedicated_key` - Dedicated session key, must be 16 bytes (AES-128)
+    ///
+    /// # Returns
+    /// `Ok(())` if the key has a valid length, error otherwise
+    pub fn set_dedicated_key(&mut self, dedicated_key: Vec<u8>) -> DlmsResult<()> {
+        if dedicated_key.len() != 16 {
+            return Err(DlmsError::Security(format!(
+                "Dedicated key must be 16 bytes, got {}",
+                dedicated_key.len()
+            )));
+        }
+        self.dedicated_key = Some(dedicated_key);
+        Ok(())
+    }
+
+    /// Remove the dedicated session key
+    ///
+    /// Subsequent ciphered APDUs fall back to the derived unicast key.
+    pub fn clear_dedicated_key(&mut self) {
+        self.dedicated_key = None;
+    }
+
+    /// Get the dedicated session key
+    ///
+    /// # Returns
+    /// Dedicated key if one was installed, `None` otherwise
+    pub fn dedicated_key(&self) -> Option<&Vec<u8>> {
+        self.dedicated_key.as_ref()
+    }
+
+    /// Get the encryption key to use for a ciphered APDU
+    ///
+    /// Unicast traffic prefers the dedicated session key when one is
+    /// installed; broadcast traffic always uses the derived broadcast key.
+    ///
+    /// # Arguments
+    /// * `is_broadcast` - Whether the APDU is broadcast
+    ///
+    /// # Returns
+    /// The session encryption key, or `None` if no suitable key is available
+    pub fn session_encryption_key(&self, is_broadcast: bool) -> Option<&Vec<u8>> {
+        if is_broadcast {
+            self.broadcast_encryption_key.as_ref()
+        } else {
+            self.dedicated_key
+                .as_ref()
+                .or(self.unicast_encryption_key.as_ref())
+        }
+    }
+
     /// Increment send frame counter and return new value
     ///
     /// # Returns
@@ -511,6 +725,112 @@ impl XdlmsContext {
         self.send_frame_counter.reset();
         self.receive_frame_counter.reset();
     }
+
+    /// Set the security policy enforced by protect/unprotect
+    pub fn set_security_policy(&mut self, policy: SecurityPolicy) {
+        self.security_policy = policy;
+    }
+
+    /// Get the current security policy
+    pub fn security_policy(&self) -> SecurityPolicy {
+        self.security_policy
+    }
+
+    /// Protect an outgoing APDU as general-glo-ciphering
+    ///
+    /// Refuses to produce the APDU if `security_control` carries less
+    /// protection than the configured security policy requires. The send
+    /// frame counter is incremented for the security header.
+    ///
+    /// # Arguments
+    /// * `plaintext` - Plaintext xDLMS APDU to protect
+    /// * `security_control` - Security control byte for the security header
+    ///
+    /// # Returns
+    /// Complete general-glo-ciphering APDU starting with tag 0xDB
+    pub fn protect_apdu(
+        &self,
+        plaintext: &[u8],
+        security_control: SecurityControl,
+    ) -> DlmsResult<Vec<u8>> {
+        if !self.security_policy.is_satisfied_by(
+            security_control.is_authenticated(),
+            security_control.is_encrypted(),
+        ) {
+            return Err(DlmsError::Security(format!(
+                "Security policy {:?} forbids sending APDU with security control 0x{:02X}",
+                self.security_policy,
+                security_control.to_byte()
+            )));
+        }
+
+        let frame_counter = self.increment_send_counter();
+        let glo = GloCiphering::new(Arc::new(self.clone()));
+        glo.build_general_glo_ciphering(
+            plaintext,
+            security_control,
+            &self.client_system_title,
+            frame_counter,
+        )
+    }
+
+    /// Unprotect an incoming general-glo-ciphering APDU
+    ///
+    /// Rejects the APDU before deciphering if its security control byte
+    /// carries less protection than the configured security policy requires.
+    ///
+    /// # Arguments
+    /// * `apdu` - Complete general-glo-ciphering APDU starting with tag 0xDB
+    ///
+    /// # Returns
+    /// Plaintext APDU recovered from the ciphered content
+    pub fn unprotect_apdu(&self, apdu: &[u8]) -> DlmsResult<Vec<u8>> {
+        let security_control = Self::peek_security_control(apdu)?;
+        if !self.security_policy.is_satisfied_by(
+            security_control.is_authenticated(),
+            security_control.is_encrypted(),
+        ) {
+            return Err(DlmsError::Security(format!(
+                "Security policy {:?} rejects received APDU with security control 0x{:02X}",
+                self.security_policy,
+                security_control.to_byte()
+            )));
+        }
+
+        let glo = GloCiphering::new(Arc::new(self.clone()));
+        glo.parse_general_glo_ciphering(apdu)
+    }
+
+    /// Read the security control byte from a general-glo-ciphering APDU
+    ///
+    /// The byte sits after the tag, the length-prefixed system title and
+    /// the BER definite length of the ciphered content.
+    fn peek_security_control(apdu: &[u8]) -> DlmsResult<SecurityControl> {
+        if apdu.len() < 3 {
+            return Err(DlmsError::InvalidData(
+                "General-glo-ciphering APDU too short".to_string(),
+            ));
+        }
+
+        // Tag | System Title Length | System Title
+        let st_len = apdu[1] as usize;
+        let mut pos = 2 + st_len;
+
+        // BER definite length of the ciphered content
+        let first = *apdu.get(pos).ok_or_else(|| {
+            DlmsError::InvalidData("Frame too short for content length".to_string())
+        })?;
+        pos += 1;
+        if (first & 0x80) != 0 {
+            pos += (first & 0x7F) as usize;
+        }
+
+        apdu.get(pos)
+            .map(|byte| SecurityControl::from_byte(*byte))
+            .ok_or_else(|| {
+                DlmsError::InvalidData("Frame too short for security header".to_string())
+            })
+    }
 }
 
 #[cfg(test)]
@@ -526,6 +846,24 @@ mod tests {
         assert_eq!(title2.as_bytes(), &[9, 10, 11, 12, 13, 14, 15, 16]);
     }
 
+    #[test]
+    fn test_system_title_from_manufacturer() {
+        let title = SystemTitle::from_manufacturer("ISK", 0x0102030405).unwrap();
+        assert_eq!(title.as_bytes(), &[b'I', b'S', b'K', 0x01, 0x02, 0x03, 0x04, 0x05]);
+        assert_eq!(title.manufacturer_id(), "ISK");
+        assert_eq!(title.serial(), 0x0102030405);
+    }
+
+    #[test]
+    fn test_system_title_from_manufacturer_rejects_bad_input() {
+        // FLAG id must be exactly 3 uppercase ASCII letters
+        assert!(SystemTitle::from_manufacturer("ISKR", 1).is_err());
+        assert!(SystemTitle::from_manufacturer("is", 1).is_err());
+        assert!(SystemTitle::from_manufacturer("isk", 1).is_err());
+        // Serial must fit in 5 bytes
+        assert!(SystemTitle::from_manufacturer("ISK", 1 << 40).is_err());
+    }
+
     #[test]
     fn test_frame_counter() {
         let counter = FrameCounter::new();
@@ -536,6 +874,39 @@ mod tests {
         assert_eq!(counter.get(), 0);
     }
 
+    #[test]
+    fn test_frame_counter_store_debounced_saves() {
+        let store = Arc::new(MemoryFrameCounterStore::new());
+        let counter = FrameCounter::with_store(store.clone(), 4);
+
+        for _ in 0..10 {
+            counter.increment();
+        }
+        assert_eq!(counter.get(), 10);
+        // Saved at 4 and 8; increments 9 and 10 are still debounced
+        assert_eq!(store.load(), 8);
+
+        // Explicit set persists immediately
+        counter.set(100);
+        assert_eq!(store.load(), 100);
+    }
+
+    #[test]
+    fn test_frame_counter_resumes_from_store() {
+        let store = Arc::new(MemoryFrameCounterStore::new());
+        store.save(42);
+
+        let counter = FrameCounter::with_store(store.clone(), 4);
+        assert_eq!(counter.get(), 42);
+
+        // Debounce window restarts from the loaded value
+        for _ in 0..4 {
+            counter.increment();
+        }
+        assert_eq!(counter.get(), 46);
+        assert_eq!(store.load(), 46);
+    }
+
     #[test]
     fn test_xdlms_context() {
         let client_title = SystemTitle::new([1, 2, 3, 4, 5, 6, 7, 8]);
@@ -551,6 +922,52 @@ mod tests {
         assert_eq!(context.increment_send_counter(), 1);
     }
 
+    fn policy_test_context(policy: SecurityPolicy) -> XdlmsContext {
+        let client_title = SystemTitle::new([1, 2, 3, 4, 5, 6, 7, 8]);
+        let server_title = SystemTitle::new([9, 10, 11, 12, 13, 14, 15, 16]);
+        let mut context = XdlmsContext::new(client_title, server_title);
+        context.set_master_key(vec![0u8; 16]).unwrap();
+        context.set_security_policy(policy);
+        context
+    }
+
+    #[test]
+    fn test_protect_apdu_enforces_security_policy() {
+        let context = policy_test_context(SecurityPolicy::AuthenticatedAndEncrypted);
+
+        // A plain security control falls short of the policy
+        let result = context.protect_apdu(b"payload", SecurityControl::new(0, false, false, false));
+        assert!(matches!(result, Err(DlmsError::Security(_))));
+        // The rejected APDU must not consume a frame counter value
+        assert_eq!(context.send_counter(), 0);
+
+        // Full protection satisfies the policy
+        let apdu = context
+            .protect_apdu(b"payload", SecurityControl::new(0, true, true, false))
+            .unwrap();
+        assert_eq!(apdu[0], crate::xdlms_frame::GENERAL_GLO_CIPHERING_TAG);
+    }
+
+    #[test]
+    fn test_unprotect_apdu_enforces_security_policy() {
+        // The sender's lax policy allows an authenticated-only APDU
+        let sender = policy_test_context(SecurityPolicy::Nothing);
+        let under_protected = sender
+            .protect_apdu(b"payload", SecurityControl::new(0, true, false, false))
+            .unwrap();
+
+        // The receiver's policy demands encryption as well
+        let receiver = policy_test_context(SecurityPolicy::AuthenticatedAndEncrypted);
+        let result = receiver.unprotect_apdu(&under_protected);
+        assert!(matches!(result, Err(DlmsError::Security(_))));
+
+        // A fully protected APDU passes the policy and deciphers
+        let protected = sender
+            .protect_apdu(b"payload", SecurityControl::new(0, true, true, false))
+            .unwrap();
+        assert_eq!(receiver.unprotect_apdu(&protected).unwrap(), b"payload");
+    }
+
     #[test]
     fn test_kdf_aes128() {
         let master_key = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
@@ -675,4 +1092,40 @@ mod tests {
         
         assert_ne!(key1, key2);
     }
+
+    #[test]
+    fn test_dedicated_key_overrides_unicast_key() {
+        let client_st = SystemTitle::new([1, 2, 3, 4, 5, 6, 7, 8]);
+        let server_st = SystemTitle::new([9, 10, 11, 12, 13, 14, 15, 16]);
+        let mut context = XdlmsContext::new(client_st, server_st);
+        context.set_master_key(vec![0u8; 16]).unwrap();
+
+        // Without a dedicated key, the derived unicast key is used
+        let derived = context.unicast_encryption_key().unwrap().clone();
+        assert_eq!(context.session_encryption_key(false), Some(&derived));
+
+        // With a dedicated key installed, it takes precedence for unicast
+        let dedicated = vec![0xAAu8; 16];
+        context.set_dedicated_key(dedicated.clone()).unwrap();
+        assert_eq!(context.session_encryption_key(false), Some(&dedicated));
+        assert_eq!(context.dedicated_key(), Some(&dedicated));
+
+        // Broadcast traffic keeps using the derived broadcast key
+        let broadcast = context.broadcast_encryption_key().unwrap().clone();
+        assert_eq!(context.session_encryption_key(true), Some(&broadcast));
+
+        // Clearing falls back to the derived unicast key
+        context.clear_dedicated_key();
+        assert_eq!(context.session_encryption_key(false), Some(&derived));
+    }
+
+    #[test]
+    fn test_dedicated_key_rejects_invalid_length() {
+        let client_st = SystemTitle::new([1, 2, 3, 4, 5, 6, 7, 8]);
+        let server_st = SystemTitle::new([9, 10, 11, 12, 13, 14, 15, 16]);
+        let mut context = XdlmsContext::new(client_st, server_st);
+
+        assert!(context.set_dedicated_key(vec![0u8; 8]).is_err());
+        assert!(context.dedicated_key().is_none());
+    }
 }
@@ -19,7 +19,7 @@
 //! Bit 4: Authenticated (1 = authenticated, 0 = not authenticated)
 //! Bit 5: Encrypted (1 = encrypted, 0 = not encrypted)
 //! Bit 6: Key Set (1 = System Title present, 0 = System Title not present)
-//! Bit 7: Reserved (must be 0)
+//! Bit 7: Compressed (1 = plaintext deflated before encryption, requires the `compression` feature)
 //!
 //! # Why This Design?
 //! - **Security Control**: Indicates which security features are active
@@ -43,6 +43,9 @@ pub struct EncryptedFrameBuilder {
     context: Arc<XdlmsContext>,
     /// Security suite ID (0-15)
     security_suite_id: u8,
+    /// Whether to deflate the plaintext before encryption
+    #[cfg(feature = "compression")]
+    compress: bool,
 }
 
 impl EncryptedFrameBuilder {
@@ -55,9 +58,23 @@ impl EncryptedFrameBuilder {
         Self {
             context,
             security_suite_id: security_suite_id & 0x0F, // Ensure only 4 bits
+            #[cfg(feature = "compression")]
+            compress: false,
         }
     }
 
+    /// Enable or disable plaintext compression
+    ///
+    /// When enabled, the plaintext APDU is deflated before encryption and the
+    /// compression bit (bit 7) of the security control byte is set, so the
+    /// receiving side knows to inflate after decryption. Useful on
+    /// bandwidth-constrained links (e.g. cellular).
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
     /// Build an encrypted frame from plaintext PDU
     ///
     /// # Arguments
@@ -87,12 +104,8 @@ impl EncryptedFrameBuilder {
         include_system_title: bool,
         is_broadcast: bool,
     ) -> DlmsResult<Vec<u8>> {
-        // Get encryption key
-        let encryption_key = if is_broadcast {
-            self.context.broadcast_encryption_key()
-        } else {
-            self.context.unicast_encryption_key()
-        };
+        // Get encryption key (dedicated session key takes precedence for unicast)
+        let encryption_key = self.context.session_encryption_key(is_broadcast);
 
         let encryption_key = encryption_key.ok_or_else(|| {
             DlmsError::Security("Encryption key not available. Call set_master_key() first.".to_string())
@@ -101,6 +114,17 @@ impl EncryptedFrameBuilder {
         // Create encryption context
         let cipher = AesGcmEncryption::new(encryption_key)?;
 
+        // Deflate the plaintext before encryption if requested
+        #[cfg(feature = "compression")]
+        let deflated;
+        #[cfg(feature = "compression")]
+        let plaintext = if self.compress && encrypted {
+            deflated = miniz_oxide::deflate::compress_to_vec(plaintext, 6);
+            deflated.as_slice()
+        } else {
+            plaintext
+        };
+
         // Increment frame counter and get current value
         let frame_counter = self.context.send_frame_counter.increment();
 
@@ -134,6 +158,8 @@ impl EncryptedFrameBuilder {
             encrypted,
             include_system_title,
         );
+        #[cfg(feature = "compression")]
+        let security_control = security_control.with_compression(self.compress && encrypted);
 
         // Build frame
         let mut frame = Vec::new();
@@ -289,12 +315,8 @@ impl EncryptedFrameParser {
 
         // 6. Decrypt data
         if encrypted {
-            // Get decryption key
-            let decryption_key = if is_broadcast {
-                self.context.broadcast_encryption_key()
-            } else {
-                self.context.unicast_encryption_key()
-            };
+            // Get decryption key (dedicated session key takes precedence for unicast)
+            let decryption_key = self.context.session_encryption_key(is_broadcast);
 
             let decryption_key = decryption_key.ok_or_else(|| {
                 DlmsError::Security(
@@ -346,6 +368,21 @@ impl EncryptedFrameParser {
             // Decrypt
             let plaintext = cipher.decrypt(ciphertext, &nonce[..], &aad[..])?;
 
+            // Inflate the plaintext if the compression bit is set
+            if security_control.is_compressed() {
+                #[cfg(feature = "compression")]
+                {
+                    return miniz_oxide::inflate::decompress_to_vec(&plaintext).map_err(|e| {
+                        DlmsError::Security(format!("Failed to decompress APDU: {:?}", e))
+                    });
+                }
+                #[cfg(not(feature = "compression"))]
+                return Err(DlmsError::Security(
+                    "Compressed frame received but the 'compression' feature is not enabled"
+                        .to_string(),
+                ));
+            }
+
             Ok(plaintext)
         } else {
             // If not encrypted, return data as-is
@@ -354,6 +391,392 @@ impl EncryptedFrameParser {
     }
 }
 
+/// Tag of the general-glo-ciphering APDU (DLMS Green Book)
+pub const GENERAL_GLO_CIPHERING_TAG: u8 = 0xDB;
+
+/// APDU tag for glo-initiate-request
+///
+/// Carries a ciphered InitiateRequest inside the AARQ user_information
+/// field for ciphered application contexts.
+pub const GLO_INITIATE_REQUEST_TAG: u8 = 0x21;
+
+/// APDU tag for glo-initiate-response
+///
+/// Carries a ciphered InitiateResponse inside the AARE user_information
+/// field for ciphered application contexts.
+pub const GLO_INITIATE_RESPONSE_TAG: u8 = 0x28;
+
+/// General-glo-ciphering APDU wrapper
+///
+/// Wraps a plaintext xDLMS APDU into the `general-glo-ciphering` structure
+/// used with the `glo-` service tags:
+/// ```text
+/// Tag (0xDB, 1 byte)
+/// System Title Length (1 byte, always 8)
+/// System Title (8 bytes)
+/// Ciphered Content Length (1-3 bytes, BER definite length)
+/// Ciphered Content:
+///     Security Control (1 byte)
+///     Frame Counter (4 bytes, big-endian)
+///     Encrypted Data + Authentication Tag (variable)
+/// ```
+///
+/// Unlike [`EncryptedFrameBuilder`], the caller supplies the security control,
+/// system title, and frame counter explicitly, which makes the wrapper usable
+/// on both the sending and receiving side of an association.
+pub struct GloCiphering {
+    /// xDLMS context containing keys and counters
+    context: Arc<XdlmsContext>,
+}
+
+impl GloCiphering {
+    /// Create a new general-glo-ciphering wrapper
+    ///
+    /// # Arguments
+    /// * `context` - xDLMS context with keys and counters
+    pub fn new(context: Arc<XdlmsContext>) -> Self {
+        Self { context }
+    }
+
+    /// Build a general-glo-ciphering APDU from a plaintext xDLMS APDU
+    ///
+    /// # Arguments
+    /// * `plaintext` - Plaintext APDU to cipher (e.g. a get-request)
+    /// * `security_control` - Security control byte to place in the security header
+    /// * `system_title` - System title of the sending party
+    /// * `frame_counter` - Frame counter value for this APDU
+    ///
+    /// # Returns
+    /// Complete general-glo-ciphering APDU starting with tag 0xDB
+    ///
+    /// # Error Handling
+    /// - Returns error if no encryption key is available
+    /// - Returns error if encryption fails
+    pub fn build_general_glo_ciphering(
+        &self,
+        plaintext: &[u8],
+        security_control: SecurityControl,
+        system_title: &SystemTitle,
+        frame_counter: u32,
+    ) -> DlmsResult<Vec<u8>> {
+        // Build nonce and AAD from System Title and Frame Counter,
+        // matching the EncryptedFrameBuilder layout
+        let mut nonce = Vec::with_capacity(12);
+        nonce.extend_from_slice(system_title.as_bytes());
+        nonce.extend_from_slice(&frame_counter.to_be_bytes());
+
+        let ciphertext = if security_control.is_encrypted() {
+            let encryption_key = self.context.session_encryption_key(false).ok_or_else(|| {
+                DlmsError::Security(
+                    "Encryption key not available. Call set_master_key() first.".to_string(),
+                )
+            })?;
+            let cipher = AesGcmEncryption::new(encryption_key)?;
+            cipher.encrypt_with_nonce(plaintext, &nonce, &nonce)?
+        } else {
+            plaintext.to_vec()
+        };
+
+        // Ciphered content: Security Control | Frame Counter | Encrypted Data
+        let mut content = Vec::with_capacity(5 + ciphertext.len());
+        content.push(security_control.to_byte());
+        content.extend_from_slice(&frame_counter.to_be_bytes());
+        content.extend_from_slice(&ciphertext);
+
+        let mut apdu = Vec::with_capacity(10 + 3 + content.len());
+        apdu.push(GENERAL_GLO_CIPHERING_TAG);
+        apdu.push(system_title.as_bytes().len() as u8);
+        apdu.extend_from_slice(system_title.as_bytes());
+        Self::encode_length(&mut apdu, content.len());
+        apdu.extend_from_slice(&content);
+
+        Ok(apdu)
+    }
+
+    /// Parse a general-glo-ciphering APDU back into the plaintext xDLMS APDU
+    ///
+    /// # Arguments
+    /// * `apdu` - Complete general-glo-ciphering APDU starting with tag 0xDB
+    ///
+    /// # Returns
+    /// Plaintext APDU recovered from the ciphered content
+    ///
+    /// # Error Handling
+    /// - Returns error if the tag or structure is invalid
+    /// - Returns error if the frame counter indicates a replay
+    /// - Returns error if decryption or tag verification fails
+    pub fn parse_general_glo_ciphering(&self, apdu: &[u8]) -> DlmsResult<Vec<u8>> {
+        if apdu.len() < 2 {
+            return Err(DlmsError::InvalidData(
+                "General-glo-ciphering APDU too short".to_string(),
+            ));
+        }
+        if apdu[0] != GENERAL_GLO_CIPHERING_TAG {
+            return Err(DlmsError::InvalidData(format!(
+                "Expected general-glo-ciphering tag 0x{:02X}, got 0x{:02X}",
+                GENERAL_GLO_CIPHERING_TAG, apdu[0]
+            )));
+        }
+
+        // System Title (length-prefixed octet string, must be 8 bytes)
+        let mut pos = 1;
+        let st_len = apdu[pos] as usize;
+        pos += 1;
+        if pos + st_len > apdu.len() {
+            return Err(DlmsError::InvalidData(
+                "Frame too short for System Title".to_string(),
+            ));
+        }
+        let system_title = SystemTitle::from_slice(&apdu[pos..pos + st_len])?;
+        pos += st_len;
+
+        // Ciphered content (length-prefixed)
+        let (content_len, len_size) = Self::decode_length(&apdu[pos..])?;
+        pos += len_size;
+        if pos + content_len != apdu.len() {
+            return Err(DlmsError::InvalidData(format!(
+                "Ciphered content length mismatch: declared {}, available {}",
+                content_len,
+                apdu.len() - pos
+            )));
+        }
+        if content_len < 5 {
+            return Err(DlmsError::InvalidData(
+                "Ciphered content too short for security header".to_string(),
+            ));
+        }
+
+        // Security header: Security Control | Frame Counter
+        let security_control = SecurityControl::from_byte(apdu[pos]);
+        pos += 1;
+        let frame_counter = u32::from_be_bytes([
+            apdu[pos],
+            apdu[pos + 1],
+            apdu[pos + 2],
+            apdu[pos + 3],
+        ]);
+        pos += 4;
+
+        // Verify frame counter (prevent replay attacks)
+        let expected_counter = self.context.receive_frame_counter.get();
+        if frame_counter <= expected_counter {
+            return Err(DlmsError::Security(format!(
+                "Frame counter validation failed: received {} <= expected {} (possible replay attack)",
+                frame_counter, expected_counter
+            )));
+        }
+        let ciphertext = &apdu[pos..];
+
+        let plaintext = if security_control.is_encrypted() {
+            let decryption_key = self.context.session_encryption_key(false).ok_or_else(|| {
+                DlmsError::Security(
+                    "Decryption key not available. Call set_master_key() first.".to_string(),
+                )
+            })?;
+            let cipher = AesGcmEncryption::new(decryption_key)?;
+
+            let mut nonce = Vec::with_capacity(12);
+            nonce.extend_from_slice(system_title.as_bytes());
+            nonce.extend_from_slice(&frame_counter.to_be_bytes());
+
+            cipher.decrypt(ciphertext, &nonce, &nonce)?
+        } else {
+            ciphertext.to_vec()
+        };
+
+        // Commit the counter only after the authentication tag verified; an
+        // unauthenticated frame must not be able to advance the replay window
+        self.context.receive_frame_counter.set(frame_counter);
+
+        Ok(plaintext)
+    }
+
+    /// Build a glo-initiate APDU (`glo-initiate-request`/`-response`)
+    ///
+    /// Unlike general-glo-ciphering, the glo- service APDUs do not carry the
+    /// system title on the wire; both sides know it from the association
+    /// (AARQ/AARE addressing). The layout is:
+    /// ```text
+    /// Tag (1 byte, 0x21 request / 0x28 response)
+    /// Ciphered Content Length (1-3 bytes, BER definite length)
+    /// Ciphered Content:
+    ///     Security Control (1 byte)
+    ///     Frame Counter (4 bytes, big-endian)
+    ///     Encrypted Data + Authentication Tag (variable)
+    /// ```
+    ///
+    /// # Arguments
+    /// * `tag` - APDU tag ([`GLO_INITIATE_REQUEST_TAG`] or [`GLO_INITIATE_RESPONSE_TAG`])
+    /// * `plaintext` - Plaintext InitiateRequest/InitiateResponse bytes
+    /// * `security_control` - Security control byte for the security header
+    /// * `system_title` - System title of the sending party (nonce input only)
+    /// * `frame_counter` - Frame counter value for this APDU
+    pub fn build_glo_initiate(
+        &self,
+        tag: u8,
+        plaintext: &[u8],
+        security_control: SecurityControl,
+        system_title: &SystemTitle,
+        frame_counter: u32,
+    ) -> DlmsResult<Vec<u8>> {
+        let mut nonce = Vec::with_capacity(12);
+        nonce.extend_from_slice(system_title.as_bytes());
+        nonce.extend_from_slice(&frame_counter.to_be_bytes());
+
+        let ciphertext = if security_control.is_encrypted() {
+            let encryption_key = self.context.session_encryption_key(false).ok_or_else(|| {
+                DlmsError::Security(
+                    "Encryption key not available. Call set_master_key() first.".to_string(),
+                )
+            })?;
+            let cipher = AesGcmEncryption::new(encryption_key)?;
+            cipher.encrypt_with_nonce(plaintext, &nonce, &nonce)?
+        } else {
+            plaintext.to_vec()
+        };
+
+        let mut content = Vec::with_capacity(5 + ciphertext.len());
+        content.push(security_control.to_byte());
+        content.extend_from_slice(&frame_counter.to_be_bytes());
+        content.extend_from_slice(&ciphertext);
+
+        let mut apdu = Vec::with_capacity(4 + content.len());
+        apdu.push(tag);
+        Self::encode_length(&mut apdu, content.len());
+        apdu.extend_from_slice(&content);
+
+        Ok(apdu)
+    }
+
+    /// Parse a glo-initiate APDU back into the plaintext bytes
+    ///
+    /// # Arguments
+    /// * `expected_tag` - APDU tag to accept (request or response)
+    /// * `apdu` - Complete glo-initiate APDU
+    /// * `sender_system_title` - System title of the sending party (nonce input)
+    ///
+    /// # Error Handling
+    /// - Returns error if the tag or structure is invalid
+    /// - Returns error if the frame counter indicates a replay
+    /// - Returns error if decryption or tag verification fails
+    pub fn parse_glo_initiate(
+        &self,
+        expected_tag: u8,
+        apdu: &[u8],
+        sender_system_title: &SystemTitle,
+    ) -> DlmsResult<Vec<u8>> {
+        if apdu.len() < 2 {
+            return Err(DlmsError::InvalidData(
+                "Glo-initiate APDU too short".to_string(),
+            ));
+        }
+        if apdu[0] != expected_tag {
+            return Err(DlmsError::InvalidData(format!(
+                "Expected glo-initiate tag 0x{:02X}, got 0x{:02X}",
+                expected_tag, apdu[0]
+            )));
+        }
+
+        let mut pos = 1;
+        let (content_len, len_size) = Self::decode_length(&apdu[pos..])?;
+        pos += len_size;
+        if pos + content_len != apdu.len() {
+            return Err(DlmsError::InvalidData(format!(
+                "Ciphered content length mismatch: declared {}, available {}",
+                content_len,
+                apdu.len() - pos
+            )));
+        }
+        if content_len < 5 {
+            return Err(DlmsError::InvalidData(
+                "Ciphered content too short for security header".to_string(),
+            ));
+        }
+
+        let security_control = SecurityControl::from_byte(apdu[pos]);
+        pos += 1;
+        let frame_counter = u32::from_be_bytes([
+            apdu[pos],
+            apdu[pos + 1],
+            apdu[pos + 2],
+            apdu[pos + 3],
+        ]);
+        pos += 4;
+
+        // Verify frame counter (prevent replay attacks)
+        let expected_counter = self.context.receive_frame_counter.get();
+        if frame_counter <= expected_counter {
+            return Err(DlmsError::Security(format!(
+                "Frame counter validation failed: received {} <= expected {} (possible replay attack)",
+                frame_counter, expected_counter
+            )));
+        }
+        let ciphertext = &apdu[pos..];
+
+        let plaintext = if security_control.is_encrypted() {
+            let decryption_key = self.context.session_encryption_key(false).ok_or_else(|| {
+                DlmsError::Security(
+                    "Decryption key not available. Call set_master_key() first.".to_string(),
+                )
+            })?;
+            let cipher = AesGcmEncryption::new(decryption_key)?;
+
+            let mut nonce = Vec::with_capacity(12);
+            nonce.extend_from_slice(sender_system_title.as_bytes());
+            nonce.extend_from_slice(&frame_counter.to_be_bytes());
+
+            cipher.decrypt(ciphertext, &nonce, &nonce)?
+        } else {
+            ciphertext.to_vec()
+        };
+
+        // Commit the counter only after the authentication tag verified; an
+        // unauthenticated frame must not be able to advance the replay window
+        self.context.receive_frame_counter.set(frame_counter);
+
+        Ok(plaintext)
+    }
+
+    /// Encode a BER definite length into the output buffer
+    fn encode_length(out: &mut Vec<u8>, len: usize) {
+        if len < 0x80 {
+            out.push(len as u8);
+        } else if len <= 0xFF {
+            out.push(0x81);
+            out.push(len as u8);
+        } else {
+            out.push(0x82);
+            out.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+    }
+
+    /// Decode a BER definite length, returning (length, bytes consumed)
+    fn decode_length(data: &[u8]) -> DlmsResult<(usize, usize)> {
+        if data.is_empty() {
+            return Err(DlmsError::InvalidData("Missing length field".to_string()));
+        }
+        match data[0] {
+            len if len < 0x80 => Ok((len as usize, 1)),
+            0x81 => {
+                if data.len() < 2 {
+                    return Err(DlmsError::InvalidData("Truncated length field".to_string()));
+                }
+                Ok((data[1] as usize, 2))
+            }
+            0x82 => {
+                if data.len() < 3 {
+                    return Err(DlmsError::InvalidData("Truncated length field".to_string()));
+                }
+                Ok((u16::from_be_bytes([data[1], data[2]]) as usize, 3))
+            }
+            other => Err(DlmsError::InvalidData(format!(
+                "Unsupported length encoding: 0x{:02X}",
+                other
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,4 +808,171 @@ mod tests {
         
         assert_eq!(plaintext, decrypted.as_slice());
     }
+
+    #[test]
+    fn test_encrypted_frame_uses_dedicated_key() {
+        let client_st = SystemTitle::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+        let server_st = SystemTitle::new([0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18]);
+        let dedicated_key = vec![0x5Au8; 16];
+
+        // Sender context: master key plus installed dedicated key
+        let mut sender_context = XdlmsContext::new(client_st.clone(), server_st.clone());
+        sender_context.set_master_key(vec![0u8; 16]).unwrap();
+        sender_context.set_dedicated_key(dedicated_key.clone()).unwrap();
+        let sender_context = Arc::new(sender_context);
+
+        let builder = EncryptedFrameBuilder::new(sender_context, 0);
+        let plaintext = b"dedicated key session";
+        let encrypted_frame = builder
+            .build_encrypted_frame(plaintext, true, true, true, false)
+            .unwrap();
+
+        // A receiver with only the global key cannot decrypt the frame
+        let mut global_context = XdlmsContext::new(client_st.clone(), server_st.clone());
+        global_context.set_master_key(vec![0u8; 16]).unwrap();
+        let parser = EncryptedFrameParser::new(Arc::new(global_context));
+        assert!(parser.parse_encrypted_frame(&encrypted_frame, false).is_err());
+
+        // A receiver with the same dedicated key installed can
+        let mut session_context = XdlmsContext::new(client_st, server_st);
+        session_context.set_master_key(vec![0u8; 16]).unwrap();
+        session_context.set_dedicated_key(dedicated_key).unwrap();
+        let parser = EncryptedFrameParser::new(Arc::new(session_context));
+        let decrypted = parser.parse_encrypted_frame(&encrypted_frame, false).unwrap();
+
+        assert_eq!(plaintext, decrypted.as_slice());
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_encrypted_frame_compression_roundtrip() {
+        let client_st = SystemTitle::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+        let server_st = SystemTitle::new([0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18]);
+        let mut context = XdlmsContext::new(client_st, server_st);
+        context.set_master_key(vec![0u8; 16]).unwrap();
+        let context = Arc::new(context);
+
+        // Highly compressible plaintext (e.g. a long list of identical entries)
+        let plaintext = vec![0xAAu8; 512];
+
+        let builder = EncryptedFrameBuilder::new(context.clone(), 0).with_compression(true);
+        let encrypted_frame = builder
+            .build_encrypted_frame(&plaintext, true, true, true, false)
+            .unwrap();
+
+        // Compression bit must be set and the frame noticeably smaller than the input
+        assert!(SecurityControl::from_byte(encrypted_frame[0]).is_compressed());
+        assert!(encrypted_frame.len() < plaintext.len());
+
+        let parser = EncryptedFrameParser::new(context);
+        let decrypted = parser.parse_encrypted_frame(&encrypted_frame, false).unwrap();
+        assert_eq!(plaintext, decrypted);
+    }
+
+    fn glo_test_context() -> Arc<XdlmsContext> {
+        let client_st = SystemTitle::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+        let server_st = SystemTitle::new([0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18]);
+        let mut context = XdlmsContext::new(client_st, server_st);
+        context.set_master_key(vec![0u8; 16]).unwrap();
+        Arc::new(context)
+    }
+
+    #[test]
+    fn test_glo_ciphering_roundtrip_get_request() {
+        let context = glo_test_context();
+        let glo = GloCiphering::new(context.clone());
+
+        // Plaintext get-request-normal: class 3, OBIS 1.0.1.8.0.255, attribute 2
+        let get_request = [
+            0xC0, 0x01, 0xC1, 0x00, 0x03, 0x01, 0x00, 0x01, 0x08, 0x00, 0xFF, 0x02, 0x00,
+        ];
+        let security_control = SecurityControl::new(0, true, true, false);
+        let system_title = SystemTitle::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+
+        let apdu = glo
+            .build_general_glo_ciphering(&get_request, security_control, &system_title, 1)
+            .unwrap();
+
+        let decrypted = glo.parse_general_glo_ciphering(&apdu).unwrap();
+        assert_eq!(get_request.as_slice(), decrypted.as_slice());
+    }
+
+    #[test]
+    fn test_glo_ciphering_security_header_layout() {
+        let context = glo_test_context();
+        let glo = GloCiphering::new(context);
+
+        let security_control = SecurityControl::new(0, true, true, false);
+        let system_title = SystemTitle::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+        let frame_counter = 0x01020304u32;
+
+        let apdu = glo
+            .build_general_glo_ciphering(b"payload", security_control, &system_title, frame_counter)
+            .unwrap();
+
+        // Tag | System Title Length | System Title
+        assert_eq!(0xDB, apdu[0]);
+        assert_eq!(0x08, apdu[1]);
+        assert_eq!(system_title.as_bytes(), &apdu[2..10]);
+
+        // Ciphered content: 1 (SC) + 4 (FC) + 7 (payload) + 16 (auth tag)
+        assert_eq!(28, apdu[10] as usize);
+        assert_eq!(apdu.len(), 11 + 28);
+
+        // Security header: SC = suite 0, authenticated + encrypted = 0x30
+        assert_eq!(0x30, apdu[11]);
+        assert_eq!([0x01, 0x02, 0x03, 0x04], apdu[12..16]);
+    }
+
+    #[test]
+    fn test_glo_ciphering_rejects_wrong_tag() {
+        let context = glo_test_context();
+        let glo = GloCiphering::new(context);
+
+        let result = glo.parse_general_glo_ciphering(&[0xC8, 0x08, 0x00]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_glo_ciphering_rejects_replayed_frame_counter() {
+        let context = glo_test_context();
+        let glo = GloCiphering::new(context);
+
+        let security_control = SecurityControl::new(0, true, true, false);
+        let system_title = SystemTitle::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+
+        let apdu = glo
+            .build_general_glo_ciphering(b"once", security_control, &system_title, 5)
+            .unwrap();
+
+        // First delivery succeeds, replay of the same frame counter is rejected
+        glo.parse_general_glo_ciphering(&apdu).unwrap();
+        assert!(glo.parse_general_glo_ciphering(&apdu).is_err());
+    }
+
+    #[test]
+    fn test_glo_ciphering_forged_frame_does_not_advance_counter() {
+        let context = glo_test_context();
+        let glo = GloCiphering::new(context);
+
+        let security_control = SecurityControl::new(0, true, true, false);
+        let system_title = SystemTitle::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+
+        let legitimate = glo
+            .build_general_glo_ciphering(b"payload", security_control, &system_title, 1)
+            .unwrap();
+
+        // A forged frame claiming counter u32::MAX with a garbage auth tag:
+        // take a valid frame layout and corrupt the ciphered content
+        let mut forged = glo
+            .build_general_glo_ciphering(b"payload", security_control, &system_title, u32::MAX)
+            .unwrap();
+        let last = forged.len() - 1;
+        forged[last] ^= 0x01;
+        assert!(glo.parse_general_glo_ciphering(&forged).is_err());
+
+        // The failed frame must not have advanced the replay window
+        let decrypted = glo.parse_general_glo_ciphering(&legitimate).unwrap();
+        assert_eq!(b"payload".as_slice(), decrypted.as_slice());
+    }
 }
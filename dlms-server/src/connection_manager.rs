@@ -371,6 +371,7 @@ mod tests {
             conformance: dlms_application::pdu::Conformance::default(),
             max_pdu_size: 1024,
             dlms_version: 6,
+            dedicated_key: None,
         }
     }
 
@@ -110,6 +110,7 @@ pub mod connection_manager;
 pub mod access_control;
 pub mod event;
 pub mod set_block_transfer;
+pub mod sn_object_map;
 pub mod request_stats;
 pub mod error;
 
@@ -117,6 +118,7 @@ pub use server::{DlmsServer, ServerConfig, AssociationContext};
 pub use server_state::{ServerStateMachine, ServerState, ServerStatus, StateTransition};
 pub use error::{DlmsServerError, ServerErrorCode};
 pub use listener::ServerListener;
+pub use sn_object_map::{SnObjectMap, SnReference};
 pub use connection_manager::{
     ConnectionManager, ConnectionInfo, ConnectionStatistics,
 };
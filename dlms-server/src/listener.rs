@@ -11,14 +11,190 @@ use dlms_application::pdu::{
     AccessRequest,
     ExceptionResponse,
 };
+use dlms_asn1::iso_acse::{RLRQApdu, ReleaseRequestReason};
 use dlms_core::{DlmsError, DlmsResult};
 use dlms_session::hdlc::{HdlcConnection, HdlcAddress};
-use dlms_session::wrapper::WrapperSession;
-use dlms_transport::TcpTransport;
+use dlms_session::wrapper::{WrapperHeader, WrapperPdu, WrapperSession};
+use dlms_transport::{StreamAccessor, TcpTransport};
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, Notify, RwLock};
+
+/// Connection lifecycle event emitted by a [`ConnectionManager`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// The server tore down the association at the given peer address,
+    /// either through [`ConnectionManager::disconnect`] or because the
+    /// association exceeded the configured idle timeout.
+    ConnectionLost(SocketAddr),
+}
+
+/// Per-connection state tracked by the [`ConnectionManager`]
+#[derive(Debug, Clone)]
+struct ConnectionEntry {
+    /// Signals the serving task to release the association and close
+    shutdown: Arc<Notify>,
+    /// When the last PDU was received from this client
+    last_activity: Arc<RwLock<Instant>>,
+}
+
+/// Connection manager for a wrapper listener
+///
+/// Tracks the active associations keyed by the client's socket address and
+/// enforces a `max_connections` limit. Unlike
+/// [`crate::connection_manager::ConnectionManager`], which tracks
+/// associations by client SAP inside the server core, this manager sits in
+/// front of the accept loop: when the limit is reached a new client's first
+/// PDU is answered with a busy exception response and the socket is closed.
+///
+/// The manager is cheaply cloneable; all clones share the same connection
+/// table, so `active_count()` and `disconnect()` can be called from outside
+/// the listener task.
+#[derive(Debug, Clone)]
+pub struct ConnectionManager {
+    /// Active connections with their shutdown handles and activity times
+    connections: Arc<RwLock<HashMap<SocketAddr, ConnectionEntry>>>,
+    /// Maximum number of concurrent connections (0 = unlimited)
+    max_connections: usize,
+    /// Idle timeout after which an association is released (None = never)
+    idle_timeout: Option<Duration>,
+    /// Subscriber for connection lifecycle events
+    events: Arc<RwLock<Option<mpsc::Sender<ConnectionEvent>>>>,
+}
+
+impl ConnectionManager {
+    /// Create a new connection manager
+    ///
+    /// # Arguments
+    /// * `max_connections` - Maximum number of concurrent connections (0 = unlimited)
+    pub fn new(max_connections: usize) -> Self {
+        Self {
+            connections: Arc::new(RwLock::new(HashMap::new())),
+            max_connections,
+            idle_timeout: None,
+            events: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Set the idle timeout for associations
+    ///
+    /// When set, a background sweep releases associations that have not
+    /// received a PDU for `idle_timeout`: the client is sent an RLRQ (or
+    /// DISC for HDLC), the socket is closed, and a
+    /// [`ConnectionEvent::ConnectionLost`] event is emitted.
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Subscribe to connection lifecycle events
+    ///
+    /// Only the most recent subscriber receives events; calling this again
+    /// replaces the previous subscription.
+    pub async fn subscribe_events(&self) -> mpsc::Receiver<ConnectionEvent> {
+        let (tx, rx) = mpsc::channel(16);
+        *self.events.write().await = Some(tx);
+        rx
+    }
+
+    /// Emit an event to the subscriber, if any
+    async fn emit(&self, event: ConnectionEvent) {
+        if let Some(tx) = self.events.read().await.as_ref() {
+            let _ = tx.send(event).await;
+        }
+    }
+
+    /// Register a new connection, returning its per-connection state
+    ///
+    /// Returns `None` when the `max_connections` limit is reached.
+    async fn register(&self, addr: SocketAddr) -> Option<ConnectionEntry> {
+        let mut connections = self.connections.write().await;
+        if self.max_connections > 0
+            && connections.len() >= self.max_connections
+            && !connections.contains_key(&addr)
+        {
+            return None;
+        }
+        let entry = ConnectionEntry {
+            shutdown: Arc::new(Notify::new()),
+            last_activity: Arc::new(RwLock::new(Instant::now())),
+        };
+        connections.insert(addr, entry.clone());
+        Some(entry)
+    }
+
+    /// Unregister a connection when it ends
+    async fn unregister(&self, addr: SocketAddr) {
+        let mut connections = self.connections.write().await;
+        connections.remove(&addr);
+    }
+
+    /// Get the number of active connections
+    pub async fn active_count(&self) -> usize {
+        let connections = self.connections.read().await;
+        connections.len()
+    }
+
+    /// Disconnect the client at `addr`
+    ///
+    /// Signals the serving task to close the connection and removes the
+    /// entry from the table.
+    ///
+    /// # Returns
+    /// `true` if the client was connected, `false` otherwise
+    pub async fn disconnect(&self, addr: SocketAddr) -> bool {
+        let entry = {
+            let mut connections = self.connections.write().await;
+            connections.remove(&addr)
+        };
+        match entry {
+            Some(entry) => {
+                entry.shutdown.notify_one();
+                self.emit(ConnectionEvent::ConnectionLost(addr)).await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Spawn the background idle sweep, if an idle timeout is configured
+    ///
+    /// The sweep periodically releases associations whose last activity is
+    /// older than the configured idle timeout.
+    fn spawn_idle_sweep(&self) {
+        if let Some(idle_timeout) = self.idle_timeout {
+            let manager = self.clone();
+            tokio::spawn(async move {
+                let sweep_interval = (idle_timeout / 4).max(Duration::from_millis(10));
+                let mut interval = tokio::time::interval(sweep_interval);
+                loop {
+                    interval.tick().await;
+                    manager.sweep_idle(idle_timeout).await;
+                }
+            });
+        }
+    }
+
+    /// Release every association idle for longer than `idle_timeout`
+    async fn sweep_idle(&self, idle_timeout: Duration) {
+        let mut expired = Vec::new();
+        {
+            let connections = self.connections.read().await;
+            for (addr, entry) in connections.iter() {
+                if entry.last_activity.read().await.elapsed() >= idle_timeout {
+                    expired.push(*addr);
+                }
+            }
+        }
+        for addr in expired {
+            log::info!("Releasing idle association from {}", addr);
+            self.disconnect(addr).await;
+        }
+    }
+}
 
 /// Server listener for accepting client connections
 ///
@@ -159,6 +335,306 @@ impl ServerListener {
         // In real implementation, this should come from HDLC address negotiation
         peer_addr.port()
     }
+
+    /// Listen for Wrapper-over-TCP connections
+    ///
+    /// Unlike [`ServerListener::start`], this entry point speaks the plain
+    /// wrapper protocol used by IP meters without HDLC. It binds to `addr`,
+    /// accepts connections indefinitely and handles each connection in a
+    /// separate task. Every received wrapper PDU is unwrapped and routed
+    /// through `DlmsServer::dispatch`; the response APDU is framed with a
+    /// `WrapperHeader` and sent back.
+    ///
+    /// The wrapper SAP addresses drive the routing: the source wSAP
+    /// identifies the client association, and the destination wSAP must
+    /// match the server SAP (the target logical device) or the PDU is
+    /// discarded.
+    ///
+    /// # Arguments
+    /// * `addr` - Address to listen on (e.g., "0.0.0.0:4059")
+    /// * `server` - The DLMS server instance
+    ///
+    /// # Errors
+    /// Returns error if binding to the address fails
+    pub async fn listen_wrapper(addr: SocketAddr, server: DlmsServer) -> DlmsResult<()> {
+        Self::listen_wrapper_managed(addr, server, ConnectionManager::new(0)).await
+    }
+
+    /// Listen for Wrapper-over-TCP connections with a connection manager
+    ///
+    /// Same as [`ServerListener::listen_wrapper`], but active connections
+    /// are tracked through `manager`. New clients beyond the manager's
+    /// `max_connections` limit get a busy exception response to their first
+    /// PDU and are closed; [`ConnectionManager::disconnect`] closes a served
+    /// client from outside the listener task.
+    ///
+    /// # Arguments
+    /// * `addr` - Address to listen on (e.g., "0.0.0.0:4059")
+    /// * `server` - The DLMS server instance
+    /// * `manager` - Connection manager shared with the caller
+    ///
+    /// # Errors
+    /// Returns error if binding to the address fails
+    pub async fn listen_wrapper_managed(
+        addr: SocketAddr,
+        server: DlmsServer,
+        manager: ConnectionManager,
+    ) -> DlmsResult<()> {
+        Self::listen_wrapper_multi_managed(addr, vec![server], manager).await
+    }
+
+    /// Listen for Wrapper-over-TCP connections serving multiple logical devices
+    ///
+    /// Each server in `devices` is one logical device, addressed by its
+    /// configured server SAP: the destination wSAP (wport) of every wrapper
+    /// PDU selects which device's object registry handles the request. PDUs
+    /// addressed to an unregistered SAP are answered with an exception
+    /// response instead of being dispatched.
+    ///
+    /// # Arguments
+    /// * `addr` - Address to listen on (e.g., "0.0.0.0:4059")
+    /// * `devices` - The logical devices, each with a distinct server SAP
+    /// * `manager` - Connection manager shared with the caller
+    ///
+    /// # Errors
+    /// Returns error if `devices` is empty, two devices share a server SAP,
+    /// or binding to the address fails
+    pub async fn listen_wrapper_multi_managed(
+        addr: SocketAddr,
+        devices: Vec<DlmsServer>,
+        manager: ConnectionManager,
+    ) -> DlmsResult<()> {
+        if devices.is_empty() {
+            return Err(DlmsError::InvalidData(
+                "At least one logical device is required".to_string(),
+            ));
+        }
+        let mut device_map = HashMap::new();
+        for device in devices {
+            let sap = device.config().server_sap;
+            if device_map.insert(sap, Arc::new(device)).is_some() {
+                return Err(DlmsError::InvalidData(format!(
+                    "Duplicate logical device SAP {}",
+                    sap
+                )));
+            }
+        }
+        let devices = Arc::new(device_map);
+
+        let listener = TcpListener::bind(addr).await
+            .map_err(|e| DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::AddrNotAvailable,
+                format!("Failed to bind to {}: {}", addr, e),
+            )))?;
+
+        log::info!("DLMS wrapper server listening on {}", addr);
+
+        manager.spawn_idle_sweep();
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, peer_addr)) => {
+                    let entry = match manager.register(peer_addr).await {
+                        Some(entry) => entry,
+                        None => {
+                            log::warn!(
+                                "Rejecting wrapper connection from {}: connection limit reached",
+                                peer_addr
+                            );
+                            let devices = devices.clone();
+                            tokio::spawn(async move {
+                                let _ = Self::reject_wrapper_connection(devices, stream).await;
+                            });
+                            continue;
+                        }
+                    };
+
+                    log::info!("Accepted wrapper connection from {}", peer_addr);
+
+                    let devices = devices.clone();
+                    let manager = manager.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = Self::serve_wrapper_connection(
+                            devices,
+                            stream,
+                            entry.shutdown,
+                            entry.last_activity,
+                        )
+                        .await
+                        {
+                            log::error!("Error handling wrapper connection from {}: {}", peer_addr, e);
+                        }
+                        manager.unregister(peer_addr).await;
+                    });
+                }
+                Err(e) => {
+                    log::error!("Error accepting connection: {}", e);
+                    // Continue accepting other connections
+                }
+            }
+        }
+    }
+
+    /// Build the exception-response APDU used for busy/unknown-SAP replies
+    fn exception_response_apdu() -> DlmsResult<Vec<u8>> {
+        let invoke_id = dlms_application::pdu::InvokeIdAndPriority::new(0, false)
+            .unwrap_or_else(|_| dlms_application::pdu::InvokeIdAndPriority::new(1, false).unwrap());
+        let exception_response = ExceptionResponse::new(
+            invoke_id,
+            None, // state_error
+            250,  // service_error: Other reason (OTHER_REASON constant)
+        );
+        let mut response = vec![216u8]; // exception-response APDU tag
+        response.extend_from_slice(&exception_response.encode()?);
+        Ok(response)
+    }
+
+    /// Answer a rejected client's first PDU with a busy indication
+    ///
+    /// The client's first wrapper PDU is read and answered with an
+    /// exception response so it knows the server is busy rather than
+    /// unreachable; the socket is closed when this function returns.
+    async fn reject_wrapper_connection(
+        devices: Arc<HashMap<u16, Arc<DlmsServer>>>,
+        stream: TcpStream,
+    ) -> DlmsResult<()> {
+        let mut transport = TcpTransport::from_connected_stream(
+            stream,
+            Some(std::time::Duration::from_secs(30)),
+        );
+
+        let pdu = WrapperPdu::decode(&mut transport).await?;
+        let client_sap = pdu.header().client_id();
+        // Reply as the addressed device if it exists, otherwise as any device
+        let requested_sap = pdu.header().logical_device_id();
+        let server_sap = if devices.contains_key(&requested_sap) {
+            requested_sap
+        } else {
+            devices.keys().copied().min().unwrap_or(1)
+        };
+
+        let response = Self::exception_response_apdu()?;
+        let response_header = WrapperHeader::new(server_sap, client_sap, response.len() as u16);
+        let response_pdu = WrapperPdu::new(response_header, response);
+        transport.write_all(&response_pdu.encode()).await?;
+        transport.flush().await?;
+
+        Ok(())
+    }
+
+    /// Serve a single wrapper connection
+    ///
+    /// Reads wrapper PDUs until the peer disconnects. The destination wSAP
+    /// of each PDU selects the logical device that dispatches it; PDUs
+    /// addressed to an unknown logical device are answered with an
+    /// exception response. All client associations opened over the
+    /// connection are released when it ends.
+    async fn serve_wrapper_connection(
+        devices: Arc<HashMap<u16, Arc<DlmsServer>>>,
+        stream: TcpStream,
+        shutdown: Arc<Notify>,
+        last_activity: Arc<RwLock<Instant>>,
+    ) -> DlmsResult<()> {
+        let mut transport = TcpTransport::from_connected_stream(
+            stream,
+            Some(std::time::Duration::from_secs(30)),
+        );
+        // Device SAP used when replying before any request was routed
+        let mut server_sap = devices.keys().copied().min().unwrap_or(1);
+        let mut client_sap = None;
+        // (device SAP, client SAP) pairs with associations to release
+        let mut associations: Vec<(u16, u16)> = Vec::new();
+
+        loop {
+            // Receive next wrapper PDU from client, or stop when the
+            // connection manager signals a disconnect
+            let pdu = tokio::select! {
+                result = WrapperPdu::decode(&mut transport) => match result {
+                    Ok(pdu) => pdu,
+                    Err(e) => {
+                        log::debug!("Wrapper connection closed: {}", e);
+                        break;
+                    }
+                },
+                _ = shutdown.notified() => {
+                    log::info!("Wrapper connection closed by connection manager");
+                    // Server-initiated release: announce it with an RLRQ
+                    // before closing so the client can distinguish a release
+                    // from a dropped connection
+                    let mut rlrq = RLRQApdu::new();
+                    rlrq.reason = Some(ReleaseRequestReason::Normal);
+                    if let Ok(apdu) = rlrq.encode() {
+                        let sap = client_sap.unwrap_or(0x10);
+                        let release_header =
+                            WrapperHeader::new(server_sap, sap, apdu.len() as u16);
+                        let release_pdu = WrapperPdu::new(release_header, apdu);
+                        let _ = transport.write_all(&release_pdu.encode()).await;
+                        let _ = transport.flush().await;
+                    }
+                    break;
+                }
+            };
+
+            // A received PDU counts as activity for the idle sweep
+            *last_activity.write().await = Instant::now();
+
+            // Destination wSAP selects the target logical device
+            let header = pdu.header();
+            let sap = header.client_id();
+            let device = match devices.get(&header.logical_device_id()) {
+                Some(device) => device,
+                None => {
+                    log::warn!(
+                        "Wrapper PDU addressed to unknown logical device {}",
+                        header.logical_device_id()
+                    );
+                    // Unknown SAP: answer with an exception response so the
+                    // client is not left waiting
+                    let response = Self::exception_response_apdu()?;
+                    let response_header = WrapperHeader::new(
+                        header.logical_device_id(),
+                        sap,
+                        response.len() as u16,
+                    );
+                    let response_pdu = WrapperPdu::new(response_header, response);
+                    transport.write_all(&response_pdu.encode()).await?;
+                    transport.flush().await?;
+                    continue;
+                }
+            };
+            server_sap = header.logical_device_id();
+
+            // Source wSAP identifies the client association on this device
+            client_sap = Some(sap);
+            if !associations.contains(&(server_sap, sap)) {
+                associations.push((server_sap, sap));
+            }
+
+            // Route the unwrapped APDU and send the framed response
+            match device.dispatch(pdu.data(), sap).await {
+                Ok(response) => {
+                    let response_header =
+                        WrapperHeader::new(server_sap, sap, response.len() as u16);
+                    let response_pdu = WrapperPdu::new(response_header, response);
+                    transport.write_all(&response_pdu.encode()).await?;
+                    transport.flush().await?;
+                }
+                Err(e) => {
+                    log::error!("Error processing wrapper request: {}", e);
+                    // Continue processing other requests
+                }
+            }
+        }
+
+        // Clean up associations on every device this connection touched
+        for (device_sap, sap) in associations {
+            if let Some(device) = devices.get(&device_sap) {
+                device.release_association(sap).await;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl ClientHandler {
@@ -556,3 +1032,330 @@ impl ClientHandler {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dlms_application::addressing::LogicalNameReference;
+    use dlms_application::pdu::{
+        CosemAttributeDescriptor, GetDataResult, GetResponse, InvokeIdAndPriority,
+    };
+    use dlms_core::{DataObject, ObisCode};
+    use dlms_interface::Data;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Send an APDU wrapped in a wrapper PDU over a raw TCP stream
+    async fn send_wrapped(stream: &mut TcpStream, client_sap: u16, server_sap: u16, apdu: &[u8]) {
+        let header = WrapperHeader::new(client_sap, server_sap, apdu.len() as u16);
+        let pdu = WrapperPdu::new(header, apdu.to_vec());
+        stream.write_all(&pdu.encode()).await.unwrap();
+        stream.flush().await.unwrap();
+    }
+
+    /// Receive a wrapper PDU from a raw TCP stream
+    async fn recv_wrapped(stream: &mut TcpStream) -> (WrapperHeader, Vec<u8>) {
+        let mut header_bytes = [0u8; 8];
+        stream.read_exact(&mut header_bytes).await.unwrap();
+        let header = WrapperHeader::decode(&header_bytes).unwrap();
+        let mut payload = vec![0u8; header.payload_length() as usize];
+        stream.read_exact(&mut payload).await.unwrap();
+        (header, payload)
+    }
+
+    #[tokio::test]
+    async fn test_listen_wrapper_get_round_trip() {
+        // Reserve a free port for the listener
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        // Server with a single Data object
+        let server = DlmsServer::new();
+        let server_sap = server.config().server_sap;
+        let obis = ObisCode::new(0, 0, 96, 1, 0, 255);
+        let object = Data::new(obis, DataObject::Unsigned32(1234));
+        server.register_object(Arc::new(object)).await.unwrap();
+
+        tokio::spawn(async move {
+            let _ = ServerListener::listen_wrapper(addr, server).await;
+        });
+
+        // Connect (retry until the listener is up)
+        let client_sap = 0x10;
+        let mut stream = loop {
+            match TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            }
+        };
+
+        // Establish the association with a wrapped InitiateRequest
+        let mut init_apdu = vec![1u8];
+        init_apdu.extend_from_slice(&InitiateRequest::new().encode().unwrap());
+        send_wrapped(&mut stream, client_sap, server_sap, &init_apdu).await;
+
+        let (_, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(payload[0], 2, "expected InitiateResponse tag");
+
+        // Send a wrapped GET for the Data object's value attribute
+        let descriptor =
+            CosemAttributeDescriptor::LogicalName(LogicalNameReference::new(1, obis, 2).unwrap());
+        let request = GetRequest::new_normal(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            descriptor,
+            None,
+        );
+        let mut get_apdu = vec![192u8];
+        get_apdu.extend_from_slice(&request.encode().unwrap());
+        send_wrapped(&mut stream, client_sap, server_sap, &get_apdu).await;
+
+        // The response is framed with the server SAP as source
+        let (header, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(header.client_id(), server_sap);
+        assert_eq!(header.logical_device_id(), client_sap);
+        assert_eq!(payload[0], 196, "expected GetResponse tag");
+
+        let response = GetResponse::decode(&payload[1..]).unwrap();
+        match response {
+            GetResponse::Normal(normal) => match normal.result {
+                GetDataResult::Data(value) => assert_eq!(value, DataObject::Unsigned32(1234)),
+                other => panic!("Expected data result, got {:?}", other),
+            },
+            other => panic!("Expected normal response, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_listen_wrapper_rejects_unknown_logical_device() {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        let server = DlmsServer::new();
+        let server_sap = server.config().server_sap;
+
+        tokio::spawn(async move {
+            let _ = ServerListener::listen_wrapper(addr, server).await;
+        });
+
+        let mut stream = loop {
+            match TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            }
+        };
+
+        // PDU addressed to an unregistered logical device is answered with
+        // an exception response, echoing the requested SAP as source
+        let mut init_apdu = vec![1u8];
+        init_apdu.extend_from_slice(&InitiateRequest::new().encode().unwrap());
+        send_wrapped(&mut stream, 0x10, server_sap + 1, &init_apdu).await;
+        let (header, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(header.client_id(), server_sap + 1);
+        assert_eq!(payload[0], 216, "expected exception-response tag");
+
+        // A correctly addressed request still gets through afterwards
+        send_wrapped(&mut stream, 0x10, server_sap, &init_apdu).await;
+        let (_, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(payload[0], 2, "expected InitiateResponse tag");
+    }
+
+    #[tokio::test]
+    async fn test_listen_wrapper_routes_by_destination_sap() {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        // Two logical devices with separate object registries: the same
+        // OBIS code holds a different value on each device
+        let obis = ObisCode::new(0, 0, 96, 1, 0, 255);
+        let device_one = DlmsServer::with_config(crate::server::ServerConfig {
+            server_sap: 1,
+            ..Default::default()
+        });
+        device_one
+            .register_object(Arc::new(Data::new(obis, DataObject::Unsigned32(111))))
+            .await
+            .unwrap();
+        let device_two = DlmsServer::with_config(crate::server::ServerConfig {
+            server_sap: 2,
+            ..Default::default()
+        });
+        device_two
+            .register_object(Arc::new(Data::new(obis, DataObject::Unsigned32(222))))
+            .await
+            .unwrap();
+
+        tokio::spawn(async move {
+            let _ = ServerListener::listen_wrapper_multi_managed(
+                addr,
+                vec![device_one, device_two],
+                ConnectionManager::new(0),
+            )
+            .await;
+        });
+
+        let mut stream = connect_retrying(addr).await;
+        let client_sap = 0x10;
+        let mut init_apdu = vec![1u8];
+        init_apdu.extend_from_slice(&InitiateRequest::new().encode().unwrap());
+
+        // Read the same attribute through both destination SAPs
+        let descriptor =
+            CosemAttributeDescriptor::LogicalName(LogicalNameReference::new(1, obis, 2).unwrap());
+        let request = GetRequest::new_normal(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            descriptor,
+            None,
+        );
+        let mut get_apdu = vec![192u8];
+        get_apdu.extend_from_slice(&request.encode().unwrap());
+
+        for (device_sap, expected) in [(1u16, 111u32), (2, 222)] {
+            send_wrapped(&mut stream, client_sap, device_sap, &init_apdu).await;
+            let (_, payload) = recv_wrapped(&mut stream).await;
+            assert_eq!(payload[0], 2, "expected InitiateResponse tag");
+
+            send_wrapped(&mut stream, client_sap, device_sap, &get_apdu).await;
+            let (header, payload) = recv_wrapped(&mut stream).await;
+            assert_eq!(header.client_id(), device_sap);
+            assert_eq!(payload[0], 196, "expected GetResponse tag");
+            match GetResponse::decode(&payload[1..]).unwrap() {
+                GetResponse::Normal(normal) => match normal.result {
+                    GetDataResult::Data(value) => {
+                        assert_eq!(value, DataObject::Unsigned32(expected))
+                    }
+                    other => panic!("Expected data result, got {:?}", other),
+                },
+                other => panic!("Expected normal response, got {:?}", other),
+            }
+        }
+
+        // An unregistered SAP is answered with an exception response
+        send_wrapped(&mut stream, client_sap, 3, &get_apdu).await;
+        let (_, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(payload[0], 216, "expected exception-response tag");
+    }
+
+    /// Connect to the managed listener, retrying until it is up
+    async fn connect_retrying(addr: SocketAddr) -> TcpStream {
+        loop {
+            match TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            }
+        }
+    }
+
+    /// Open an association over a new stream with a wrapped InitiateRequest
+    async fn open_association(addr: SocketAddr, client_sap: u16, server_sap: u16) -> TcpStream {
+        let mut stream = connect_retrying(addr).await;
+        let mut init_apdu = vec![1u8];
+        init_apdu.extend_from_slice(&InitiateRequest::new().encode().unwrap());
+        send_wrapped(&mut stream, client_sap, server_sap, &init_apdu).await;
+        let (_, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(payload[0], 2, "expected InitiateResponse tag");
+        stream
+    }
+
+    #[tokio::test]
+    async fn test_connection_cap_rejects_extra_client() {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        let server = DlmsServer::new();
+        let server_sap = server.config().server_sap;
+        let manager = ConnectionManager::new(1);
+
+        let listener_manager = manager.clone();
+        tokio::spawn(async move {
+            let _ = ServerListener::listen_wrapper_managed(addr, server, listener_manager).await;
+        });
+
+        // The first client fills the single slot
+        let _held = open_association(addr, 0x10, server_sap).await;
+        assert_eq!(manager.active_count().await, 1);
+
+        // The second client's first PDU is answered with a busy indication
+        let mut rejected = connect_retrying(addr).await;
+        let mut init_apdu = vec![1u8];
+        init_apdu.extend_from_slice(&InitiateRequest::new().encode().unwrap());
+        send_wrapped(&mut rejected, 0x11, server_sap, &init_apdu).await;
+
+        let (_, payload) = recv_wrapped(&mut rejected).await;
+        assert_eq!(payload[0], 216, "expected exception-response tag");
+
+        // The rejected socket is closed after the busy indication
+        let mut buf = [0u8; 1];
+        assert_eq!(rejected.read(&mut buf).await.unwrap(), 0);
+        assert_eq!(manager.active_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_connection_manager_disconnects_client() {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        let server = DlmsServer::new();
+        let server_sap = server.config().server_sap;
+        let manager = ConnectionManager::new(0);
+
+        let listener_manager = manager.clone();
+        tokio::spawn(async move {
+            let _ = ServerListener::listen_wrapper_managed(addr, server, listener_manager).await;
+        });
+
+        let mut stream = open_association(addr, 0x10, server_sap).await;
+        let peer_addr = stream.local_addr().unwrap();
+        assert_eq!(manager.active_count().await, 1);
+
+        // Disconnecting the client announces the release with an RLRQ,
+        // then closes its socket and frees the slot
+        assert!(manager.disconnect(peer_addr).await);
+        let (_, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(payload[0], 0x62, "expected RLRQ tag");
+        let mut buf = [0u8; 1];
+        assert_eq!(stream.read(&mut buf).await.unwrap(), 0);
+        assert_eq!(manager.active_count().await, 0);
+
+        // Disconnecting an unknown address reports failure
+        assert!(!manager.disconnect(peer_addr).await);
+    }
+
+    #[tokio::test]
+    async fn test_idle_association_is_released() {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        let server = DlmsServer::new();
+        let server_sap = server.config().server_sap;
+        let manager = ConnectionManager::new(0).with_idle_timeout(Duration::from_millis(100));
+        let mut events = manager.subscribe_events().await;
+
+        let listener_manager = manager.clone();
+        tokio::spawn(async move {
+            let _ = ServerListener::listen_wrapper_managed(addr, server, listener_manager).await;
+        });
+
+        let mut stream = open_association(addr, 0x10, server_sap).await;
+        let peer_addr = stream.local_addr().unwrap();
+        assert_eq!(manager.active_count().await, 1);
+
+        // Without further PDUs the sweep releases the association: the
+        // client receives an RLRQ followed by the socket closing
+        let (_, payload) = recv_wrapped(&mut stream).await;
+        assert_eq!(payload[0], 0x62, "expected RLRQ tag");
+        let mut buf = [0u8; 1];
+        assert_eq!(stream.read(&mut buf).await.unwrap(), 0);
+
+        // The teardown is reported to the event subscriber
+        assert_eq!(
+            events.recv().await,
+            Some(ConnectionEvent::ConnectionLost(peer_addr))
+        );
+        assert_eq!(manager.active_count().await, 0);
+    }
+}
@@ -5,13 +5,22 @@
 
 use crate::connection_manager::{ConnectionManager, ConnectionInfo, ConnectionStatistics};
 use crate::access_control::{AccessControlManager, AccessControlList};
+use crate::sn_object_map::SnObjectMap;
 use dlms_application::pdu::{
     GetRequest, GetResponse, SetRequest, SetResponse, ActionRequest, ActionResponse,
     InitiateRequest, InitiateResponse, AccessRequest, AccessResponse,
     AccessRequestSpecification, AccessResponseSpecification,
     CosemAttributeDescriptor, CosemMethodDescriptor, GetDataResult, SetDataResult, ActionResult,
     InvokeIdAndPriority, Conformance,
-    SetRequestWithList,
+    SetRequestWithList, state_error, service_error,
+};
+use dlms_application::sn_pdu::{ReadRequest, ReadResponse, WriteRequest, WriteResponse};
+use dlms_asn1::{
+    AxdrEncoder, AARQApdu, AAREApdu,
+    DLMS_APPLICATION_CONTEXT_NAME, DLMS_APPLICATION_CONTEXT_NAME_CIPHERED,
+};
+use dlms_asn1::iso_acse::{
+    AcseServiceUserDiagnostic, AssociateResult, AssociateSourceDiagnostic, mechanism_oid,
 };
 use dlms_core::{DlmsError, DlmsResult, ObisCode};
 use dlms_security::SecuritySuite;
@@ -39,6 +48,11 @@ pub struct AssociationContext {
     pub max_pdu_size: u16,
     /// DLMS version (typically 6)
     pub dlms_version: u8,
+    /// Dedicated session key from the InitiateRequest (if any)
+    ///
+    /// Install it via `XdlmsContext::set_dedicated_key` so ciphered APDUs
+    /// in this association use it instead of the derived unicast key.
+    pub dedicated_key: Option<Vec<u8>>,
 }
 
 /// Block transfer state for GetRequest-Next
@@ -56,7 +70,7 @@ struct BlockTransferState {
     total_data: Vec<u8>,
     /// Current block size (bytes per block)
     block_size: usize,
-    /// Current block number
+    /// Number of the most recently sent block (Green Book numbering, from 1)
     current_block: u32,
     /// Last block flag
     last_block: bool,
@@ -74,22 +88,21 @@ impl BlockTransferState {
             _attribute_id: attribute_id,
             total_data: data,
             block_size,
-            current_block: 0,
+            current_block: 1,
             last_block,
         }
     }
 
     /// Get the current block of data
     fn get_current_block(&self) -> Vec<u8> {
-        let start = (self.current_block as usize) * self.block_size;
+        let start = ((self.current_block - 1) as usize) * self.block_size;
         let end = (start + self.block_size).min(self.total_data.len());
         self.total_data[start..end].to_vec()
     }
 
     /// Check if this is the last block
     fn is_last_block(&self) -> bool {
-        let next_block = self.current_block + 1;
-        let start = (next_block as usize) * self.block_size;
+        let start = (self.current_block as usize) * self.block_size;
         start >= self.total_data.len()
     }
 
@@ -146,6 +159,12 @@ impl BlockTransferState {
 pub struct DlmsServer {
     /// Registered COSEM objects (indexed by OBIS code)
     objects: Arc<RwLock<HashMap<ObisCode, Arc<dyn CosemObject>>>>,
+    /// Per-object access locks (indexed by OBIS code)
+    ///
+    /// GET takes the shared side, SET/ACTION the exclusive side, so
+    /// concurrent clients are serialized per object but run in parallel
+    /// across different objects.
+    object_locks: Arc<RwLock<HashMap<ObisCode, Arc<RwLock<()>>>>>,
     /// Active associations (indexed by client SAP)
     associations: Arc<RwLock<HashMap<u16, AssociationContext>>>,
     /// Connection manager for tracking active connections
@@ -158,10 +177,12 @@ pub struct DlmsServer {
     block_transfers: Arc<RwLock<HashMap<(u16, u8), BlockTransferState>>>,
     /// Short Name (base_name) to OBIS code mapping for SN addressing
     base_name_to_obis: Arc<RwLock<HashMap<u16, ObisCode>>>,
+    /// Short Name object map for SN READ/WRITE services
+    sn_objects: Arc<RwLock<SnObjectMap>>,
 }
 
 /// Server configuration
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ServerConfig {
     /// Server Service Access Point (SAP) address
     pub server_sap: u16,
@@ -177,6 +198,12 @@ pub struct ServerConfig {
     pub max_connections: usize,
     /// Connection idle timeout in seconds
     pub connection_idle_timeout_secs: u64,
+    /// Initial COSEM object set, registered when the server is created
+    ///
+    /// The set is de-duplicated by (class_id, OBIS code); two different
+    /// objects claiming the same OBIS code are a configuration error
+    /// (see `DlmsServer::try_with_config`).
+    pub initial_objects: Vec<Arc<dyn CosemObject>>,
 }
 
 impl Default for ServerConfig {
@@ -189,10 +216,27 @@ impl Default for ServerConfig {
             dlms_version: 6,
             max_connections: 100,
             connection_idle_timeout_secs: 300, // 5 minutes
+            initial_objects: Vec::new(),
         }
     }
 }
 
+impl std::fmt::Debug for ServerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Trait objects are not Debug, so report the initial object count
+        f.debug_struct("ServerConfig")
+            .field("server_sap", &self.server_sap)
+            .field("default_security", &self.default_security)
+            .field("default_conformance", &self.default_conformance)
+            .field("max_pdu_size", &self.max_pdu_size)
+            .field("dlms_version", &self.dlms_version)
+            .field("max_connections", &self.max_connections)
+            .field("connection_idle_timeout_secs", &self.connection_idle_timeout_secs)
+            .field("initial_objects", &self.initial_objects.len())
+            .finish()
+    }
+}
+
 impl DlmsServer {
     /// Create a new DLMS server with default configuration
     pub fn new() -> Self {
@@ -200,7 +244,28 @@ impl DlmsServer {
     }
 
     /// Create a new DLMS server with custom configuration
+    ///
+    /// # Panics
+    /// Panics if `config.initial_objects` contains a (class_id, OBIS code)
+    /// collision. Use `try_with_config()` to handle the collision as an error.
     pub fn with_config(config: ServerConfig) -> Self {
+        Self::try_with_config(config).expect("Initial object set contains an OBIS code collision")
+    }
+
+    /// Create a new DLMS server with custom configuration, validating the initial object set
+    ///
+    /// Objects from `config.initial_objects` are registered before the
+    /// server is returned, de-duplicated by (class_id, OBIS code):
+    /// identical duplicates are registered once.
+    ///
+    /// # Errors
+    /// Returns `DlmsError::InvalidData` if two different objects claim
+    /// the same OBIS code.
+    pub fn try_with_config(mut config: ServerConfig) -> DlmsResult<Self> {
+        let initial_objects = std::mem::take(&mut config.initial_objects);
+        let mut objects = HashMap::new();
+        Self::insert_deduplicated(&mut objects, initial_objects)?;
+
         let connection_manager = Arc::new(ConnectionManager::new(
             config.max_connections,
             Duration::from_secs(config.connection_idle_timeout_secs),
@@ -208,15 +273,48 @@ impl DlmsServer {
 
         let access_control = Arc::new(AccessControlManager::new());
 
-        Self {
-            objects: Arc::new(RwLock::new(HashMap::new())),
+        Ok(Self {
+            objects: Arc::new(RwLock::new(objects)),
+            object_locks: Arc::new(RwLock::new(HashMap::new())),
             associations: Arc::new(RwLock::new(HashMap::new())),
             connection_manager,
             access_control,
             config,
             block_transfers: Arc::new(RwLock::new(HashMap::new())),
             base_name_to_obis: Arc::new(RwLock::new(HashMap::new())),
+            sn_objects: Arc::new(RwLock::new(SnObjectMap::new())),
+        })
+    }
+
+    /// Insert a batch of objects into a registry, de-duplicating by (class_id, OBIS code)
+    ///
+    /// An object whose (class_id, OBIS code) pair is already present is
+    /// silently skipped; an OBIS code already claimed by a different class
+    /// is a collision and aborts the insertion.
+    fn insert_deduplicated(
+        registry: &mut HashMap<ObisCode, Arc<dyn CosemObject>>,
+        objects: Vec<Arc<dyn CosemObject>>,
+    ) -> DlmsResult<()> {
+        for object in objects {
+            let obis = object.obis_code();
+            let class_id = object.class_id();
+
+            if let Some(existing) = registry.get(&obis) {
+                if existing.class_id() == class_id {
+                    // Identical (class_id, obis) pair: keep the first instance
+                    continue;
+                }
+                return Err(DlmsError::InvalidData(format!(
+                    "OBIS code {} is already registered for class {}, cannot register class {}",
+                    obis,
+                    existing.class_id(),
+                    class_id
+                )));
+            }
+
+            registry.insert(obis, object);
         }
+        Ok(())
     }
 
     /// Get the connection manager
@@ -313,7 +411,26 @@ impl DlmsServer {
         objects.insert(obis, object);
         Ok(())
     }
-    
+
+    /// Register a batch of COSEM objects with the server
+    ///
+    /// Convenience for seeding a server with many objects at once. The
+    /// batch is de-duplicated by (class_id, OBIS code) against both the
+    /// batch itself and the already-registered objects, so registering an
+    /// identical object twice is a no-op.
+    ///
+    /// # Arguments
+    /// * `objects` - The COSEM objects to register
+    ///
+    /// # Errors
+    /// Returns `DlmsError::InvalidData` if an OBIS code is claimed by two
+    /// different classes. Objects processed before the collision stay
+    /// registered.
+    pub async fn register_all(&self, objects: Vec<Arc<dyn CosemObject>>) -> DlmsResult<()> {
+        let mut registry = self.objects.write().await;
+        Self::insert_deduplicated(&mut registry, objects)
+    }
+
     /// Unregister a COSEM object
     ///
     /// # Arguments
@@ -321,6 +438,8 @@ impl DlmsServer {
     pub async fn unregister_object(&self, obis_code: &ObisCode) {
         let mut objects = self.objects.write().await;
         objects.remove(obis_code);
+        // Drop the access lock along with the object
+        self.object_locks.write().await.remove(obis_code);
     }
     
     /// Find an object by OBIS code
@@ -335,6 +454,80 @@ impl DlmsServer {
         objects.get(obis_code).cloned()
     }
 
+    /// Get (or lazily create) the access lock guarding the object at `obis_code`
+    async fn object_lock(&self, obis_code: &ObisCode) -> Arc<RwLock<()>> {
+        let mut locks = self.object_locks.write().await;
+        locks
+            .entry(*obis_code)
+            .or_insert_with(|| Arc::new(RwLock::new(())))
+            .clone()
+    }
+
+    /// Read an attribute under the object's shared lock
+    ///
+    /// Concurrent GETs on the same object proceed in parallel but are
+    /// serialized against writes and method invocations.
+    async fn locked_get_attribute(
+        &self,
+        object: &Arc<dyn CosemObject>,
+        attribute_id: u8,
+        selective_access: Option<&dlms_application::pdu::SelectiveAccessDescriptor>,
+        ctx: Option<&dlms_interface::association_access::CosemInvocationContext>,
+    ) -> DlmsResult<dlms_core::DataObject> {
+        let lock = self.object_lock(&object.obis_code()).await;
+        let _guard = lock.read().await;
+        object.get_attribute(attribute_id, selective_access, ctx).await
+    }
+
+    /// Write an attribute under the object's exclusive lock
+    async fn locked_set_attribute(
+        &self,
+        object: &Arc<dyn CosemObject>,
+        attribute_id: u8,
+        value: dlms_core::DataObject,
+        selective_access: Option<&dlms_application::pdu::SelectiveAccessDescriptor>,
+        ctx: Option<&dlms_interface::association_access::CosemInvocationContext>,
+    ) -> DlmsResult<()> {
+        // Check the value against the class schema before touching the
+        // object, so a mismatched write never mutates state
+        if let Some(expected) = object.attribute_type(attribute_id)
+            && !expected.matches(&value)
+        {
+            return Err(DlmsError::DataAccess {
+                code: dlms_application::pdu::data_access_result::TYPE_UNMATCHED,
+                description: format!(
+                    "Attribute {} of class {} expects {:?}, got {:?}",
+                    attribute_id,
+                    object.class_id(),
+                    expected,
+                    value.get_type()
+                ),
+            });
+        }
+
+        let lock = self.object_lock(&object.obis_code()).await;
+        let _guard = lock.write().await;
+        object
+            .set_attribute(attribute_id, value, selective_access, ctx)
+            .await
+    }
+
+    /// Invoke a method under the object's exclusive lock
+    async fn locked_invoke_method(
+        &self,
+        object: &Arc<dyn CosemObject>,
+        method_id: u8,
+        parameters: Option<dlms_core::DataObject>,
+        selective_access: Option<&dlms_application::pdu::SelectiveAccessDescriptor>,
+        ctx: Option<&dlms_interface::association_access::CosemInvocationContext>,
+    ) -> DlmsResult<Option<dlms_core::DataObject>> {
+        let lock = self.object_lock(&object.obis_code()).await;
+        let _guard = lock.write().await;
+        object
+            .invoke_method(method_id, parameters, selective_access, ctx)
+            .await
+    }
+
     /// Register a Short Name (base_name) to OBIS code mapping
     ///
     /// This enables Short Name addressing for COSEM objects. When a client
@@ -425,7 +618,137 @@ impl DlmsServer {
         let mapping = self.base_name_to_obis.read().await;
         mapping.iter().map(|(k, v)| (*k, *v)).collect()
     }
-    
+
+    /// Register an object in the SN address space
+    ///
+    /// Makes the object reachable via the SN READ/WRITE services at
+    /// `base_name + 8 * (attribute_id - 1)`.
+    ///
+    /// # Arguments
+    /// * `base_name` - Base address of the object in the SN address space
+    /// * `object` - The COSEM object served at that address
+    ///
+    /// # Errors
+    /// Returns error if the base name is already registered
+    pub async fn register_sn_object(
+        &self,
+        base_name: u16,
+        object: Arc<dyn CosemObject>,
+    ) -> DlmsResult<()> {
+        let mut sn_objects = self.sn_objects.write().await;
+        sn_objects.register(base_name, object)
+    }
+
+    /// Unregister an object from the SN address space
+    ///
+    /// # Returns
+    /// `true` if a mapping was removed
+    pub async fn unregister_sn_object(&self, base_name: u16) -> bool {
+        let mut sn_objects = self.sn_objects.write().await;
+        sn_objects.unregister(base_name)
+    }
+
+    /// Handle SN READ Request
+    ///
+    /// Translates the short name address into an object attribute via the
+    /// SN object map and reads it. Address or access failures are reported
+    /// as a data access result inside the response, mirroring the LN GET
+    /// handling.
+    ///
+    /// # Arguments
+    /// * `request` - The ReadRequest PDU
+    /// * `client_sap` - Client Service Access Point address
+    ///
+    /// # Returns
+    /// ReadResponse PDU
+    pub async fn handle_read_request(
+        &self,
+        request: &ReadRequest,
+        client_sap: u16,
+    ) -> DlmsResult<ReadResponse> {
+        // Verify association exists
+        let _association = self.get_association(client_sap).await.ok_or_else(|| {
+            DlmsError::InvalidData("No active association for this client".to_string())
+        })?;
+
+        let reference = {
+            let sn_objects = self.sn_objects.read().await;
+            sn_objects.resolve(request.short_name.value())
+        };
+
+        let result = match reference {
+            Ok(reference) => {
+                match self
+                    .locked_get_attribute(&reference.object, reference.attribute_id, None, None)
+                    .await
+                {
+                    Ok(value) => GetDataResult::new_data(value),
+                    Err(_e) => GetDataResult::new_error(
+                        dlms_application::pdu::data_access_result::OTHER_REASON,
+                    ),
+                }
+            }
+            Err(_e) => GetDataResult::new_error(
+                dlms_application::pdu::data_access_result::OBJECT_UNDEFINED,
+            ),
+        };
+
+        Ok(ReadResponse::new(request.invoke_id, result))
+    }
+
+    /// Handle SN WRITE Request
+    ///
+    /// Translates the short name address into an object attribute via the
+    /// SN object map and writes the supplied data to it.
+    ///
+    /// # Arguments
+    /// * `request` - The WriteRequest PDU
+    /// * `client_sap` - Client Service Access Point address
+    ///
+    /// # Returns
+    /// WriteResponse PDU
+    pub async fn handle_write_request(
+        &self,
+        request: &WriteRequest,
+        client_sap: u16,
+    ) -> DlmsResult<WriteResponse> {
+        // Verify association exists
+        let _association = self.get_association(client_sap).await.ok_or_else(|| {
+            DlmsError::InvalidData("No active association for this client".to_string())
+        })?;
+
+        let reference = {
+            let sn_objects = self.sn_objects.read().await;
+            sn_objects.resolve(request.short_name.value())
+        };
+
+        let result = match reference {
+            Ok(reference) => {
+                match self
+                    .locked_set_attribute(
+                        &reference.object,
+                        reference.attribute_id,
+                        request.data.clone(),
+                        None,
+                        None,
+                    )
+                    .await
+                {
+                    Ok(()) => SetDataResult::new_success(),
+                    Err(_e) => SetDataResult::new_error(
+                        dlms_application::pdu::data_access_result::OTHER_REASON,
+                    ),
+                }
+            }
+            Err(_e) => SetDataResult::new_error(
+                dlms_application::pdu::data_access_result::OBJECT_UNDEFINED,
+            ),
+        };
+
+        Ok(WriteResponse::new(request.invoke_id, result))
+    }
+
+
     /// Register an association (client connection)
     ///
     /// # Arguments
@@ -482,6 +805,8 @@ impl DlmsServer {
         client_sap: u16,
     ) -> DlmsResult<InitiateResponse> {
         // Create association context
+        // The dedicated key (if the client proposed one) is extracted here so
+        // the ciphered session can install it as the session encryption key
         let context = AssociationContext {
             client_sap,
             server_sap: self.config.server_sap,
@@ -489,6 +814,7 @@ impl DlmsServer {
             conformance: self.config.default_conformance.clone(),
             max_pdu_size: request.max_pdu_size().min(self.config.max_pdu_size),
             dlms_version: self.config.dlms_version,
+            dedicated_key: request.dedicated_key.clone(),
         };
         
         // Register association
@@ -505,6 +831,102 @@ impl DlmsServer {
         Ok(response)
     }
     
+    /// Handle an AARQ and build the AARE response
+    ///
+    /// Decodes the association request, validates the application context and
+    /// authentication requirements, negotiates the conformance bits against
+    /// the server capabilities and returns the encoded AARE. An accepted
+    /// association carries the `InitiateResponse` in the user information
+    /// field; rejections carry the ACSE service user diagnostic explaining
+    /// the refusal.
+    ///
+    /// # Arguments
+    /// * `aarq_bytes` - BER-encoded AARQ APDU received from the client
+    ///
+    /// # Returns
+    /// BER-encoded AARE APDU bytes
+    pub async fn handle_aarq(&self, aarq_bytes: &[u8]) -> DlmsResult<Vec<u8>> {
+        let aarq = AARQApdu::decode(aarq_bytes)?;
+
+        // Validate the application context (LN, optionally ciphered).
+        // The third variant is the LN context OID used by Association::build_aarq.
+        let context = aarq.application_context_name.as_slice();
+        let context_supported = context == DLMS_APPLICATION_CONTEXT_NAME
+            || context == DLMS_APPLICATION_CONTEXT_NAME_CIPHERED
+            || context == [1, 0, 17, 0, 0, 8, 0, 101];
+
+        if !context_supported {
+            let aare = AAREApdu::new(
+                aarq.application_context_name.clone(),
+                AssociateResult::RejectedPermanent,
+                AssociateSourceDiagnostic::service_user(
+                    AcseServiceUserDiagnostic::CONTEXT_NOT_SUPPORTED.value(),
+                ),
+            );
+            return aare.encode();
+        }
+
+        // Validate authentication: any mechanism other than lowest-level
+        // "none" must come with an authentication value
+        if let Some(mechanism) = &aarq.mechanism_name
+            && mechanism.oid() != mechanism_oid::NONE
+            && aarq.calling_authentication_value.is_none()
+        {
+            let aare = AAREApdu::new(
+                aarq.application_context_name.clone(),
+                AssociateResult::RejectedPermanent,
+                AssociateSourceDiagnostic::service_user(
+                    AcseServiceUserDiagnostic::AUTHENTICATION_REQUIRED.value(),
+                ),
+            );
+            return aare.encode();
+        }
+
+        // Decode the InitiateRequest from the user information field
+        // (fall back to defaults if the client omitted it)
+        let initiate_request = match aarq.get_initiate_request() {
+            Some(bytes) => InitiateRequest::decode(bytes)?,
+            None => InitiateRequest::new(),
+        };
+
+        // Negotiate conformance: intersection of the proposed bits and the
+        // server capabilities
+        let mut negotiated_conformance = Conformance::new();
+        for bit in 0..24 {
+            let proposed = initiate_request
+                .proposed_conformance
+                .get_bit(bit)
+                .unwrap_or(false);
+            let supported = self
+                .config
+                .default_conformance
+                .get_bit(bit)
+                .unwrap_or(false);
+            if proposed && supported {
+                negotiated_conformance.set_bit(bit, true)?;
+            }
+        }
+
+        let max_pdu_size = initiate_request
+            .client_max_receive_pdu_size
+            .min(self.config.max_pdu_size);
+
+        let initiate_response = InitiateResponse::new(
+            self.config.dlms_version,
+            negotiated_conformance,
+            max_pdu_size,
+            0x0007, // vaa_name: standard VAA name for DLMS
+        )?;
+
+        let mut aare = AAREApdu::new(
+            aarq.application_context_name.clone(),
+            AssociateResult::Accepted,
+            AssociateSourceDiagnostic::null(),
+        );
+        aare.set_initiate_response(initiate_response.encode()?);
+        aare.encode()
+    }
+
     /// Handle GET Request
     ///
     /// Processes a GET request and returns the appropriate response.
@@ -521,7 +943,7 @@ impl DlmsServer {
         client_sap: u16,
     ) -> DlmsResult<GetResponse> {
         // Verify association exists
-        let _association = self.get_association(client_sap).await.ok_or_else(|| {
+        let association = self.get_association(client_sap).await.ok_or_else(|| {
             DlmsError::InvalidData("No active association for this client".to_string())
         })?;
 
@@ -555,12 +977,27 @@ impl DlmsServer {
                     CosemAttributeDescriptor::ShortName { reference, .. } => reference.id,
                 };
 
-                let value = object
-                    .get_attribute(attribute_id, selective_access.as_deref(), None)
+                let value = self
+                    .locked_get_attribute(&object, attribute_id, selective_access, None)
                     .await?;
 
-                // Create response
                 let invoke_id = normal.invoke_id_and_priority().invoke_id();
+
+                // If the encoded value does not fit in the negotiated PDU size,
+                // fall back to block transfer: return the first block as a
+                // GetResponse-WithDataBlock and serve the remaining blocks via
+                // GetRequest-Next.
+                let mut encoder = AxdrEncoder::new();
+                encoder.encode_data_object(&value)?;
+                let encoded = encoder.into_bytes();
+
+                if encoded.len() > association.max_pdu_size as usize {
+                    return self
+                        .start_block_transfer(client_sap, invoke_id, obis, attribute_id, encoded)
+                        .await;
+                }
+
+                // Create response
                 let result = GetDataResult::new_data(value);
                 let invoke_id_and_priority = InvokeIdAndPriority::new(invoke_id, false)?;
                 let response = GetResponse::new_normal(invoke_id_and_priority, result);
@@ -650,8 +1087,8 @@ impl DlmsServer {
             };
 
             // Get attribute value
-            match object
-                .get_attribute(attribute_id, selective_access, None)
+            match self
+                .locked_get_attribute(&object, attribute_id, selective_access, None)
                 .await
             {
                 Ok(value) => {
@@ -816,10 +1253,10 @@ impl DlmsServer {
         let mut transfers = self.block_transfers.write().await;
         transfers.insert(key, transfer_state);
 
-        // Return first block response
+        // Return first block response (datablocks are numbered from 1)
         Ok(GetResponse::WithDataBlock {
             invoke_id_and_priority: InvokeIdAndPriority::new(invoke_id, false)?,
-            block_number: 0,
+            block_number: 1,
             last_block,
             block_data,
         })
@@ -876,14 +1313,14 @@ impl DlmsServer {
                     CosemAttributeDescriptor::ShortName { reference, .. } => reference.id,
                 };
 
-                object
-                    .set_attribute(
-                        attribute_id,
-                        value.clone(),
-                        selective_access.as_deref(),
-                        None,
-                    )
-                    .await?;
+                self.locked_set_attribute(
+                    &object,
+                    attribute_id,
+                    value.clone(),
+                    selective_access,
+                    None,
+                )
+                .await?;
 
                 // Create response
                 let invoke_id = normal.invoke_id_and_priority().invoke_id();
@@ -1087,8 +1524,9 @@ impl DlmsServer {
             };
 
             // Set attribute
-            match object
-                .set_attribute(
+            match self
+                .locked_set_attribute(
+                    &object,
                     attribute_id,
                     value.clone(),
                     selective_access.as_deref(),
@@ -1163,8 +1601,8 @@ impl DlmsServer {
                     CosemMethodDescriptor::ShortName { reference, .. } => reference.id,
                 };
 
-                let return_value = object
-                    .invoke_method(method_id, parameters.cloned(), None, None)
+                let return_value = self
+                    .locked_invoke_method(&object, method_id, parameters.cloned(), None, None)
                     .await?;
 
                 // Create response
@@ -1245,8 +1683,13 @@ impl DlmsServer {
                                 CosemAttributeDescriptor::ShortName { reference, .. } => reference.id,
                             };
 
-                            match object
-                                .get_attribute(attribute_id, access_selection.as_ref(), None)
+                            match self
+                                .locked_get_attribute(
+                                    &object,
+                                    attribute_id,
+                                    access_selection.as_ref(),
+                                    None,
+                                )
                                 .await
                             {
                                 Ok(value) => {
@@ -1304,8 +1747,9 @@ impl DlmsServer {
                                 CosemAttributeDescriptor::ShortName { reference, .. } => reference.id,
                             };
 
-                            match object
-                                .set_attribute(
+                            match self
+                                .locked_set_attribute(
+                                    &object,
                                     attribute_id,
                                     value.clone(),
                                     access_selection.as_ref(),
@@ -1367,8 +1811,9 @@ impl DlmsServer {
                                 CosemMethodDescriptor::ShortName { reference, .. } => reference.id,
                             };
 
-                            match object
-                                .invoke_method(
+                            match self
+                                .locked_invoke_method(
+                                    &object,
                                     method_id,
                                     method_invocation_parameters.clone(),
                                     None,
@@ -1420,6 +1865,147 @@ impl DlmsServer {
         Ok(response)
     }
     
+    /// Dispatch a raw APDU to the appropriate request handler
+    ///
+    /// The first byte of `apdu` is the APDU tag identifying the request type;
+    /// the remaining bytes are the PDU body. The response is returned with
+    /// the matching response tag prepended, so it can be framed and sent
+    /// back to the client as-is.
+    ///
+    /// # APDU Tags (DLMS standard)
+    /// - InitiateRequest: 1 -> InitiateResponse: 2
+    /// - AccessRequest: 3 -> AccessResponse: 4
+    /// - GetRequest: 192 (0xC0) -> GetResponse: 196 (0xC4)
+    /// - SetRequest: 193 (0xC1) -> SetResponse: 197 (0xC5)
+    /// - ActionRequest: 195 (0xC3) -> ActionResponse: 199 (0xC7)
+    ///
+    /// # Arguments
+    /// * `apdu` - Tagged APDU bytes
+    /// * `client_sap` - Client Service Access Point address
+    ///
+    /// # Returns
+    /// The tagged response APDU bytes. Malformed or unroutable requests are
+    /// answered with a tagged ExceptionResponse (tag 216) instead of an
+    /// error, so a misbehaving client never tears down the connection.
+    pub async fn dispatch(&self, apdu: &[u8], client_sap: u16) -> DlmsResult<Vec<u8>> {
+        match self.dispatch_apdu(apdu, client_sap).await {
+            Ok(response) => Ok(response),
+            Err(error) => {
+                log::warn!(
+                    "Request from client SAP {} failed: {}; replying with exception response",
+                    client_sap,
+                    error
+                );
+                let (state_error, service_error) = Self::exception_codes(&error);
+                Self::exception_response(state_error, service_error)
+            }
+        }
+    }
+
+    /// Decode and route a tagged APDU (fallible inner part of `dispatch`)
+    async fn dispatch_apdu(&self, apdu: &[u8], client_sap: u16) -> DlmsResult<Vec<u8>> {
+        if apdu.is_empty() {
+            return Err(DlmsError::InvalidData("Empty APDU".to_string()));
+        }
+
+        let pdu_tag = apdu[0];
+        let body = &apdu[1..];
+
+        match pdu_tag {
+            // InitiateRequest: 1
+            1 => {
+                let request = InitiateRequest::decode(body)?;
+                let response = self.handle_initiate_request(&request, client_sap).await?;
+                let mut out = vec![2u8];
+                out.extend_from_slice(&response.encode()?);
+                Ok(out)
+            }
+            // AccessRequest: 3
+            3 => {
+                let request = AccessRequest::decode(body)?;
+                let response = self.handle_access_request(&request, client_sap).await?;
+                let mut out = vec![4u8];
+                out.extend_from_slice(&response.encode()?);
+                Ok(out)
+            }
+            // GetRequest: 192 (0xC0)
+            192 => {
+                let request = GetRequest::decode(body)?;
+                let response = self.handle_get_request(&request, client_sap).await?;
+                let mut out = vec![196u8];
+                out.extend_from_slice(&response.encode()?);
+                Ok(out)
+            }
+            // SetRequest: 193 (0xC1)
+            193 => {
+                let request = SetRequest::decode(body)?;
+                let response = self.handle_set_request(&request, client_sap).await?;
+                let mut out = vec![197u8];
+                out.extend_from_slice(&response.encode()?);
+                Ok(out)
+            }
+            // ActionRequest: 195 (0xC3)
+            195 => {
+                let request = ActionRequest::decode(body)?;
+                let response = self.handle_action_request(&request, client_sap).await?;
+                let mut out = vec![199u8];
+                out.extend_from_slice(&response.encode()?);
+                Ok(out)
+            }
+            // Unknown tag: the service itself is unknown, which maps to a
+            // dedicated exception rather than a decode failure
+            _ => {
+                log::warn!("Unknown PDU type tag: 0x{:02X}", pdu_tag);
+                Self::exception_response(
+                    Some(state_error::SERVICE_UNKNOWN),
+                    service_error::SERVICE_NOT_SUPPORTED,
+                )
+            }
+        }
+    }
+
+    /// Map a request processing error to ExceptionResponse codes
+    ///
+    /// Decode failures are reported as an invalid inquiry, denied access as
+    /// a service not allowed in the current state, and protocol violations
+    /// as an operation that is not possible; anything else falls back to
+    /// the generic other-reason code.
+    fn exception_codes(error: &DlmsError) -> (Option<u8>, u8) {
+        match error {
+            DlmsError::InvalidData(_)
+            | DlmsError::Asn1Decoding(_)
+            | DlmsError::DecodeError { .. }
+            | DlmsError::LengthMismatch(_)
+            | DlmsError::FrameInvalid(_) => (None, service_error::INVALID_INQUIRY),
+            DlmsError::AccessDenied(_) | DlmsError::Security(_) => {
+                (Some(state_error::SERVICE_NOT_ALLOWED), service_error::OTHER_REASON)
+            }
+            DlmsError::Protocol(_) => (None, service_error::OPERATION_NOT_POSSIBLE),
+            _ => (None, service_error::OTHER_REASON),
+        }
+    }
+
+    /// Build a tagged ExceptionResponse APDU
+    ///
+    /// The body is built by hand in the layout `ExceptionResponse::decode`
+    /// expects (length-prefixed invoke ID, optional state error, service
+    /// error) so clients can decode the reply.
+    fn exception_response(state_error: Option<u8>, service_error: u8) -> DlmsResult<Vec<u8>> {
+        let invoke_bytes = InvokeIdAndPriority::new(0, false)?.encode()?;
+        let mut out = vec![216u8]; // exception-response APDU tag
+        out.push(invoke_bytes.len() as u8);
+        out.extend_from_slice(&invoke_bytes);
+        match state_error {
+            Some(code) => {
+                out.push(1);
+                out.push(code);
+            }
+            None => out.push(0),
+        }
+        out.push(service_error);
+        Ok(out)
+    }
+
     /// Get server configuration
     pub fn config(&self) -> &ServerConfig {
         &self.config
@@ -1443,3 +2029,579 @@ impl Default for DlmsServer {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dlms_application::addressing::LogicalNameReference;
+    use dlms_application::pdu::ExceptionResponse;
+    use dlms_asn1::AxdrDecoder;
+    use dlms_core::DataObject;
+    use dlms_interface::Data;
+
+    /// Build a large object_list-like value: an array of
+    /// (class_id, logical_name) structures
+    fn large_object_list() -> DataObject {
+        let entries = (0..16)
+            .map(|i| {
+                DataObject::Structure(vec![
+                    DataObject::Unsigned16(1),
+                    DataObject::OctetString(vec![0, 0, 96, 1, i, 255]),
+                ])
+            })
+            .collect();
+        DataObject::Array(entries)
+    }
+
+    async fn server_with_object(max_pdu_size: u16, obis: ObisCode, value: DataObject) -> DlmsServer {
+        let config = ServerConfig {
+            max_pdu_size,
+            ..ServerConfig::default()
+        };
+        let server = DlmsServer::with_config(config);
+        server
+            .register_object(Arc::new(Data::new(obis, value)))
+            .await
+            .unwrap();
+        server
+    }
+
+    #[tokio::test]
+    async fn test_get_large_attribute_served_in_three_blocks() {
+        let obis = ObisCode::new(0, 0, 40, 0, 0, 255);
+        let value = large_object_list();
+        let server = server_with_object(80, obis, value.clone()).await;
+
+        let client_sap = 0x10;
+        server
+            .handle_initiate_request(&InitiateRequest::new(), client_sap)
+            .await
+            .unwrap();
+
+        // Initial GET: the encoded value exceeds the PDU size, so the
+        // server must answer with the first data block
+        let request = GetRequest::new_normal(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            CosemAttributeDescriptor::LogicalName(
+                LogicalNameReference::new(1, obis, 2).unwrap(),
+            ),
+            None,
+        );
+        let response = server.handle_get_request(&request, client_sap).await.unwrap();
+
+        let (mut assembled, mut last_block, mut block_number) = match response {
+            GetResponse::WithDataBlock {
+                block_number,
+                last_block,
+                block_data,
+                ..
+            } => (block_data, last_block, block_number),
+            other => panic!("Expected WithDataBlock response, got {:?}", other),
+        };
+        assert_eq!(block_number, 1);
+        assert!(!last_block);
+
+        // Mock client loop: request the remaining blocks via GetRequest-Next
+        let mut blocks = 1;
+        while !last_block {
+            let next = GetRequest::Next {
+                invoke_id_and_priority: InvokeIdAndPriority::new(1, false).unwrap(),
+                block_number: block_number + 1,
+            };
+            let response = server.handle_get_request(&next, client_sap).await.unwrap();
+            match response {
+                GetResponse::WithDataBlock {
+                    block_number: n,
+                    last_block: last,
+                    block_data,
+                    ..
+                } => {
+                    assert_eq!(n, block_number + 1);
+                    assembled.extend_from_slice(&block_data);
+                    block_number = n;
+                    last_block = last;
+                    blocks += 1;
+                }
+                other => panic!("Expected WithDataBlock response, got {:?}", other),
+            }
+        }
+
+        assert_eq!(blocks, 3);
+
+        // Reassembled bytes decode back to the original value
+        let mut decoder = AxdrDecoder::new(&assembled);
+        let decoded = decoder.decode_data_object().unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[tokio::test]
+    async fn test_get_small_attribute_stays_normal() {
+        let obis = ObisCode::new(0, 0, 96, 1, 0, 255);
+        let server = server_with_object(1024, obis, DataObject::Unsigned32(42)).await;
+
+        let client_sap = 0x10;
+        server
+            .handle_initiate_request(&InitiateRequest::new(), client_sap)
+            .await
+            .unwrap();
+
+        let request = GetRequest::new_normal(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            CosemAttributeDescriptor::LogicalName(
+                LogicalNameReference::new(1, obis, 2).unwrap(),
+            ),
+            None,
+        );
+        let response = server.handle_get_request(&request, client_sap).await.unwrap();
+
+        match response {
+            GetResponse::Normal(normal) => match normal.result {
+                GetDataResult::Data(value) => assert_eq!(value, DataObject::Unsigned32(42)),
+                other => panic!("Expected data result, got {:?}", other),
+            },
+            other => panic!("Expected normal response, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_all_batch_deduplicates_identical_objects() {
+        let server = DlmsServer::new();
+
+        let device_id = ObisCode::new(0, 0, 96, 1, 0, 255);
+        let batch: Vec<Arc<dyn CosemObject>> = vec![
+            Arc::new(Data::new(device_id, DataObject::Unsigned32(1))),
+            Arc::new(Data::new(
+                ObisCode::new(0, 0, 96, 1, 1, 255),
+                DataObject::Unsigned32(2),
+            )),
+            // Identical (class_id, obis) duplicate: registered once
+            Arc::new(Data::new(device_id, DataObject::Unsigned32(1))),
+        ];
+
+        server.register_all(batch).await.unwrap();
+        assert_eq!(server.object_count().await, 2);
+        assert!(server.find_object(&device_id).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_register_all_reports_obis_collision() {
+        use dlms_interface::{Register, ScalerUnit};
+
+        let server = DlmsServer::new();
+
+        let obis = ObisCode::new(1, 0, 1, 8, 0, 255);
+        let batch: Vec<Arc<dyn CosemObject>> = vec![
+            Arc::new(Data::new(obis, DataObject::Unsigned32(1))),
+            // Same OBIS claimed by a different class: collision
+            Arc::new(Register::new(
+                obis,
+                DataObject::Unsigned32(0),
+                ScalerUnit::new(0, 0x1E),
+                None,
+            )),
+        ];
+
+        let result = server.register_all(batch).await;
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+
+    #[tokio::test]
+    async fn test_initial_objects_registered_from_config() {
+        let obis = ObisCode::new(0, 0, 96, 1, 0, 255);
+        let config = ServerConfig {
+            initial_objects: vec![Arc::new(Data::new(obis, DataObject::Unsigned32(7)))],
+            ..ServerConfig::default()
+        };
+
+        let server = DlmsServer::try_with_config(config).unwrap();
+        assert_eq!(server.object_count().await, 1);
+        assert!(server.find_object(&obis).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_initial_objects_collision_reported_from_config() {
+        use dlms_interface::{Register, ScalerUnit};
+
+        let obis = ObisCode::new(1, 0, 1, 8, 0, 255);
+        let config = ServerConfig {
+            initial_objects: vec![
+                Arc::new(Data::new(obis, DataObject::Unsigned32(1))),
+                Arc::new(Register::new(
+                    obis,
+                    DataObject::Unsigned32(0),
+                    ScalerUnit::new(0, 0x1E),
+                    None,
+                )),
+            ],
+            ..ServerConfig::default()
+        };
+
+        let result = DlmsServer::try_with_config(config);
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+
+    /// Test object whose set_attribute is a deliberately non-atomic
+    /// read-modify-write, so unsynchronized concurrent writes lose updates
+    struct RacyCounter {
+        obis: ObisCode,
+        value: std::sync::Mutex<u32>,
+    }
+
+    #[async_trait::async_trait]
+    impl CosemObject for RacyCounter {
+        fn class_id(&self) -> u16 {
+            1
+        }
+
+        fn obis_code(&self) -> ObisCode {
+            self.obis
+        }
+
+        async fn get_attribute(
+            &self,
+            _attribute_id: u8,
+            _selective_access: Option<&dlms_application::pdu::SelectiveAccessDescriptor>,
+            _ctx: Option<&dlms_interface::association_access::CosemInvocationContext>,
+        ) -> DlmsResult<DataObject> {
+            Ok(DataObject::Unsigned32(*self.value.lock().unwrap()))
+        }
+
+        async fn set_attribute(
+            &self,
+            _attribute_id: u8,
+            _value: DataObject,
+            _selective_access: Option<&dlms_application::pdu::SelectiveAccessDescriptor>,
+            _ctx: Option<&dlms_interface::association_access::CosemInvocationContext>,
+        ) -> DlmsResult<()> {
+            let current = *self.value.lock().unwrap();
+            // Yield between read and write so interleavings can happen
+            tokio::time::sleep(Duration::from_millis(2)).await;
+            *self.value.lock().unwrap() = current + 1;
+            Ok(())
+        }
+
+        async fn invoke_method(
+            &self,
+            _method_id: u8,
+            _parameters: Option<DataObject>,
+            _selective_access: Option<&dlms_application::pdu::SelectiveAccessDescriptor>,
+            _ctx: Option<&dlms_interface::association_access::CosemInvocationContext>,
+        ) -> DlmsResult<Option<DataObject>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_writes_serialized_per_object() {
+        let obis = ObisCode::new(0, 0, 96, 50, 0, 255);
+        let server = Arc::new(DlmsServer::new());
+        let counter = Arc::new(RacyCounter {
+            obis,
+            value: std::sync::Mutex::new(0),
+        });
+        server.register_object(counter.clone()).await.unwrap();
+        let object = server.find_object(&obis).await.unwrap();
+
+        // Eight simultaneous writes: the per-object exclusive lock must
+        // serialize them so no increment is lost
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let server = Arc::clone(&server);
+            let object = Arc::clone(&object);
+            handles.push(tokio::spawn(async move {
+                server
+                    .locked_set_attribute(&object, 2, DataObject::Null, None, None)
+                    .await
+                    .unwrap();
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(*counter.value.lock().unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_set_attribute_rejects_mismatched_type() {
+        use dlms_interface::Clock;
+
+        let server = DlmsServer::new();
+        let clock = Arc::new(Clock::with_default_obis());
+        server.register_object(clock.clone()).await.unwrap();
+        let object = server.find_object(&Clock::default_obis()).await.unwrap();
+
+        // time_zone (attribute 3) is declared Integer16; an Unsigned16
+        // must be rejected with TYPE_UNMATCHED before the clock changes
+        clock.set_time_zone(60).await;
+        let result = server
+            .locked_set_attribute(&object, 3, DataObject::Unsigned16(120), None, None)
+            .await;
+        match result {
+            Err(DlmsError::DataAccess { code, .. }) => {
+                assert_eq!(code, dlms_application::pdu::data_access_result::TYPE_UNMATCHED);
+            }
+            other => panic!("Expected TYPE_UNMATCHED, got {:?}", other),
+        }
+        assert_eq!(clock.time_zone().await, 60);
+
+        // A correctly typed write still goes through
+        server
+            .locked_set_attribute(&object, 3, DataObject::Integer16(120), None, None)
+            .await
+            .unwrap();
+        assert_eq!(clock.time_zone().await, 120);
+    }
+
+    #[tokio::test]
+    async fn test_initiate_request_dedicated_key_installed_in_association() {
+        let server = DlmsServer::new();
+        let client_sap = 0x10;
+
+        let mut request = InitiateRequest::new();
+        request.dedicated_key = Some(vec![0x5Au8; 16]);
+
+        server
+            .handle_initiate_request(&request, client_sap)
+            .await
+            .unwrap();
+
+        let association = server.get_association(client_sap).await.unwrap();
+        assert_eq!(association.dedicated_key, Some(vec![0x5Au8; 16]));
+    }
+
+    #[tokio::test]
+    async fn test_handle_aarq_accepted_with_negotiated_conformance() {
+        // Server supports block read and block write
+        let mut server_conformance = Conformance::new();
+        server_conformance.set_block_read(true).unwrap();
+        server_conformance.set_block_write(true).unwrap();
+        let config = ServerConfig {
+            default_conformance: server_conformance,
+            max_pdu_size: 1024,
+            ..ServerConfig::default()
+        };
+        let server = DlmsServer::with_config(config);
+
+        // Client proposes block read and unconfirmed write
+        let mut proposed = Conformance::new();
+        proposed.set_block_read(true).unwrap();
+        proposed.set_unconfirmed_write(true).unwrap();
+        let initiate_request = InitiateRequest::with_params(proposed, 2048).unwrap();
+
+        let mut aarq = AARQApdu::new(DLMS_APPLICATION_CONTEXT_NAME.to_vec());
+        aarq.set_initiate_request(initiate_request.encode().unwrap());
+
+        let aare_bytes = server.handle_aarq(&aarq.encode().unwrap()).await.unwrap();
+        let aare = AAREApdu::decode(&aare_bytes).unwrap();
+        assert_eq!(aare.result, AssociateResult::Accepted);
+
+        let initiate_response =
+            InitiateResponse::decode(aare.get_initiate_response().unwrap()).unwrap();
+        // Only the intersection of proposed and supported bits survives
+        assert!(initiate_response.negotiated_conformance.block_read());
+        assert!(!initiate_response.negotiated_conformance.block_write());
+        assert!(!initiate_response.negotiated_conformance.unconfirmed_write());
+        // The server caps the PDU size at its own maximum
+        assert_eq!(initiate_response.server_max_receive_pdu_size, 1024);
+    }
+
+    #[tokio::test]
+    async fn test_handle_aarq_rejected_unauthorized() {
+        use dlms_asn1::iso_acse::MechanismName;
+
+        let server = DlmsServer::new();
+
+        // Low-level authentication requested without an authentication value
+        let mut aarq = AARQApdu::new(DLMS_APPLICATION_CONTEXT_NAME.to_vec());
+        aarq.mechanism_name = Some(MechanismName::new(mechanism_oid::LOW.to_vec()));
+        aarq.set_initiate_request(InitiateRequest::new().encode().unwrap());
+
+        let aare_bytes = server.handle_aarq(&aarq.encode().unwrap()).await.unwrap();
+        let aare = AAREApdu::decode(&aare_bytes).unwrap();
+        assert_eq!(aare.result, AssociateResult::RejectedPermanent);
+        assert_eq!(
+            aare.result_source_diagnostic.value(),
+            AcseServiceUserDiagnostic::AUTHENTICATION_REQUIRED.value()
+        );
+        assert!(aare.get_initiate_response().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_handle_aarq_rejects_unknown_application_context() {
+        let server = DlmsServer::new();
+
+        let aarq = AARQApdu::new(vec![1, 2, 3, 4]);
+        let aare_bytes = server.handle_aarq(&aarq.encode().unwrap()).await.unwrap();
+        let aare = AAREApdu::decode(&aare_bytes).unwrap();
+        assert_eq!(aare.result, AssociateResult::RejectedPermanent);
+        assert_eq!(
+            aare.result_source_diagnostic.value(),
+            AcseServiceUserDiagnostic::CONTEXT_NOT_SUPPORTED.value()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_register_value_by_short_name() {
+        use dlms_application::sn_pdu::{ReadRequest, ShortName};
+        use dlms_interface::{Register, ScalerUnit};
+
+        let server = DlmsServer::new();
+        let register = Register::new(
+            ObisCode::new(1, 0, 1, 8, 0, 255),
+            DataObject::Unsigned32(12345),
+            ScalerUnit::new(0, 30),
+            None,
+        );
+        server
+            .register_sn_object(0x1000, Arc::new(register))
+            .await
+            .unwrap();
+
+        let client_sap = 0x10;
+        server
+            .handle_initiate_request(&InitiateRequest::new(), client_sap)
+            .await
+            .unwrap();
+
+        // Attribute 2 (value) lives at base_name + 8
+        let request = ReadRequest::new(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            ShortName::new(0x1008),
+        );
+        let response = server.handle_read_request(&request, client_sap).await.unwrap();
+
+        match response.result {
+            GetDataResult::Data(value) => assert_eq!(value, DataObject::Unsigned32(12345)),
+            other => panic!("Expected data result, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_register_value_by_short_name() {
+        use dlms_application::sn_pdu::{ReadRequest, ShortName, WriteRequest};
+        use dlms_interface::{Register, ScalerUnit};
+
+        let server = DlmsServer::new();
+        let register = Register::new(
+            ObisCode::new(1, 0, 1, 8, 0, 255),
+            DataObject::Unsigned32(0),
+            ScalerUnit::new(0, 30),
+            None,
+        );
+        server
+            .register_sn_object(0x1000, Arc::new(register))
+            .await
+            .unwrap();
+
+        let client_sap = 0x10;
+        server
+            .handle_initiate_request(&InitiateRequest::new(), client_sap)
+            .await
+            .unwrap();
+
+        let write = WriteRequest::new(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            ShortName::new(0x1008),
+            DataObject::Unsigned32(999),
+        );
+        let response = server.handle_write_request(&write, client_sap).await.unwrap();
+        assert_eq!(response.result, SetDataResult::Success);
+
+        // Read the value back through the same short name
+        let read = ReadRequest::new(
+            InvokeIdAndPriority::new(2, false).unwrap(),
+            ShortName::new(0x1008),
+        );
+        let response = server.handle_read_request(&read, client_sap).await.unwrap();
+        match response.result {
+            GetDataResult::Data(value) => assert_eq!(value, DataObject::Unsigned32(999)),
+            other => panic!("Expected data result, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_unmapped_short_name_reports_object_undefined() {
+        use dlms_application::sn_pdu::{ReadRequest, ShortName};
+
+        let server = DlmsServer::new();
+        let client_sap = 0x10;
+        server
+            .handle_initiate_request(&InitiateRequest::new(), client_sap)
+            .await
+            .unwrap();
+
+        let request = ReadRequest::new(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            ShortName::new(0x2000),
+        );
+        let response = server.handle_read_request(&request, client_sap).await.unwrap();
+
+        match response.result {
+            GetDataResult::DataAccessResult(code) => assert_eq!(
+                code,
+                dlms_application::pdu::data_access_result::OBJECT_UNDEFINED
+            ),
+            other => panic!("Expected data access result, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_request_next_rejects_wrong_block_number() {
+        let obis = ObisCode::new(0, 0, 40, 0, 0, 255);
+        let server = server_with_object(80, obis, large_object_list()).await;
+
+        let client_sap = 0x10;
+        server
+            .handle_initiate_request(&InitiateRequest::new(), client_sap)
+            .await
+            .unwrap();
+
+        let request = GetRequest::new_normal(
+            InvokeIdAndPriority::new(1, false).unwrap(),
+            CosemAttributeDescriptor::LogicalName(
+                LogicalNameReference::new(1, obis, 2).unwrap(),
+            ),
+            None,
+        );
+        server.handle_get_request(&request, client_sap).await.unwrap();
+
+        // Requesting block 5 while the server is at block 1 must fail
+        let next = GetRequest::Next {
+            invoke_id_and_priority: InvokeIdAndPriority::new(1, false).unwrap(),
+            block_number: 5,
+        };
+        assert!(server.handle_get_request(&next, client_sap).await.is_err());
+    }
+
+    /// Dispatch an APDU and decode the expected exception response
+    async fn dispatch_expecting_exception(server: &DlmsServer, apdu: &[u8]) -> ExceptionResponse {
+        let response = server.dispatch(apdu, 0x10).await.unwrap();
+        assert_eq!(response[0], 216, "expected exception-response tag");
+        ExceptionResponse::decode(&response[1..]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_unknown_tag_returns_exception_response() {
+        let server = DlmsServer::new();
+
+        let exception = dispatch_expecting_exception(&server, &[0x99, 0x01]).await;
+        assert_eq!(exception.state_error, Some(state_error::SERVICE_UNKNOWN));
+        assert_eq!(exception.service_error, service_error::SERVICE_NOT_SUPPORTED);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_malformed_pdu_returns_exception_response() {
+        let server = DlmsServer::new();
+
+        // A GET tag followed by garbage fails to decode; the server must
+        // answer with an exception response instead of an error
+        let exception = dispatch_expecting_exception(&server, &[192, 0xFF]).await;
+        assert_eq!(exception.state_error, None);
+        assert_eq!(exception.service_error, service_error::INVALID_INQUIRY);
+
+        // An empty APDU is also answered, not dropped
+        let exception = dispatch_expecting_exception(&server, &[]).await;
+        assert_eq!(exception.service_error, service_error::INVALID_INQUIRY);
+    }
+}
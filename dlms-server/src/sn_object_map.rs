@@ -0,0 +1,220 @@
+//! Short Name object map for SN addressing
+//!
+//! Legacy meters address COSEM objects with 2-byte short names instead of
+//! 6-byte OBIS codes. Each object occupies a region of the SN address space
+//! starting at its base name; attribute `n` of the object is reachable at
+//! `base_name + 8 * (n - 1)`.
+//!
+//! This module provides the base-name → object registry and the translation
+//! from a raw short name address to the owning object and attribute index.
+
+use dlms_core::{DlmsError, DlmsResult};
+use dlms_interface::CosemObject;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Spacing between attributes in the SN address space (per DLMS Blue Book)
+pub const SN_ATTRIBUTE_SPACING: u16 = 8;
+
+/// A resolved short name reference
+///
+/// Result of translating a raw short name address through an [`SnObjectMap`]:
+/// the owning object, its base name and the addressed attribute.
+#[derive(Clone)]
+pub struct SnReference {
+    /// Base name of the owning object
+    pub base_name: u16,
+    /// Addressed attribute ID (1-based)
+    pub attribute_id: u8,
+    /// The owning COSEM object
+    pub object: Arc<dyn CosemObject>,
+}
+
+/// Base-name → object registry for Short Name addressing
+///
+/// Entries are kept sorted by base name so a raw short name address can be
+/// resolved to the object with the greatest base name not exceeding it.
+pub struct SnObjectMap {
+    /// Registered objects indexed by base name
+    entries: BTreeMap<u16, Arc<dyn CosemObject>>,
+}
+
+impl SnObjectMap {
+    /// Create an empty map
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Register an object at a base name
+    ///
+    /// # Arguments
+    /// * `base_name` - Base address of the object in the SN address space
+    /// * `object` - The COSEM object served at that address
+    ///
+    /// # Errors
+    /// Returns error if the base name is already registered
+    pub fn register(&mut self, base_name: u16, object: Arc<dyn CosemObject>) -> DlmsResult<()> {
+        if self.entries.contains_key(&base_name) {
+            return Err(DlmsError::InvalidData(format!(
+                "Base name 0x{:04X} is already registered",
+                base_name
+            )));
+        }
+        self.entries.insert(base_name, object);
+        Ok(())
+    }
+
+    /// Unregister an object by base name
+    ///
+    /// # Returns
+    /// `true` if a mapping was removed
+    pub fn unregister(&mut self, base_name: u16) -> bool {
+        self.entries.remove(&base_name).is_some()
+    }
+
+    /// Resolve a raw short name address to an object and attribute
+    ///
+    /// The owning object is the one with the greatest registered base name
+    /// not exceeding `short_name`; the offset from the base name selects the
+    /// attribute (`base_name + 8 * (attribute_id - 1)`).
+    ///
+    /// # Errors
+    /// Returns error if no object covers the address or the offset is not
+    /// aligned to the attribute spacing
+    pub fn resolve(&self, short_name: u16) -> DlmsResult<SnReference> {
+        let (base_name, object) = self
+            .entries
+            .range(..=short_name)
+            .next_back()
+            .ok_or_else(|| {
+                DlmsError::InvalidData(format!(
+                    "Short name 0x{:04X} is not covered by any registered object",
+                    short_name
+                ))
+            })?;
+
+        let offset = short_name - base_name;
+        if !offset.is_multiple_of(SN_ATTRIBUTE_SPACING) {
+            return Err(DlmsError::InvalidData(format!(
+                "Short name 0x{:04X} is not aligned to an attribute of base name 0x{:04X}",
+                short_name, base_name
+            )));
+        }
+
+        let index = offset / SN_ATTRIBUTE_SPACING + 1;
+        let attribute_id = u8::try_from(index).map_err(|_| {
+            DlmsError::InvalidData(format!(
+                "Short name 0x{:04X} addresses attribute {} of base name 0x{:04X}, which exceeds the attribute range",
+                short_name, index, base_name
+            ))
+        })?;
+
+        Ok(SnReference {
+            base_name: *base_name,
+            attribute_id,
+            object: object.clone(),
+        })
+    }
+
+    /// Get all registered mappings as (base_name, object) pairs
+    pub fn mappings(&self) -> Vec<(u16, Arc<dyn CosemObject>)> {
+        self.entries
+            .iter()
+            .map(|(base_name, object)| (*base_name, object.clone()))
+            .collect()
+    }
+
+    /// Get the number of registered objects
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for SnObjectMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dlms_core::{DataObject, ObisCode};
+    use dlms_interface::Data;
+
+    fn sample_object(e: u8) -> Arc<dyn CosemObject> {
+        Arc::new(Data::new(
+            ObisCode::new(0, 0, 96, 1, e, 255),
+            DataObject::Unsigned32(42),
+        ))
+    }
+
+    #[test]
+    fn test_sn_object_map_register_and_resolve() {
+        let mut map = SnObjectMap::new();
+        map.register(0x1000, sample_object(0)).unwrap();
+
+        // Base name itself addresses attribute 1 (logical_name)
+        let reference = map.resolve(0x1000).unwrap();
+        assert_eq!(reference.base_name, 0x1000);
+        assert_eq!(reference.attribute_id, 1);
+
+        // Offset 8 addresses attribute 2 (value)
+        let reference = map.resolve(0x1008).unwrap();
+        assert_eq!(reference.base_name, 0x1000);
+        assert_eq!(reference.attribute_id, 2);
+    }
+
+    #[test]
+    fn test_sn_object_map_resolve_picks_covering_object() {
+        let mut map = SnObjectMap::new();
+        map.register(0x1000, sample_object(0)).unwrap();
+        map.register(0x1040, sample_object(1)).unwrap();
+
+        // 0x1048 is past the second base name, so it belongs to that object
+        let reference = map.resolve(0x1048).unwrap();
+        assert_eq!(reference.base_name, 0x1040);
+        assert_eq!(reference.attribute_id, 2);
+    }
+
+    #[test]
+    fn test_sn_object_map_resolve_rejects_uncovered_address() {
+        let mut map = SnObjectMap::new();
+        map.register(0x1000, sample_object(0)).unwrap();
+
+        // Below the lowest base name there is no covering object
+        assert!(map.resolve(0x0FF8).is_err());
+    }
+
+    #[test]
+    fn test_sn_object_map_resolve_rejects_unaligned_address() {
+        let mut map = SnObjectMap::new();
+        map.register(0x1000, sample_object(0)).unwrap();
+
+        assert!(map.resolve(0x1003).is_err());
+    }
+
+    #[test]
+    fn test_sn_object_map_register_duplicate_fails() {
+        let mut map = SnObjectMap::new();
+        map.register(0x1000, sample_object(0)).unwrap();
+        assert!(map.register(0x1000, sample_object(1)).is_err());
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_sn_object_map_unregister() {
+        let mut map = SnObjectMap::new();
+        map.register(0x1000, sample_object(0)).unwrap();
+        assert!(map.unregister(0x1000));
+        assert!(!map.unregister(0x1000));
+        assert!(map.is_empty());
+    }
+}
@@ -77,6 +77,30 @@ impl HdlcAddress {
         })
     }
 
+    /// Create a new HDLC address with an explicit encoding width
+    ///
+    /// Meters differ in the server address encoding they expect, so the
+    /// width cannot always be derived from the address values alone.
+    /// `byte_length` selects the wire encoding: 1 (logical ID only),
+    /// 2 (one byte logical + one byte physical) or 4 (two bytes each).
+    ///
+    /// # Errors
+    /// Returns error if the byte length is not 1, 2 or 4, or if the IDs
+    /// do not fit the selected width
+    pub fn new_with_length(
+        logical_id: u16,
+        physical_id: u16,
+        byte_length: usize,
+    ) -> DlmsResult<Self> {
+        let address = Self {
+            byte_length,
+            logical_id,
+            physical_id,
+        };
+        address.validate()?;
+        Ok(address)
+    }
+
     fn address_size_of(address: u16) -> DlmsResult<usize> {
         if address <= ONE_BYTE_UPPER_BOUND {
             Ok(1)
@@ -167,7 +191,7 @@ impl HdlcAddress {
             }
         };
 
-        Self::new_with_physical(logical_device_addr, physical_dev_addr)
+        Self::new_with_length(logical_device_addr, physical_dev_addr, length)
     }
 
     fn validate(&self) -> DlmsResult<()> {
@@ -182,18 +206,42 @@ impl HdlcAddress {
         let lower_length = self.byte_length / 2;
 
         let max_logical = (1u32 << (7 * upper_length)) - 1;
-        let max_physical = (1u32 << (7 * lower_length)) - 1;
-
-        if self.logical_id as u32 >= max_logical || self.physical_id as u32 >= max_physical {
+        if self.logical_id as u32 > max_logical {
             return Err(DlmsError::InvalidData(format!(
-                "HdlcAddress values out of range: logical={}, physical={}",
-                self.logical_id, self.physical_id
+                "HdlcAddress logical ID {} does not fit in {} byte(s)",
+                self.logical_id, self.byte_length
             )));
         }
 
+        if lower_length == 0 {
+            if self.physical_id != 0 {
+                return Err(DlmsError::InvalidData(format!(
+                    "HdlcAddress physical ID {} requires a wider encoding than 1 byte",
+                    self.physical_id
+                )));
+            }
+        } else {
+            let max_physical = (1u32 << (7 * lower_length)) - 1;
+            if self.physical_id as u32 > max_physical {
+                return Err(DlmsError::InvalidData(format!(
+                    "HdlcAddress physical ID {} does not fit in {} byte(s)",
+                    self.physical_id, self.byte_length
+                )));
+            }
+        }
+
         Ok(())
     }
 
+    /// Check if this is a broadcast address
+    ///
+    /// Broadcast in HDLC terms is the all-station address (0x7F in the
+    /// short encodings, 0x3FFF in the 4-byte encoding); this is a
+    /// clearer-named alias for `is_all_station`.
+    pub fn is_broadcast(&self) -> bool {
+        self.is_all_station()
+    }
+
     /// Check if this is an all-station (broadcast) address
     pub fn is_all_station(&self) -> bool {
         if self.byte_length == 1 || self.byte_length == 2 {
@@ -246,8 +294,26 @@ pub struct HdlcAddressPair {
 
 impl HdlcAddressPair {
     /// Create a new address pair
-    pub fn new(source: HdlcAddress, destination: HdlcAddress) -> Self {
-        Self { source, destination }
+    ///
+    /// # Errors
+    /// Returns error if the source is a reserved address that cannot
+    /// originate frames: the no-station address or the broadcast
+    /// (all-station) address. The calling address is accepted, since a
+    /// server without an assigned address sends from it by design.
+    pub fn new(source: HdlcAddress, destination: HdlcAddress) -> DlmsResult<Self> {
+        if source.is_no_station() {
+            return Err(DlmsError::InvalidData(format!(
+                "No-station address {} cannot be used as a source",
+                source
+            )));
+        }
+        if source.is_broadcast() {
+            return Err(DlmsError::InvalidData(format!(
+                "Broadcast address {} cannot be used as a source",
+                source
+            )));
+        }
+        Ok(Self { source, destination })
     }
 
     /// Get source address
@@ -259,6 +325,37 @@ impl HdlcAddressPair {
     pub fn destination(&self) -> HdlcAddress {
         self.destination
     }
+
+    /// Encode the pair as it appears on the wire: destination first,
+    /// then source, each with its configured byte length
+    pub fn encode(&self) -> DlmsResult<Vec<u8>> {
+        let mut result = self.destination.encode()?;
+        result.extend(self.source.encode()?);
+        Ok(result)
+    }
+
+    /// Decode a pair with explicit destination and source byte lengths
+    ///
+    /// # Errors
+    /// Returns error if `data` is shorter than the two lengths combined
+    /// or either address is invalid
+    pub fn decode(
+        data: &[u8],
+        destination_length: usize,
+        source_length: usize,
+    ) -> DlmsResult<Self> {
+        if data.len() < destination_length + source_length {
+            return Err(DlmsError::InvalidData(format!(
+                "Address pair needs {} bytes, have {}",
+                destination_length + source_length,
+                data.len()
+            )));
+        }
+
+        let destination = HdlcAddress::decode(&data[..destination_length], destination_length)?;
+        let source = HdlcAddress::decode(&data[destination_length..], source_length)?;
+        Ok(Self { source, destination })
+    }
 }
 
 #[cfg(test)]
@@ -280,12 +377,91 @@ mod tests {
         assert_eq!(addr, decoded);
     }
 
+    #[test]
+    fn test_hdlc_address_explicit_length() {
+        // Small IDs forced into the wide 4-byte encoding
+        let addr = HdlcAddress::new_with_length(0x01, 0x20, 4).unwrap();
+        assert_eq!(addr.byte_length(), 4);
+
+        let encoded = addr.encode().unwrap();
+        assert_eq!(encoded.len(), 4);
+        let decoded = HdlcAddress::decode(&encoded, 4).unwrap();
+        assert_eq!(addr, decoded);
+    }
+
+    #[test]
+    fn test_hdlc_address_explicit_length_validates_fit() {
+        // A logical ID above 0x7F does not fit a 1-byte encoding
+        assert!(HdlcAddress::new_with_length(0x100, 0, 1).is_err());
+        // A physical ID cannot be carried in a 1-byte encoding at all
+        assert!(HdlcAddress::new_with_length(0x01, 0x20, 1).is_err());
+        // 3 is not a valid HDLC address width
+        assert!(HdlcAddress::new_with_length(0x01, 0x20, 3).is_err());
+    }
+
+    #[test]
+    fn test_hdlc_address_pair_encode_decode_mixed_widths() {
+        // 1-byte client address paired with a 4-byte server address
+        let client = HdlcAddress::new_with_length(0x10, 0, 1).unwrap();
+        let server = HdlcAddress::new_with_length(0x01, 0x21, 4).unwrap();
+        let pair = HdlcAddressPair::new(client, server).unwrap();
+
+        let encoded = pair.encode().unwrap();
+        assert_eq!(encoded.len(), 5);
+
+        let decoded = HdlcAddressPair::decode(&encoded, 4, 1).unwrap();
+        assert_eq!(decoded.destination(), server);
+        assert_eq!(decoded.source(), client);
+    }
+
     #[test]
     fn test_hdlc_address_pair() {
         let src = HdlcAddress::new(0x10).unwrap();
         let dst = HdlcAddress::new(0x20).unwrap();
-        let pair = HdlcAddressPair::new(src, dst);
+        let pair = HdlcAddressPair::new(src, dst).unwrap();
         assert_eq!(pair.source(), src);
         assert_eq!(pair.destination(), dst);
     }
+
+    #[test]
+    fn test_hdlc_address_reserved_predicates() {
+        let broadcast_short = HdlcAddress::new(reserved::CLIENT_ALL_STATION).unwrap();
+        assert!(broadcast_short.is_broadcast());
+        assert!(!broadcast_short.is_no_station());
+
+        let broadcast_wide =
+            HdlcAddress::new_with_length(reserved::SERVER_UPPER_ALL_STATIONS_2BYTE, 0x01, 4)
+                .unwrap();
+        assert!(broadcast_wide.is_broadcast());
+
+        let no_station = HdlcAddress::new(reserved::NO_STATION).unwrap();
+        assert!(no_station.is_no_station());
+        assert!(!no_station.is_broadcast());
+
+        let calling =
+            HdlcAddress::new_with_physical(0x01, reserved::SERVER_LOWER_CALLING_1BYTE).unwrap();
+        assert!(calling.is_calling());
+        assert!(!calling.is_broadcast());
+
+        let unicast = HdlcAddress::new(0x10).unwrap();
+        assert!(!unicast.is_broadcast());
+        assert!(!unicast.is_no_station());
+        assert!(!unicast.is_calling());
+    }
+
+    #[test]
+    fn test_hdlc_address_pair_rejects_reserved_source() {
+        let destination = HdlcAddress::new(0x01).unwrap();
+
+        let broadcast = HdlcAddress::new(reserved::CLIENT_ALL_STATION).unwrap();
+        assert!(HdlcAddressPair::new(broadcast, destination).is_err());
+
+        let no_station = HdlcAddress::new(reserved::NO_STATION).unwrap();
+        assert!(HdlcAddressPair::new(no_station, destination).is_err());
+
+        // The calling address is a legitimate source for an unaddressed server
+        let calling =
+            HdlcAddress::new_with_physical(0x01, reserved::SERVER_LOWER_CALLING_1BYTE).unwrap();
+        assert!(HdlcAddressPair::new(calling, destination).is_ok());
+    }
 }
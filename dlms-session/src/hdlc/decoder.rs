@@ -3,6 +3,7 @@
 use crate::error::{DlmsError, DlmsResult};
 use crate::hdlc::frame::{HdlcFrame, FLAG};
 use dlms_transport::StreamAccessor;
+use dlms_transport::trace::{TraceHookHandle, TraceLayer};
 use std::time::Duration;
 
 const HDLC_LENGTH_MASK: u16 = 0x07FF;
@@ -15,6 +16,18 @@ impl HdlcMessageDecoder {
     pub async fn decode<S: StreamAccessor>(
         stream: &mut S,
         timeout: Option<Duration>,
+    ) -> DlmsResult<Vec<HdlcFrame>> {
+        Self::decode_with_hook(stream, timeout, &TraceHookHandle::none()).await
+    }
+
+    /// Decode HDLC frames from stream, reporting each raw frame to `trace`
+    ///
+    /// The hook fires with the raw frame bytes (without the surrounding
+    /// flags) before the frame is decoded, so invalid frames are traced too.
+    pub async fn decode_with_hook<S: StreamAccessor>(
+        stream: &mut S,
+        timeout: Option<Duration>,
+        trace: &TraceHookHandle,
     ) -> DlmsResult<Vec<HdlcFrame>> {
         let mut frames = Vec::new();
 
@@ -38,6 +51,8 @@ impl HdlcMessageDecoder {
             }
             Self::validate_flag(flag_buf[0])?;
 
+            trace.on_rx(TraceLayer::Hdlc, &frame_bytes);
+
             // Decode frame
             match HdlcFrame::decode(&frame_bytes) {
                 Ok(frame) => frames.push(frame),
@@ -29,6 +29,7 @@ pub enum FrameType {
     DisconnectMode,
     FrameReject,
     UnnumberedInformation,
+    Test,
     InvalidType,
 }
 
@@ -46,7 +47,8 @@ impl FrameType {
             x if (x & 0xEF) == 0x63 => FrameType::UnnumberedAcknowledge,
             x if (x & 0xEF) == 0x0F => FrameType::DisconnectMode,
             x if (x & 0xEF) == 0x87 => FrameType::FrameReject,
-            x if (x & 0xEF) == 0xC0 => FrameType::UnnumberedInformation,
+            x if (x & 0xEF) == 0x03 => FrameType::UnnumberedInformation,
+            x if (x & 0xEF) == 0xE3 => FrameType::Test,
             _ => FrameType::InvalidType,
         }
     }
@@ -69,7 +71,8 @@ impl FrameType {
             FrameType::UnnumberedAcknowledge => 0x63,
             FrameType::DisconnectMode => 0x0F,
             FrameType::FrameReject => 0x87,
-            FrameType::UnnumberedInformation => 0xC0,
+            FrameType::UnnumberedInformation => 0x03,
+            FrameType::Test => 0xE3,
             FrameType::InvalidType => 0xFF,
         }
     }
@@ -366,7 +369,7 @@ impl HdlcFrame {
         fcs_calc.update(frame[pos + 1]);
         fcs_calc.validate()?;
 
-        let address_pair = HdlcAddressPair::new(source, destination);
+        let address_pair = HdlcAddressPair::new(source, destination)?;
 
         Ok(Self {
             frame_type,
@@ -447,7 +450,8 @@ impl HdlcFrame {
         let control = if let (Some(send_seq), Some(recv_seq)) = (self.send_sequence, self.receive_sequence) {
             FrameType::Information.to_control_byte(Some(send_seq), Some(recv_seq))
         } else {
-            self.frame_type.to_control_byte(None, None)
+            // Supervisory frames (RR/RNR) carry N(R) but no N(S)
+            self.frame_type.to_control_byte(self.send_sequence, self.receive_sequence)
         };
         result.push(control);
         hcs_calc.update(control);
@@ -614,4 +618,20 @@ mod tests {
         assert_eq!(FrameType::from_control_byte(0x00), FrameType::Information);
         assert_eq!(FrameType::from_control_byte(0x01), FrameType::ReceiveReady);
     }
+
+    #[test]
+    fn test_frame_type_unnumbered_round_trip() {
+        // UI and TEST with and without the P/F bit (0x10)
+        assert_eq!(
+            FrameType::from_control_byte(0x03),
+            FrameType::UnnumberedInformation
+        );
+        assert_eq!(
+            FrameType::from_control_byte(0x13),
+            FrameType::UnnumberedInformation
+        );
+        assert_eq!(FrameType::from_control_byte(0xE3), FrameType::Test);
+        assert_eq!(FrameType::from_control_byte(0xF3), FrameType::Test);
+        assert_eq!(FrameType::Test.to_control_byte(None, None), 0xE3);
+    }
 }
\ No newline at end of file
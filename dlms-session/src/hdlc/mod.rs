@@ -16,6 +16,6 @@ pub use decoder::HdlcMessageDecoder;
 pub use dispatcher::{HdlcDispatcher, HdlcMessageQueue};
 pub use connection::{HdlcConnection, HdlcParameters};
 pub use fcs::FcsCalc;
-pub use statistics::HdlcStatistics;
+pub use statistics::{HdlcStatistics, HdlcStatsSnapshot};
 pub use window::{SendWindow, ReceiveWindow};
 pub use state::HdlcConnectionState;
\ No newline at end of file
@@ -31,6 +31,10 @@ pub struct HdlcStatistics {
     pub sequence_errors: u64,
     /// Number of retransmitted frames
     pub retransmissions: u64,
+    /// Total number of bytes sent (including HDLC framing)
+    pub bytes_sent: u64,
+    /// Total number of bytes received (including HDLC framing)
+    pub bytes_received: u64,
 }
 
 impl HdlcStatistics {
@@ -86,6 +90,36 @@ impl HdlcStatistics {
         self.retransmissions += 1;
     }
 
+    /// Add to the bytes sent counter
+    pub fn add_bytes_sent(&mut self, bytes: u64) {
+        self.bytes_sent += bytes;
+    }
+
+    /// Add to the bytes received counter
+    pub fn add_bytes_received(&mut self, bytes: u64) {
+        self.bytes_received += bytes;
+    }
+
+    /// Take a point-in-time snapshot of all counters
+    ///
+    /// The snapshot is a plain value type suitable for exporting to external
+    /// metrics systems. With the `serde` feature enabled it also implements
+    /// `serde::Serialize`.
+    pub fn snapshot(&self) -> HdlcStatsSnapshot {
+        HdlcStatsSnapshot {
+            frames_sent: self.frames_sent,
+            frames_received: self.frames_received,
+            frames_rejected: self.frames_rejected,
+            timeouts: self.timeouts,
+            fcs_errors: self.fcs_errors,
+            hcs_errors: self.hcs_errors,
+            sequence_errors: self.sequence_errors,
+            retransmissions: self.retransmissions,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+        }
+    }
+
     /// Get error rate as a percentage
     ///
     /// Calculates the percentage of frames that resulted in errors.
@@ -103,3 +137,63 @@ impl HdlcStatistics {
         }
     }
 }
+
+/// Point-in-time snapshot of HDLC statistics counters
+///
+/// Decoupled from the live [`HdlcStatistics`] so it can be cheaply cloned
+/// and handed off to metrics exporters without holding a connection borrow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HdlcStatsSnapshot {
+    /// Total number of frames sent
+    pub frames_sent: u64,
+    /// Total number of frames received
+    pub frames_received: u64,
+    /// Number of frames rejected due to errors
+    pub frames_rejected: u64,
+    /// Number of timeout events
+    pub timeouts: u64,
+    /// Number of FCS (Frame Check Sequence) errors
+    pub fcs_errors: u64,
+    /// Number of HCS (Header Check Sequence) errors
+    pub hcs_errors: u64,
+    /// Number of sequence number mismatches
+    pub sequence_errors: u64,
+    /// Number of retransmitted frames
+    pub retransmissions: u64,
+    /// Total number of bytes sent (including HDLC framing)
+    pub bytes_sent: u64,
+    /// Total number of bytes received (including HDLC framing)
+    pub bytes_received: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_reflects_counter_increments() {
+        let mut stats = HdlcStatistics::new();
+        stats.increment_frames_sent();
+        stats.increment_frames_sent();
+        stats.increment_frames_received();
+        stats.increment_retransmissions();
+        stats.increment_fcs_errors();
+        stats.increment_timeouts();
+        stats.add_bytes_sent(64);
+        stats.add_bytes_received(32);
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.frames_sent, 2);
+        assert_eq!(snapshot.frames_received, 1);
+        assert_eq!(snapshot.retransmissions, 1);
+        assert_eq!(snapshot.fcs_errors, 1);
+        assert_eq!(snapshot.timeouts, 1);
+        assert_eq!(snapshot.bytes_sent, 64);
+        assert_eq!(snapshot.bytes_received, 32);
+
+        // A snapshot is a copy, not a live view
+        stats.increment_frames_sent();
+        assert_eq!(snapshot.frames_sent, 2);
+    }
+}
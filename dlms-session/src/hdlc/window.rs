@@ -288,6 +288,40 @@ impl SendWindow {
         retransmissions
     }
 
+    /// Collect all pending frames for immediate retransmission
+    ///
+    /// Unlike `get_retransmissions`, this does not consult the per-frame
+    /// timer: the caller has already observed an acknowledgment timeout
+    /// (e.g. a receive deadline elapsed with no RR frame) and wants the
+    /// outstanding frames resent right away. Retry counts are still
+    /// incremented, and frames that have exhausted `max_retries` are
+    /// not returned.
+    ///
+    /// # Returns
+    /// Vector of (sequence, encoded_bytes) tuples to retransmit
+    pub fn retransmit_pending(&mut self) -> Vec<(u8, Vec<u8>)> {
+        let mut retransmissions = Vec::new();
+
+        for pending in &mut self.unacked_frames {
+            if pending.retry_count() < self.max_retries {
+                pending.increment_retry();
+                retransmissions.push((pending.sequence, pending.encoded_bytes.clone()));
+            }
+        }
+
+        retransmissions
+    }
+
+    /// Update the retransmission policy
+    ///
+    /// # Arguments
+    /// * `retransmit_timeout` - Time to wait before retransmitting
+    /// * `max_retries` - Maximum number of retransmission attempts
+    pub fn set_retransmit_policy(&mut self, retransmit_timeout: Duration, max_retries: u8) {
+        self.retransmit_timeout = retransmit_timeout;
+        self.max_retries = max_retries;
+    }
+
     /// Get the oldest unacknowledged frame sequence
     ///
     /// # Returns
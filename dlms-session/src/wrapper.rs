@@ -2,6 +2,7 @@
 
 use crate::error::{DlmsError, DlmsResult};
 use dlms_transport::{StreamAccessor, TransportLayer};
+use dlms_transport::trace::{TraceHook, TraceHookHandle, TraceLayer};
 use std::time::Duration;
 
 /// Wrapper header length
@@ -189,6 +190,10 @@ pub struct WrapperSession<T: TransportLayer> {
     client_id: u16,
     logical_device_id: u16,
     closed: bool,
+    /// Negotiated server PDU size limit (None before association, 0 = no limit)
+    server_max_receive_pdu_size: Option<u16>,
+    /// Trace hook observing raw wrapper PDUs (no-op when unset)
+    trace: TraceHookHandle,
 }
 
 impl<T: TransportLayer> WrapperSession<T> {
@@ -199,9 +204,27 @@ impl<T: TransportLayer> WrapperSession<T> {
             client_id,
             logical_device_id,
             closed: true,
+            server_max_receive_pdu_size: None,
+            trace: TraceHookHandle::none(),
         }
     }
 
+    /// Record the server PDU size limit negotiated during association
+    ///
+    /// Outgoing PDUs larger than this limit are rejected by [`send`](Self::send).
+    /// A value of 0 means the server did not state a limit.
+    pub fn set_server_max_receive_pdu_size(&mut self, size: u16) {
+        self.server_max_receive_pdu_size = Some(size);
+    }
+
+    /// Attach a trace hook observing every wrapper PDU sent or received
+    ///
+    /// The hook fires with the complete encoded PDU (header plus payload).
+    /// When no hook is set the trace points are a no-op.
+    pub fn set_trace_hook(&mut self, hook: std::sync::Arc<dyn TraceHook>) {
+        self.trace.set(hook);
+    }
+
     /// Open the wrapper session
     pub async fn open(&mut self) -> DlmsResult<()> {
         self.transport.open().await?;
@@ -210,6 +233,15 @@ impl<T: TransportLayer> WrapperSession<T> {
     }
 
     /// Send data through wrapper session
+    ///
+    /// # PDU Size Enforcement
+    /// The wrapper protocol has no segmentation of its own, so a PDU either
+    /// fits in one wrapper frame or cannot be sent at all. Two limits apply:
+    /// the 16-bit wrapper length field (hard protocol limit) and the server
+    /// PDU size negotiated during association (if recorded via
+    /// [`set_server_max_receive_pdu_size`](Self::set_server_max_receive_pdu_size)).
+    /// Oversized PDUs are rejected with `DlmsError::LengthMismatch` rather
+    /// than silently truncated.
     pub async fn send(&mut self, data: &[u8]) -> DlmsResult<()> {
         if self.closed {
             return Err(DlmsError::Connection(std::io::Error::new(
@@ -218,9 +250,28 @@ impl<T: TransportLayer> WrapperSession<T> {
             )));
         }
 
+        if data.len() > usize::from(u16::MAX) {
+            return Err(DlmsError::LengthMismatch(format!(
+                "PDU is {} bytes but the wrapper length field holds at most {} bytes",
+                data.len(),
+                u16::MAX
+            )));
+        }
+        if let Some(max_size) = self.server_max_receive_pdu_size
+            && max_size > 0
+            && data.len() > usize::from(max_size)
+        {
+            return Err(DlmsError::LengthMismatch(format!(
+                "PDU is {} bytes but the server accepts at most {} bytes and wrapper cannot segment",
+                data.len(),
+                max_size
+            )));
+        }
+
         let header = WrapperHeader::new(self.client_id, self.logical_device_id, data.len() as u16);
         let pdu = WrapperPdu::new(header, data.to_vec());
         let encoded = pdu.encode();
+        self.trace.on_tx(TraceLayer::Wrapper, &encoded);
 
         self.transport.write_all(&encoded).await?;
         self.transport.flush().await?;
@@ -241,6 +292,9 @@ impl<T: TransportLayer> WrapperSession<T> {
         }
 
         let pdu = WrapperPdu::decode(&mut self.transport).await?;
+        if self.trace.is_set() {
+            self.trace.on_rx(TraceLayer::Wrapper, &pdu.encode());
+        }
         Ok(pdu.data().to_vec())
     }
 
@@ -274,4 +328,86 @@ mod tests {
         assert_eq!(decoded.logical_device_id(), 0x0001);
         assert_eq!(decoded.payload_length(), 100);
     }
+
+    /// Transport stub that records written bytes
+    struct MockTransport {
+        tx: Vec<u8>,
+        closed: bool,
+    }
+
+    impl MockTransport {
+        fn new() -> Self {
+            Self {
+                tx: Vec::new(),
+                closed: false,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl StreamAccessor for MockTransport {
+        async fn set_timeout(&mut self, _timeout: Option<Duration>) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        async fn read(&mut self, _buf: &mut [u8]) -> DlmsResult<usize> {
+            Ok(0)
+        }
+
+        async fn write(&mut self, buf: &[u8]) -> DlmsResult<usize> {
+            self.tx.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        async fn flush(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        fn is_closed(&self) -> bool {
+            self.closed
+        }
+
+        async fn close(&mut self) -> DlmsResult<()> {
+            self.closed = true;
+            Ok(())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TransportLayer for MockTransport {
+        async fn open(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_within_negotiated_limit() {
+        let mut session = WrapperSession::new(MockTransport::new(), 0x0001, 0x0001);
+        session.open().await.unwrap();
+        session.set_server_max_receive_pdu_size(16);
+
+        session.send(&[0xC0, 0x01, 0xC1]).await.unwrap();
+
+        // Header (8 bytes) plus payload went out on the wire
+        assert_eq!(session.transport.tx.len(), WRAPPER_HEADER_LENGTH + 3);
+        assert_eq!(&session.transport.tx[WRAPPER_HEADER_LENGTH..], &[0xC0, 0x01, 0xC1]);
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_pdu_over_negotiated_limit() {
+        let mut session = WrapperSession::new(MockTransport::new(), 0x0001, 0x0001);
+        session.open().await.unwrap();
+        session.set_server_max_receive_pdu_size(16);
+
+        let result = session.send(&[0u8; 17]).await;
+        match result {
+            Err(DlmsError::LengthMismatch(message)) => {
+                assert!(message.contains("17"), "{}", message);
+            }
+            other => panic!("Expected LengthMismatch, got {:?}", other),
+        }
+
+        // Nothing was written: no truncated PDU on the wire
+        assert!(session.transport.tx.is_empty());
+    }
 }
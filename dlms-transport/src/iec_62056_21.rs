@@ -0,0 +1,245 @@
+//! IEC 62056-21 mode E sign-on handshake
+//!
+//! Optical-probe meters typically start in IEC 62056-21 ("FLAG") mode at
+//! 300 baud and switch to HDLC after a baud negotiation. The mode E
+//! handshake works as follows:
+//!
+//! 1. Client sends the sign-on request `/?!<CR><LF>` (optionally with a
+//!    device address between `?` and `!`)
+//! 2. Meter answers with an identification string `/XXXZ<ident><CR><LF>`,
+//!    where `XXX` is the manufacturer code and `Z` is the baud rate
+//!    character (`0` = 300 ... `6` = 19200)
+//! 3. Client acknowledges with `<ACK>2Z2<CR><LF>` selecting binary (HDLC)
+//!    mode E at the proposed baud rate
+//! 4. Both sides switch the serial port to the negotiated baud rate and
+//!    continue with the HDLC SNRM/UA handshake
+//!
+//! This module performs steps 1-3 and returns the negotiated baud rate;
+//! the caller applies it to the serial port (see
+//! [`SerialTransport::detect_baud`](crate::SerialTransport::detect_baud)
+//! for the related probing helper) before opening the HDLC connection.
+
+use crate::error::{DlmsError, DlmsResult};
+use crate::stream::StreamAccessor;
+
+/// ASCII ACK control character
+const ACK: u8 = 0x06;
+
+/// Maximum identification string length per IEC 62056-21 (with margin)
+const MAX_IDENTIFICATION_LENGTH: usize = 64;
+
+/// IEC 62056-21 mode E sign-on handshake
+///
+/// Performs the `/?!` sign-on, parses the identification response and sends
+/// the mode E acknowledgement selecting binary (HDLC) mode.
+#[derive(Debug, Clone, Default)]
+pub struct Iec21Handshake {
+    /// Optional device address inserted into the sign-on request
+    device_address: Option<String>,
+}
+
+impl Iec21Handshake {
+    /// Create a handshake addressing all devices (broadcast sign-on)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a handshake addressing a specific device
+    ///
+    /// # Arguments
+    /// * `device_address` - Device address placed in the sign-on request
+    ///   (`/?<address>!<CR><LF>`)
+    pub fn with_device_address(device_address: String) -> Self {
+        Self {
+            device_address: Some(device_address),
+        }
+    }
+
+    /// Perform the mode E sign-on and baud negotiation
+    ///
+    /// Sends the sign-on request, reads the identification string, then
+    /// acknowledges with the mode E sequence. The caller must switch the
+    /// serial port to the returned baud rate before starting HDLC.
+    ///
+    /// # Returns
+    /// The negotiated baud rate in bit/s.
+    pub async fn perform<S: StreamAccessor>(&mut self, transport: &mut S) -> DlmsResult<u32> {
+        // Step 1: sign-on request
+        let mut request = b"/?".to_vec();
+        if let Some(address) = &self.device_address {
+            request.extend_from_slice(address.as_bytes());
+        }
+        request.extend_from_slice(b"!\r\n");
+        transport.write_all(&request).await?;
+        transport.flush().await?;
+
+        // Step 2: identification response
+        let identification = Self::read_identification(transport).await?;
+        let baud_char = Self::parse_baud_character(&identification)?;
+        let baud_rate = Self::baud_rate_of(baud_char)?;
+
+        // Step 3: acknowledge with protocol control '2' (binary/HDLC mode E)
+        let ack = [ACK, b'2', baud_char, b'2', b'\r', b'\n'];
+        transport.write_all(&ack).await?;
+        transport.flush().await?;
+
+        Ok(baud_rate)
+    }
+
+    /// Read the identification string up to and including the terminating LF
+    async fn read_identification<S: StreamAccessor>(transport: &mut S) -> DlmsResult<Vec<u8>> {
+        let mut identification = Vec::new();
+        let mut byte = [0u8; 1];
+
+        loop {
+            let n = transport.read(&mut byte).await?;
+            if n == 0 {
+                return Err(DlmsError::InvalidData(
+                    "Connection closed while reading IEC identification".to_string(),
+                ));
+            }
+            identification.push(byte[0]);
+
+            if byte[0] == b'\n' {
+                return Ok(identification);
+            }
+            if identification.len() > MAX_IDENTIFICATION_LENGTH {
+                return Err(DlmsError::InvalidData(
+                    "IEC identification string exceeds maximum length".to_string(),
+                ));
+            }
+        }
+    }
+
+    /// Extract the baud rate character from an identification string
+    ///
+    /// The identification has the form `/XXXZ<ident><CR><LF>`: start
+    /// character, 3-character manufacturer code, baud rate character.
+    fn parse_baud_character(identification: &[u8]) -> DlmsResult<u8> {
+        if identification.len() < 5 || identification[0] != b'/' {
+            return Err(DlmsError::InvalidData(format!(
+                "Malformed IEC identification: {:02X?}",
+                identification
+            )));
+        }
+        Ok(identification[4])
+    }
+
+    /// Map an IEC 62056-21 baud rate character to bit/s
+    fn baud_rate_of(baud_char: u8) -> DlmsResult<u32> {
+        match baud_char {
+            b'0' => Ok(300),
+            b'1' => Ok(600),
+            b'2' => Ok(1200),
+            b'3' => Ok(2400),
+            b'4' => Ok(4800),
+            b'5' => Ok(9600),
+            b'6' => Ok(19200),
+            other => Err(DlmsError::InvalidData(format!(
+                "Unknown IEC baud rate character: 0x{:02X}",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::time::Duration;
+
+    /// Mock stream with a canned receive buffer that records writes
+    struct MockStream {
+        rx: Vec<u8>,
+        pos: usize,
+        tx: Vec<u8>,
+    }
+
+    impl MockStream {
+        fn new(rx: &[u8]) -> Self {
+            Self {
+                rx: rx.to_vec(),
+                pos: 0,
+                tx: Vec::new(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl StreamAccessor for MockStream {
+        async fn set_timeout(&mut self, _timeout: Option<Duration>) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        async fn read(&mut self, buf: &mut [u8]) -> DlmsResult<usize> {
+            let remaining = &self.rx[self.pos..];
+            let n = remaining.len().min(buf.len());
+            buf[..n].copy_from_slice(&remaining[..n]);
+            self.pos += n;
+            Ok(n)
+        }
+
+        async fn write(&mut self, buf: &[u8]) -> DlmsResult<usize> {
+            self.tx.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        async fn flush(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        fn is_closed(&self) -> bool {
+            false
+        }
+
+        async fn close(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mode_e_handshake_negotiates_baud() {
+        let mut stream = MockStream::new(b"/GEC5\\2@V0050\r\n");
+
+        let mut handshake = Iec21Handshake::new();
+        let baud = handshake.perform(&mut stream).await.unwrap();
+
+        // Baud character '5' means 9600 bit/s
+        assert_eq!(baud, 9600);
+        // Sign-on request followed by the mode E acknowledgement
+        assert!(stream.tx.starts_with(b"/?!\r\n"));
+        assert!(stream.tx.ends_with(&[ACK, b'2', b'5', b'2', b'\r', b'\n']));
+    }
+
+    #[tokio::test]
+    async fn test_mode_e_handshake_with_device_address() {
+        let mut stream = MockStream::new(b"/ISK6MT171\r\n");
+
+        let mut handshake = Iec21Handshake::with_device_address("12345678".to_string());
+        let baud = handshake.perform(&mut stream).await.unwrap();
+
+        assert_eq!(baud, 19200);
+        assert!(stream.tx.starts_with(b"/?12345678!\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_mode_e_handshake_rejects_malformed_identification() {
+        let mut stream = MockStream::new(b"garbage\r\n");
+
+        let mut handshake = Iec21Handshake::new();
+        let result = handshake.perform(&mut stream).await;
+
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+
+    #[tokio::test]
+    async fn test_mode_e_handshake_rejects_unknown_baud_character() {
+        let mut stream = MockStream::new(b"/GECX\r\n");
+
+        let mut handshake = Iec21Handshake::new();
+        let result = handshake.perform(&mut stream).await;
+
+        assert!(matches!(result, Err(DlmsError::InvalidData(_))));
+    }
+}
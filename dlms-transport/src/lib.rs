@@ -158,6 +158,7 @@
 //! - [`tcp`] - TCP transport implementation
 //! - [`udp`] - UDP transport implementation
 //! - [`serial`] - Serial transport implementation
+//! - [`iec_62056_21`] - IEC 62056-21 mode E sign-on handshake
 //! - [`stream`] - Transport layer trait definitions
 //! - [`error`] - Transport layer error types
 //!
@@ -168,12 +169,18 @@
 
 pub mod error;
 pub mod stream;
+pub mod trace;
 pub mod tcp;
 pub mod udp;
 pub mod serial;
+pub mod iec_62056_21;
+pub mod reconnect;
 
 pub use error::{DlmsError, DlmsResult};
 pub use stream::{StreamAccessor, TransportLayer};
+pub use trace::{TraceHook, TraceHookHandle, TraceLayer};
 pub use tcp::{TcpTransport, TcpSettings};
 pub use udp::{UdpTransport, UdpSettings, MAX_UDP_PAYLOAD_SIZE};
 pub use serial::{SerialTransport, SerialSettings};
+pub use iec_62056_21::Iec21Handshake;
+pub use reconnect::{ReconnectingTransport, TransportFactory};
@@ -0,0 +1,252 @@
+//! Auto-reconnecting transport decorator
+//!
+//! Reconnect logic does not belong in every client: a session layer only
+//! sees a `TransportLayer`, so recovery from a dropped link can live in a
+//! decorator. [`ReconnectingTransport`] wraps any transport and, when a
+//! read or write fails, rebuilds the inner transport from a factory
+//! closure, waits out a backoff, reopens it and retries the operation
+//! once. A second failure is reported to the caller unchanged.
+
+use crate::error::DlmsResult;
+use crate::stream::{StreamAccessor, TransportLayer};
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// Factory producing a fresh inner transport for reconnection
+pub type TransportFactory<T> = Box<dyn Fn() -> T + Send + Sync>;
+
+/// Transport decorator that reconnects and retries once on I/O errors
+///
+/// Implements `TransportLayer` itself, so it drops into any session layer
+/// in place of the transport it wraps.
+pub struct ReconnectingTransport<T: TransportLayer> {
+    inner: T,
+    factory: TransportFactory<T>,
+    backoff: Duration,
+    /// Last timeout requested by the caller, reapplied after a reconnect
+    timeout: Option<Option<Duration>>,
+}
+
+impl<T: TransportLayer> ReconnectingTransport<T> {
+    /// Create a reconnecting transport around `factory`'s product
+    ///
+    /// The factory is called once immediately for the initial transport
+    /// and again on every reconnection. The default backoff before a
+    /// reconnection attempt is 500 ms.
+    pub fn new(factory: TransportFactory<T>) -> Self {
+        let inner = factory();
+        Self {
+            inner,
+            factory,
+            backoff: Duration::from_millis(500),
+            timeout: None,
+        }
+    }
+
+    /// Set the delay before a reconnection attempt
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Get the wrapped transport
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Tear down the failed transport and open a fresh one
+    async fn reconnect(&mut self) -> DlmsResult<()> {
+        // The old transport is already broken; a close error adds nothing
+        let _ = self.inner.close().await;
+        tokio::time::sleep(self.backoff).await;
+
+        let mut fresh = (self.factory)();
+        fresh.open().await?;
+        if let Some(timeout) = self.timeout {
+            fresh.set_timeout(timeout).await?;
+        }
+        self.inner = fresh;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: TransportLayer> StreamAccessor for ReconnectingTransport<T> {
+    async fn set_timeout(&mut self, timeout: Option<Duration>) -> DlmsResult<()> {
+        self.timeout = Some(timeout);
+        self.inner.set_timeout(timeout).await
+    }
+
+    async fn read(&mut self, buf: &mut [u8]) -> DlmsResult<usize> {
+        match self.inner.read(buf).await {
+            Ok(n) => Ok(n),
+            Err(_) => {
+                self.reconnect().await?;
+                self.inner.read(buf).await
+            }
+        }
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> DlmsResult<usize> {
+        match self.inner.write(buf).await {
+            Ok(n) => Ok(n),
+            Err(_) => {
+                self.reconnect().await?;
+                self.inner.write(buf).await
+            }
+        }
+    }
+
+    async fn flush(&mut self) -> DlmsResult<()> {
+        self.inner.flush().await
+    }
+
+    fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    async fn close(&mut self) -> DlmsResult<()> {
+        self.inner.close().await
+    }
+}
+
+#[async_trait]
+impl<T: TransportLayer> TransportLayer for ReconnectingTransport<T> {
+    async fn open(&mut self) -> DlmsResult<()> {
+        self.inner.open().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DlmsError;
+    use std::sync::{Arc, Mutex};
+
+    /// Shared across factory products so the test can script failures
+    /// and observe what happened over the transport's whole lifetime
+    #[derive(Default)]
+    struct FlakyState {
+        opens: u32,
+        failures_left: u32,
+        written: Vec<u8>,
+    }
+
+    struct FlakyTransport {
+        state: Arc<Mutex<FlakyState>>,
+    }
+
+    impl FlakyTransport {
+        fn io_error(message: &str) -> DlmsError {
+            DlmsError::Connection(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                message,
+            ))
+        }
+    }
+
+    #[async_trait]
+    impl StreamAccessor for FlakyTransport {
+        async fn set_timeout(&mut self, _timeout: Option<Duration>) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        async fn read(&mut self, buf: &mut [u8]) -> DlmsResult<usize> {
+            let mut state = self.state.lock().unwrap();
+            if state.failures_left > 0 {
+                state.failures_left -= 1;
+                return Err(Self::io_error("scripted read failure"));
+            }
+            buf[0] = 0x7E;
+            Ok(1)
+        }
+
+        async fn write(&mut self, buf: &[u8]) -> DlmsResult<usize> {
+            let mut state = self.state.lock().unwrap();
+            if state.failures_left > 0 {
+                state.failures_left -= 1;
+                return Err(Self::io_error("scripted write failure"));
+            }
+            state.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        async fn flush(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        fn is_closed(&self) -> bool {
+            false
+        }
+
+        async fn close(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl TransportLayer for FlakyTransport {
+        async fn open(&mut self) -> DlmsResult<()> {
+            self.state.lock().unwrap().opens += 1;
+            Ok(())
+        }
+    }
+
+    fn flaky(failures: u32) -> (ReconnectingTransport<FlakyTransport>, Arc<Mutex<FlakyState>>) {
+        let state = Arc::new(Mutex::new(FlakyState {
+            failures_left: failures,
+            ..FlakyState::default()
+        }));
+        let factory_state = Arc::clone(&state);
+        let transport = ReconnectingTransport::new(Box::new(move || FlakyTransport {
+            state: Arc::clone(&factory_state),
+        }))
+        .with_backoff(Duration::from_millis(1));
+        (transport, state)
+    }
+
+    #[tokio::test]
+    async fn test_write_reconnects_and_retries_once() {
+        let (mut transport, state) = flaky(1);
+        transport.open().await.unwrap();
+
+        transport.write_all(b"\x01\x02").await.unwrap();
+
+        let state = state.lock().unwrap();
+        assert_eq!(state.written, b"\x01\x02");
+        // Initial open plus the reconnection's open
+        assert_eq!(state.opens, 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_reconnects_and_retries_once() {
+        let (mut transport, state) = flaky(1);
+        transport.open().await.unwrap();
+
+        let mut buf = [0u8; 1];
+        let n = transport.read(&mut buf).await.unwrap();
+        assert_eq!(n, 1);
+        assert_eq!(buf[0], 0x7E);
+        assert_eq!(state.lock().unwrap().opens, 2);
+    }
+
+    #[tokio::test]
+    async fn test_second_failure_is_reported() {
+        // Fails the original attempt and the post-reconnect retry
+        let (mut transport, state) = flaky(2);
+        transport.open().await.unwrap();
+
+        let result = transport.write(b"\x01").await;
+        assert!(matches!(result, Err(DlmsError::Connection(_))));
+        assert_eq!(state.lock().unwrap().opens, 2);
+    }
+
+    #[tokio::test]
+    async fn test_healthy_transport_never_reconnects() {
+        let (mut transport, state) = flaky(0);
+        transport.open().await.unwrap();
+
+        transport.write_all(b"\xAA").await.unwrap();
+        assert_eq!(state.lock().unwrap().opens, 1);
+    }
+}
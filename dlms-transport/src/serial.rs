@@ -2,6 +2,7 @@
 
 use crate::error::{DlmsError, DlmsResult};
 use crate::stream::{StreamAccessor, TransportLayer};
+use crate::trace::{TraceHook, TraceHookHandle, TraceLayer};
 use async_trait::async_trait;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
@@ -78,6 +79,7 @@ pub struct SerialTransport {
     stream: Option<DebugSerialStream>,
     settings: SerialSettings,
     closed: bool,
+    trace: TraceHookHandle,
 }
 
 impl SerialTransport {
@@ -87,15 +89,105 @@ impl SerialTransport {
             stream: None,
             settings,
             closed: true,
+            trace: TraceHookHandle::none(),
         }
     }
 
+    /// Attach a trace hook observing raw bytes on this transport
+    pub fn set_trace_hook(&mut self, hook: std::sync::Arc<dyn TraceHook>) {
+        self.trace.set(hook);
+    }
+
     /// Create serial transport with port name and baud rate
     pub fn new_simple(port_name: String, baud_rate: u32) -> Self {
         Self::new(SerialSettings::new(port_name, baud_rate))
     }
+
+    /// Detect the baud rate of a device with an unknown configuration
+    ///
+    /// Tries each candidate baud rate in order by sending an IEC 62056-21
+    /// mode-E request and checking for a valid framed response within a short
+    /// timeout. A device answering in IEC mode starts its identification with
+    /// `/`; a device already in HDLC mode answers with a `0x7E`-flagged frame.
+    /// Either counts as a hit.
+    ///
+    /// # Arguments
+    /// * `port` - Serial port name (e.g. "/dev/ttyUSB0")
+    /// * `candidates` - Baud rates to try, in order of preference
+    ///
+    /// # Returns
+    /// The first baud rate that produced a valid response, or
+    /// `DlmsError::Timeout` if none did.
+    pub async fn detect_baud(port: &str, candidates: &[u32]) -> DlmsResult<u32> {
+        Self::detect_baud_with(candidates, |baud| {
+            let settings = SerialSettings::with_timeout(
+                port.to_string(),
+                baud,
+                Duration::from_millis(PROBE_TIMEOUT_MS),
+            );
+            async move {
+                let mut transport = Self::new(settings);
+                transport.open().await?;
+                Ok(transport)
+            }
+        })
+        .await
+    }
+
+    /// Baud detection over an arbitrary stream factory
+    ///
+    /// Separated from `detect_baud` so the probe loop can be exercised with
+    /// mock streams in tests.
+    async fn detect_baud_with<S, F, Fut>(candidates: &[u32], open_at: F) -> DlmsResult<u32>
+    where
+        S: StreamAccessor,
+        F: Fn(u32) -> Fut,
+        Fut: std::future::Future<Output = DlmsResult<S>>,
+    {
+        for &baud in candidates {
+            let mut stream = match open_at(baud).await {
+                Ok(stream) => stream,
+                // The port may reject unsupported baud rates; try the next one
+                Err(_) => continue,
+            };
+
+            let detected = Self::probe(&mut stream).await;
+            let _ = stream.close().await;
+
+            if detected {
+                return Ok(baud);
+            }
+        }
+
+        Err(DlmsError::Timeout)
+    }
+
+    /// Send a mode-E request and check for a valid framed reply
+    async fn probe<S: StreamAccessor>(stream: &mut S) -> bool {
+        if stream.write_all(MODE_E_REQUEST).await.is_err() {
+            return false;
+        }
+        if stream.flush().await.is_err() {
+            return false;
+        }
+
+        let mut buf = [0u8; 32];
+        match stream.read(&mut buf).await {
+            Ok(n) if n > 0 => buf[0] == b'/' || buf[..n].contains(&HDLC_FLAG),
+            _ => false,
+        }
+    }
 }
 
+/// IEC 62056-21 mode-E identification request ("/?!\r\n")
+const MODE_E_REQUEST: &[u8] = b"/?!\r\n";
+
+/// HDLC frame boundary flag
+const HDLC_FLAG: u8 = 0x7E;
+
+/// Per-candidate response timeout during baud detection
+const PROBE_TIMEOUT_MS: u64 = 500;
+
 #[async_trait]
 impl TransportLayer for SerialTransport {
     async fn open(&mut self) -> DlmsResult<()> {
@@ -152,7 +244,10 @@ impl StreamAccessor for SerialTransport {
                 self.closed = true;
                 Ok(0)
             }
-            Ok(n) => Ok(n),
+            Ok(n) => {
+                self.trace.on_rx(TraceLayer::Transport, &buf[..n]);
+                Ok(n)
+            }
             Err(e) => {
                 self.closed = true;
                 Err(e)
@@ -168,13 +263,16 @@ impl StreamAccessor for SerialTransport {
             ))
         })?;
 
-        if let Some(timeout) = self.settings.timeout {
+        let written = if let Some(timeout) = self.settings.timeout {
             tokio::time::timeout(timeout, stream.write(buf)).await
                 .map_err(|_| DlmsError::Timeout)?
-                .map_err(|e| DlmsError::Connection(e))
+                .map_err(DlmsError::Connection)?
         } else {
-            stream.write(buf).await.map_err(|e| DlmsError::Connection(e))
-        }
+            stream.write(buf).await.map_err(DlmsError::Connection)?
+        };
+
+        self.trace.on_tx(TraceLayer::Transport, &buf[..written]);
+        Ok(written)
     }
 
     async fn flush(&mut self) -> DlmsResult<()> {
@@ -211,4 +309,92 @@ mod tests {
         assert_eq!(settings.port_name, "/dev/ttyUSB0");
         assert_eq!(settings.baud_rate, 9600);
     }
+
+    /// Mock stream that only answers the probe when built at the right baud
+    struct MockProbeStream {
+        responds: bool,
+        response: Vec<u8>,
+        pos: usize,
+    }
+
+    impl MockProbeStream {
+        fn new(responds: bool, response: Vec<u8>) -> Self {
+            Self {
+                responds,
+                response,
+                pos: 0,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl StreamAccessor for MockProbeStream {
+        async fn set_timeout(&mut self, _timeout: Option<Duration>) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        async fn read(&mut self, buf: &mut [u8]) -> DlmsResult<usize> {
+            if !self.responds {
+                return Err(DlmsError::Timeout);
+            }
+            let remaining = &self.response[self.pos..];
+            let n = remaining.len().min(buf.len());
+            buf[..n].copy_from_slice(&remaining[..n]);
+            self.pos += n;
+            Ok(n)
+        }
+
+        async fn write(&mut self, buf: &[u8]) -> DlmsResult<usize> {
+            Ok(buf.len())
+        }
+
+        async fn flush(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+
+        fn is_closed(&self) -> bool {
+            false
+        }
+
+        async fn close(&mut self) -> DlmsResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detect_baud_finds_responding_rate() {
+        let result = SerialTransport::detect_baud_with(&[300, 9600, 115200], |baud| async move {
+            Ok(MockProbeStream::new(
+                baud == 9600,
+                b"/GEC5\\2@V0050\r\n".to_vec(),
+            ))
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 9600);
+    }
+
+    #[tokio::test]
+    async fn test_detect_baud_accepts_hdlc_response() {
+        let result = SerialTransport::detect_baud_with(&[19200], |_baud| async move {
+            // A meter already in HDLC mode answers with a flagged frame (e.g. a UA)
+            Ok(MockProbeStream::new(
+                true,
+                vec![0x7E, 0xA0, 0x0A, 0x21, 0x02, 0x73, 0x00, 0x00, 0x7E],
+            ))
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 19200);
+    }
+
+    #[tokio::test]
+    async fn test_detect_baud_times_out_when_nothing_answers() {
+        let result = SerialTransport::detect_baud_with(&[300, 9600], |_baud| async move {
+            Ok(MockProbeStream::new(false, Vec::new()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(DlmsError::Timeout)));
+    }
 }
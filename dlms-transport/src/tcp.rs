@@ -2,6 +2,7 @@
 
 use crate::error::{DlmsError, DlmsResult};
 use crate::stream::{StreamAccessor, TransportLayer};
+use crate::trace::{TraceHook, TraceHookHandle, TraceLayer};
 use async_trait::async_trait;
 use std::fmt;
 use std::net::SocketAddr;
@@ -64,6 +65,7 @@ pub struct TcpTransport {
     stream: Option<DebugTcpStream>,
     settings: TcpSettings,
     closed: bool,
+    trace: TraceHookHandle,
 }
 
 impl TcpTransport {
@@ -73,9 +75,15 @@ impl TcpTransport {
             stream: None,
             settings,
             closed: true,
+            trace: TraceHookHandle::none(),
         }
     }
 
+    /// Attach a trace hook observing raw bytes on this transport
+    pub fn set_trace_hook(&mut self, hook: std::sync::Arc<dyn TraceHook>) {
+        self.trace.set(hook);
+    }
+
     /// Create TCP transport from address string
     pub fn from_address(address: &str) -> DlmsResult<Self> {
         let addr: SocketAddr = address.parse().map_err(|e| {
@@ -97,6 +105,7 @@ impl TcpTransport {
                 timeout,
             },
             closed: false,
+            trace: TraceHookHandle::none(),
         }
     }
 }
@@ -157,7 +166,10 @@ impl StreamAccessor for TcpTransport {
                 self.closed = true;
                 Ok(0)
             }
-            Ok(n) => Ok(n),
+            Ok(n) => {
+                self.trace.on_rx(TraceLayer::Transport, &buf[..n]);
+                Ok(n)
+            }
             Err(e) => {
                 self.closed = true;
                 Err(e)
@@ -173,13 +185,16 @@ impl StreamAccessor for TcpTransport {
             ))
         })?;
 
-        if let Some(timeout) = self.settings.timeout {
+        let written = if let Some(timeout) = self.settings.timeout {
             tokio::time::timeout(timeout, stream.write(buf)).await
                 .map_err(|_| DlmsError::Timeout)?
-                .map_err(|e| DlmsError::Connection(e))
+                .map_err(DlmsError::Connection)?
         } else {
-            stream.write(buf).await.map_err(|e| DlmsError::Connection(e))
-        }
+            stream.write(buf).await.map_err(DlmsError::Connection)?
+        };
+
+        self.trace.on_tx(TraceLayer::Transport, &buf[..written]);
+        Ok(written)
     }
 
     async fn flush(&mut self) -> DlmsResult<()> {
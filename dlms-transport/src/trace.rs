@@ -0,0 +1,87 @@
+//! Raw-frame tracing hooks for protocol debugging
+//!
+//! Diagnosing interop issues requires seeing the raw bytes at each layer.
+//! A [`TraceHook`] can be attached to the transports and to the session
+//! layers (HDLC, Wrapper); it fires for every frame/PDU sent or received
+//! at that layer. When no hook is attached the trace points are a no-op
+//! (a single `Option` check), so tracing costs nothing in production.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Protocol layer a traced frame belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TraceLayer {
+    /// Transport layer (TCP/UDP/Serial) - bytes as read from/written to the wire
+    Transport,
+    /// HDLC session layer - complete HDLC frames
+    Hdlc,
+    /// Wrapper session layer - complete wrapper PDUs
+    Wrapper,
+}
+
+/// Observer for raw bytes crossing a protocol layer
+///
+/// Implementations are called inline on the I/O path and must be cheap
+/// and non-blocking (e.g. copy the bytes into a channel or log buffer).
+pub trait TraceHook: Send + Sync {
+    /// Called with every frame/PDU sent at `layer`
+    fn on_tx(&self, layer: TraceLayer, bytes: &[u8]);
+
+    /// Called with every frame/PDU received at `layer`
+    fn on_rx(&self, layer: TraceLayer, bytes: &[u8]);
+}
+
+/// Optionally-attached trace hook
+///
+/// Wraps `Option<Arc<dyn TraceHook>>` so carrying a hook does not break
+/// `Debug` derives on the owning transport/session and the unset case
+/// stays a no-op.
+#[derive(Clone, Default)]
+pub struct TraceHookHandle(Option<Arc<dyn TraceHook>>);
+
+impl TraceHookHandle {
+    /// Create an unset handle (trace points become no-ops)
+    pub fn none() -> Self {
+        Self(None)
+    }
+
+    /// Attach a hook
+    pub fn set(&mut self, hook: Arc<dyn TraceHook>) {
+        self.0 = Some(hook);
+    }
+
+    /// Detach the hook
+    pub fn clear(&mut self) {
+        self.0 = None;
+    }
+
+    /// Check whether a hook is attached
+    pub fn is_set(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Fire the TX trace point (no-op when unset)
+    #[inline]
+    pub fn on_tx(&self, layer: TraceLayer, bytes: &[u8]) {
+        if let Some(hook) = &self.0 {
+            hook.on_tx(layer, bytes);
+        }
+    }
+
+    /// Fire the RX trace point (no-op when unset)
+    #[inline]
+    pub fn on_rx(&self, layer: TraceLayer, bytes: &[u8]) {
+        if let Some(hook) = &self.0 {
+            hook.on_rx(layer, bytes);
+        }
+    }
+}
+
+impl fmt::Debug for TraceHookHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TraceHookHandle")
+            .field(&if self.0.is_some() { "set" } else { "unset" })
+            .finish()
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::error::{DlmsError, DlmsResult};
 use crate::stream::{StreamAccessor, TransportLayer};
+use crate::trace::{TraceHook, TraceHookHandle, TraceLayer};
 use async_trait::async_trait;
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -44,6 +45,7 @@ pub struct UdpTransport {
     closed: bool,
     read_buffer: Arc<Mutex<Vec<u8>>>,
     read_position: Arc<Mutex<usize>>,
+    trace: TraceHookHandle,
 }
 
 impl UdpTransport {
@@ -55,9 +57,15 @@ impl UdpTransport {
             closed: true,
             read_buffer: Arc::new(Mutex::new(Vec::new())),
             read_position: Arc::new(Mutex::new(0)),
+            trace: TraceHookHandle::none(),
         }
     }
 
+    /// Attach a trace hook observing raw bytes on this transport
+    pub fn set_trace_hook(&mut self, hook: std::sync::Arc<dyn TraceHook>) {
+        self.trace.set(hook);
+    }
+
     /// Create UDP transport from address string
     pub fn from_address(address: &str) -> DlmsResult<Self> {
         let addr: SocketAddr = address.parse().map_err(|e| {
@@ -145,6 +153,7 @@ impl StreamAccessor for UdpTransport {
         if to_read > 0 {
             buf[..to_read].copy_from_slice(&buffer[*position..*position + to_read]);
             *position += to_read;
+            self.trace.on_rx(TraceLayer::Transport, &buf[..to_read]);
         }
 
         Ok(to_read)
@@ -181,6 +190,7 @@ impl StreamAccessor for UdpTransport {
             remaining = &remaining[sent..];
         }
 
+        self.trace.on_tx(TraceLayer::Transport, &buf[..written]);
         Ok(written)
     }
 